@@ -0,0 +1,183 @@
+use engine::{
+    BattlerGen1, MoveEffectGen1, MoveGen1, SpeciesGen1, damage_is_predictable_gen_1, damage_rolls_gen_1,
+    find_move_gen_1, find_species_gen_1, suggest_moves_gen_1, suggest_species_gen_1,
+};
+use engine::pokemon::IVsGen1;
+
+const USAGE: &str = "usage: nuzpedia calc --attacker <\"Species L<level>\"> --defender <\"Species L<level>\"> --move <move> [--crit] [--roll min|avg|max|all]";
+
+/// Which damage rolls to show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Roll {
+    Min,
+    Average,
+    Max,
+    All,
+}
+
+impl Roll {
+    /// Parses `min`, `avg`, `max` or `all`.
+    pub fn parse(text: &str) -> Option<Roll> {
+        match text {
+            "min" => Some(Roll::Min),
+            "avg" => Some(Roll::Average),
+            "max" => Some(Roll::Max),
+            "all" => Some(Roll::All),
+            _ => None,
+        }
+    }
+}
+
+/// Runs `nuzpedia calc`: the damage one Pokémon's move does to another,
+/// as a share of the defender's HP, with the chance it KOs and every roll.
+///
+/// Both Pokémon are assumed to have perfect DVs and no stat experience.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut args = args.iter();
+    let mut attacker = None;
+    let mut defender = None;
+    let mut move_name = None;
+    let mut crit = false;
+    let mut roll = Roll::All;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--attacker" => attacker = Some(parse_pokemon(args.next().ok_or(USAGE)?)?),
+            "--defender" => defender = Some(parse_pokemon(args.next().ok_or(USAGE)?)?),
+            "--move" => move_name = Some(find_move(args.next().ok_or(USAGE)?)?),
+            "--crit" => crit = true,
+            "--roll" => roll = args.next().and_then(|r| Roll::parse(r)).ok_or(USAGE)?,
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let (Some(attacker), Some(defender), Some(mov)) = (attacker, defender, move_name) else {
+        return Err(USAGE.into());
+    };
+    print_calc(&attacker, &defender, mov, crit, roll);
+    Ok(())
+}
+
+/// Prints the damage `mov` does from `attacker` to `defender`, against the
+/// defender's current HP and with both sides' stages and status.
+pub fn print_calc(attacker: &BattlerGen1, defender: &BattlerGen1, mov: MoveGen1, crit: bool, roll: Roll) {
+    let name = mov.name.clone();
+    let attacker = BattlerGen1 { moves: vec![mov], ..attacker.clone() };
+    let hp = defender.hp;
+    let rolls = damage_rolls_gen_1(&attacker, defender, 0, crit);
+    println!(
+        "L{} {} {name} vs. L{} {} ({hp} HP){}",
+        attacker.pokemon.stats.lvl, attacker.pokemon.name,
+        defender.pokemon.stats.lvl, defender.pokemon.name,
+        if crit { ", critical hit" } else { "" },
+    );
+    if !damage_is_predictable_gen_1(&attacker.moves[0]) {
+        println!("{name}'s damage depends on the last hit {} took, so it can't be calculated.", attacker.pokemon.name);
+        return;
+    }
+    if rolls.is_empty() {
+        println!("{name} doesn't do damage.");
+        return;
+    }
+    if attacker.moves[0].effect == MoveEffectGen1::OneHitKo && rolls == [0] {
+        println!(
+            "{name} can't hit {}: it's immune, or {} is slower or lower level.",
+            defender.pokemon.name, attacker.pokemon.name,
+        );
+        return;
+    }
+    if rolls.iter().all(|&damage| damage == 0) {
+        println!("{name} doesn't affect {}.", defender.pokemon.name);
+        return;
+    }
+
+    let percent = |damage: u16| damage as f64 * 100.0 / hp as f64;
+    let shown = match roll {
+        // The average roll is the middle one
+        Roll::Min => &rolls[..1],
+        Roll::Average => &rolls[rolls.len() / 2..=rolls.len() / 2],
+        Roll::Max => &rolls[rolls.len() - 1..],
+        Roll::All => &rolls[..],
+    };
+    let (low, high) = (shown[0], shown[shown.len() - 1]);
+    if low == high {
+        println!("Damage: {low} ({:.1}%)", percent(low));
+    } else {
+        println!("Damage: {low}-{high} ({:.1}-{:.1}%)", percent(low), percent(high));
+    }
+
+    let kos = shown.iter().filter(|&&damage| damage >= hp).count();
+    if kos == 0 {
+        match low {
+            0 => println!("KO chance: 0%"),
+            _ if shown.len() == 1 => println!("KO chance: 0% ({}HKO)", hp.div_ceil(low)),
+            _ => println!("KO chance: 0% ({}HKO on the lowest roll)", hp.div_ceil(low)),
+        }
+    } else {
+        println!("KO chance: {:.1}% ({kos}/{} rolls)", kos as f64 * 100.0 / shown.len() as f64, shown.len());
+    }
+    if roll == Roll::All && rolls.len() > 1 {
+        println!("Rolls: {}", rolls.iter().map(u16::to_string).collect::<Vec<_>>().join(", "));
+    }
+}
+
+/// Parses `Species L<level>` (or `Species:<level>`) into a battler with
+/// perfect DVs.
+pub fn parse_pokemon(text: &str) -> Result<BattlerGen1, String> {
+    let (name, level) = text.rsplit_once(':')
+        .or_else(|| text.trim().rsplit_once(' ').map(|(name, level)| (name, level.trim_start_matches(['L', 'l']))))
+        .ok_or(format!("'{text}' needs a level, e.g. \"Pikachu L25\""))?;
+    let species = find_species(name.trim())?;
+    let level = level.parse::<u8>().ok()
+        .filter(|l| (1..=100).contains(l))
+        .ok_or(format!("'{text}' needs a level between 1 and 100"))?;
+    let pokemon = species.pokemon(level, IVsGen1 { hp: 15, attack: 15, defense: 15, special: 15, speed: 15 });
+    let moves = pokemon.moves.clone();
+    Ok(BattlerGen1::new(pokemon, moves, species.base.speed))
+}
+
+/// Looks up a species, suggesting close names if there's no such species.
+pub fn find_species(name: &str) -> Result<&'static SpeciesGen1, String> {
+    find_species_gen_1(name).ok_or_else(|| unknown("species", name, suggest_species_gen_1(name)))
+}
+
+/// Looks up a move, suggesting close names if there's no such move.
+pub fn find_move(name: &str) -> Result<MoveGen1, String> {
+    find_move_gen_1(name).ok_or_else(|| unknown("move", name, suggest_moves_gen_1(name)))
+}
+
+fn unknown(kind: &str, name: &str, suggestions: Vec<&str>) -> String {
+    match suggestions.as_slice() {
+        [] => format!("unknown {kind} '{name}'"),
+        [only] => format!("unknown {kind} '{name}'; did you mean {only}?"),
+        [rest @ .., last] => format!("unknown {kind} '{name}'; did you mean {} or {last}?", rest.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Only the four roll names parse.
+    #[test]
+    fn test_roll_parse() {
+        assert_eq!(Roll::parse("min"), Some(Roll::Min));
+        assert_eq!(Roll::parse("avg"), Some(Roll::Average));
+        assert_eq!(Roll::parse("max"), Some(Roll::Max));
+        assert_eq!(Roll::parse("all"), Some(Roll::All));
+        assert_eq!(Roll::parse("average"), None);
+        assert_eq!(Roll::parse("MAX"), None);
+    }
+
+    /// Both level spellings parse, and bad levels and species are errors.
+    #[test]
+    fn test_parse_pokemon() {
+        let pikachu = parse_pokemon("Pikachu L25").unwrap();
+        assert_eq!((pikachu.pokemon.name.as_str(), pikachu.pokemon.stats.lvl), ("Pikachu", 25));
+        assert_eq!(parse_pokemon("Mr. Mime:30").unwrap().pokemon.stats.lvl, 30);
+        assert_eq!(parse_pokemon("nidoran m l5").unwrap().pokemon.name, "Nidoran♂");
+
+        assert!(parse_pokemon("Pikachu").unwrap_err().contains("needs a level"));
+        assert!(parse_pokemon("Pikachu L0").unwrap_err().contains("between 1 and 100"));
+        assert!(parse_pokemon("Pikachu L101").is_err());
+        assert!(parse_pokemon("Pikachoo L25").unwrap_err().contains("did you mean Pikachu?"));
+    }
+}
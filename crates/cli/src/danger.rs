@@ -0,0 +1,37 @@
+use engine::{DangerLevelGen1, danger_analysis_gen_1};
+
+use crate::matchup::{parse_trainer_and_party, render_table};
+
+const USAGE: &str = "usage: nuzpedia danger <trainer> [--location <text>] [--variant <text>] [--yellow] [--showdown <file|->] [--team <file.toml>] <Species:Level[:Move/Move/...]>...";
+
+/// Runs `nuzpedia danger`: lists every enemy move that can OHKO or 2HKO a party
+/// member from full HP, most dangerous first.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (trainer, party) = parse_trainer_and_party(args, USAGE)?;
+    let threats = danger_analysis_gen_1(&party, &trainer.battlers(), Some(trainer.class));
+
+    println!("{} ({}){}", trainer.name, trainer.location, trainer.variant.map(|v| format!(" - {v}")).unwrap_or_default());
+    println!();
+
+    let header: Vec<String> = ["", "Threat", "Max", "Crit", "Boosted"].map(String::from).into();
+    let rows: Vec<Vec<String>> = threats.iter()
+        .filter(|t| t.level != DangerLevelGen1::Safe)
+        .map(|t| vec![
+            match t.level {
+                DangerLevelGen1::Ohko => "OHKO".into(),
+                _ => "2HKO".into(),
+            },
+            format!("{} ({})", t.summary(), trainer.name),
+            format!("{}/{}", t.max_damage, t.ally_hp),
+            format!("{}/{}", t.max_crit_damage, t.ally_hp),
+            t.boosted_max_damage.map(|d| format!("{d}/{}", t.ally_hp)).unwrap_or("-".into()),
+        ])
+        .collect();
+
+    if rows.is_empty() {
+        println!("Nothing can 2HKO your party from full HP.");
+    } else {
+        println!("{}", render_table(&header, &rows));
+    }
+    Ok(())
+}
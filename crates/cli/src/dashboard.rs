@@ -0,0 +1,392 @@
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use engine::species::name_key;
+use engine::{
+    BadgeGen1, BattlerGen1, MatchupMatrixGen1, MoveMatchupGen1, TrainerClassGen1, TrainerGen1, level_cap_gen_1,
+    matchup_matrix_gen_1, trainers_gen_1,
+};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Cell, LineGauge, Paragraph, Row, Table, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use tracker::{DeathGen1, RunGen1, TrackedPokemonGen1};
+
+const USAGE: &str = "usage: nuzpedia dashboard <run.json>";
+
+/// How often the run file is checked for changes.
+const REFRESH: Duration = Duration::from_millis(500);
+
+/// Runs `nuzpedia dashboard`: a full-screen view of a run saved as JSON,
+/// with the party's HP, the graveyard, the level cap and the next boss's
+/// team, and how each party member fares against each of the boss's
+/// Pokémon. The view reloads whenever the file changes; q or Esc quits.
+///
+/// Tracked Pokémon don't record their moves, so party members are assumed
+/// to know the moves they'd know in the wild at their level.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let [path] = args else {
+        return Err(USAGE.into());
+    };
+    // Fail before taking over the terminal if there's no run to show
+    let mut dashboard = Dashboard { path, modified: modified(path), run: load_run(path)?, error: None };
+
+    let mut terminal = ratatui::try_init().map_err(|e| format!("can't start the dashboard: {e}"))?;
+    let result = dashboard.show(&mut terminal).map_err(|e| format!("dashboard: {e}"));
+    ratatui::try_restore().map_err(|e| format!("can't restore the terminal: {e}"))?;
+    result
+}
+
+/// The run being shown and where it came from.
+///
+/// # Fields
+/// - `path`: The run file
+/// - `modified`: When the file was last changed, as of the last reload
+/// - `run`: The last run read successfully
+/// - `error`: Why the last reload failed, if it did; the old run stays up
+struct Dashboard<'a> {
+    path: &'a str,
+    modified: Option<SystemTime>,
+    run: RunGen1,
+    error: Option<String>,
+}
+
+impl Dashboard<'_> {
+    /// Draws until the user quits, reloading the run when its file changes.
+    fn show(&mut self, terminal: &mut DefaultTerminal) -> std::io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            if event::poll(REFRESH)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+                    _ => {}
+                }
+            }
+            self.reload();
+        }
+    }
+
+    fn reload(&mut self) {
+        let modified = modified(self.path);
+        if modified == self.modified {
+            return;
+        }
+        self.modified = modified;
+        match load_run(self.path) {
+            Ok(run) => {
+                self.run = run;
+                self.error = None;
+            }
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let run = &self.run;
+        let party: Vec<&TrackedPokemonGen1> = run.party().collect();
+        let boss = next_boss(run);
+
+        let [header, top, bottom, status] = Layout::vertical([
+            Constraint::Length(4),
+            Constraint::Length(party.len().max(1) as u16 * 2 + 2),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [party_area, graveyard_area] = Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(top);
+        let [boss_area, matchup_area] = Layout::horizontal([Constraint::Percentage(35), Constraint::Percentage(65)]).areas(bottom);
+
+        frame.render_widget(self.header(), header);
+        draw_party(frame, party_area, run);
+        frame.render_widget(graveyard(run), graveyard_area);
+        frame.render_widget(boss_team(boss), boss_area);
+        frame.render_widget(matchup_table(&party, boss), matchup_area);
+        frame.render_widget(self.status(), status);
+    }
+
+    /// The run's name, game, badges and level cap.
+    fn header(&self) -> Paragraph<'_> {
+        let run = &self.run;
+        let mut badges = vec![Span::from(format!("Badges {}/8: ", run.progress.badge_count()))];
+        badges.extend(BadgeGen1::iter().flat_map(|badge| {
+            let name = badge.name().trim_end_matches(" Badge");
+            let name = if run.progress.has(badge) { Span::from(name).bold() } else { Span::from(name).dark_gray() };
+            [name, Span::from(" ")]
+        }));
+        let cap = match level_cap_gen_1(run.game.version(), &run.progress) {
+            Some(cap) => Line::from(vec![
+                Span::from("Level cap: "),
+                Span::from(cap.level.to_string()).bold(),
+                Span::from(format!(" ({})", cap.boss.name)),
+            ]),
+            None => Line::from("No level cap: the Champion has been beaten"),
+        };
+        Paragraph::new(vec![Line::from(badges), cap])
+            .block(Block::bordered().title(format!(" {} - Pokémon {} ", run.name, run.game.name())))
+    }
+
+    /// The file being watched, or why it couldn't be read.
+    fn status(&self) -> Line<'_> {
+        match &self.error {
+            Some(error) => Line::from(format!("Showing the last good run; {error}")).red(),
+            None => Line::from(format!("Watching {} - q to quit", self.path)).dark_gray(),
+        }
+    }
+}
+
+/// The next boss. The Red and Blue Champion's team depends on the starter,
+/// so it's picked from the run's starter when one was tracked, rather than
+/// the strongest variant the level cap goes by.
+fn next_boss(run: &RunGen1) -> Option<&'static TrainerGen1> {
+    let boss = level_cap_gen_1(run.game.version(), &run.progress)?.boss;
+    let starter = run.encounters.iter()
+        .map(|encounter| encounter.species.name)
+        .find(|species| STARTERS.contains(species));
+    let Some(starter) = starter.filter(|_| boss.class == TrainerClassGen1::Rival3) else {
+        return Some(boss);
+    };
+    trainers_gen_1().iter()
+        .filter(|t| t.class == boss.class && t.version == boss.version)
+        .find(|t| t.variant.is_some_and(|v| name_key(v).contains(&name_key(starter))))
+        .or(Some(boss))
+}
+
+/// The Red and Blue starters, which decide the Champion's team.
+const STARTERS: [&str; 3] = ["Bulbasaur", "Charmander", "Squirtle"];
+
+/// One HP bar per party member, flagging any above the level cap.
+fn draw_party(frame: &mut Frame, area: Rect, run: &RunGen1) {
+    let block = Block::bordered().title(" Party ");
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let cap = run.level_cap().unwrap_or(u8::MAX);
+    let rows = Layout::vertical(run.party().map(|_| Constraint::Length(2))).split(inner);
+    for (pokemon, &row) in run.party().zip(rows.iter()) {
+        let max = pokemon.pokemon().stats.hp;
+        let hp = pokemon.current_hp();
+        let ratio = if max == 0 { 0.0 } else { f64::from(hp) / f64::from(max) };
+        let color = match ratio {
+            r if r > 0.5 => Color::Green,
+            r if r > 0.2 => Color::Yellow,
+            _ => Color::Red,
+        };
+        let mut label = vec![Span::from(format!("{} ({}) L{} ", pokemon.nickname, pokemon.species.name, pokemon.level))];
+        if pokemon.level > cap {
+            label.push(Span::from("over cap ").red().bold());
+        }
+        label.push(Span::from(format!("{hp}/{max} HP ")));
+        let gauge = LineGauge::default()
+            .label(Line::from(label))
+            .ratio(ratio)
+            .filled_style(Style::new().fg(color))
+            .unfilled_style(Style::new().fg(Color::DarkGray));
+        frame.render_widget(gauge, Rect { height: 1, ..row });
+    }
+}
+
+/// Every Pokémon that has died, with how.
+fn graveyard(run: &RunGen1) -> Paragraph<'_> {
+    let items: Vec<Line> = run.graveyard()
+        .map(|pokemon| {
+            Line::from(vec![
+                Span::from(format!("{} ({}) L{}", pokemon.nickname, pokemon.species.name, pokemon.level)).bold(),
+                Span::from(format!(" - {}", cause(pokemon.death.as_ref()))),
+            ])
+        })
+        .collect();
+    let title = format!(" Graveyard ({}) ", items.len());
+    Paragraph::new(items).wrap(Wrap { trim: false }).block(Block::bordered().title(title))
+}
+
+fn cause(death: Option<&DeathGen1>) -> String {
+    let Some(death) = death else {
+        return "unknown cause".into();
+    };
+    if death.soul_link {
+        return "its Soul Link partner died".into();
+    }
+    let mut cause = match (&death.trainer, &death.opponent) {
+        (Some(trainer), Some(opponent)) => format!("{trainer}'s {opponent}"),
+        (Some(trainer), None) => trainer.clone(),
+        (None, Some(opponent)) => opponent.clone(),
+        (None, None) => "unknown cause".into(),
+    };
+    let details: Vec<String> = death.move_name.iter().cloned()
+        .chain(death.turn.map(|turn| format!("turn {turn}")))
+        .collect();
+    if !details.is_empty() {
+        cause += &format!(" ({})", details.join(", "));
+    }
+    cause
+}
+
+/// The next boss's Pokémon and their moves.
+fn boss_team(boss: Option<&'static TrainerGen1>) -> Paragraph<'static> {
+    let Some(boss) = boss else {
+        return Paragraph::new("Every boss has been beaten.").block(Block::bordered().title(" Next boss "));
+    };
+    let items: Vec<Line> = boss.battlers().iter()
+        .map(|battler| {
+            let moves: Vec<&str> = battler.moves.iter().map(|m| m.name.as_str()).collect();
+            Line::from(vec![
+                Span::from(format!("{} L{}", battler.pokemon.name, battler.pokemon.stats.lvl)).bold(),
+                Span::from(format!(": {}", moves.join(", "))),
+            ])
+        })
+        .collect();
+    Paragraph::new(items)
+        .wrap(Wrap { trim: false })
+        .block(Block::bordered().title(format!(" Next boss: {} ({}) ", boss.name, boss.location)))
+}
+
+/// One row per party member and one column per boss Pokémon, with each
+/// side's best move against the other as a share of max HP.
+fn matchup_table(party: &[&TrackedPokemonGen1], boss: Option<&'static TrainerGen1>) -> Table<'static> {
+    let block = Block::bordered()
+        .title(" Matchups ")
+        .title_bottom(Line::from(vec![Span::from(" yours ").green(), Span::from("/"), Span::from(" theirs ").red()]));
+    let Some(boss) = boss else {
+        return Table::default().block(block);
+    };
+    let allies: Vec<BattlerGen1> = party.iter().map(|tracked| battler(tracked)).collect();
+    let enemies = boss.battlers();
+    let matrix = matchup_matrix_gen_1(&allies, &enemies);
+
+    let mut header = vec![Cell::from("")];
+    header.extend(matrix.enemies.iter().map(|name| Cell::from(name.clone()).bold()));
+    let rows: Vec<Row> = party.iter().enumerate()
+        .map(|(a, tracked)| {
+            let mut cells = vec![Cell::from(tracked.nickname.clone()).bold()];
+            cells.extend((0..enemies.len()).map(|e| matchup_cell(&matrix, a, e)));
+            Row::new(cells).height(2)
+        })
+        .collect();
+    let widths = std::iter::once(Constraint::Length(12)).chain(enemies.iter().map(|_| Constraint::Fill(1)));
+    Table::new(rows, widths).header(Row::new(header)).block(block)
+}
+
+/// The party member's best move against the boss Pokémon over the boss
+/// Pokémon's best move against it.
+fn matchup_cell(matrix: &MatchupMatrixGen1, ally: usize, enemy: usize) -> Cell<'static> {
+    let ours = best(matrix.ally_attacks.iter().filter(|m| m.attacker == ally && m.defender == enemy));
+    let theirs = best(matrix.enemy_attacks.iter().filter(|m| m.attacker == enemy && m.defender == ally));
+    Cell::from(vec![attack_line(ours).green(), attack_line(theirs).red()])
+}
+
+fn best<'a>(attacks: impl Iterator<Item = &'a MoveMatchupGen1>) -> Option<&'a MoveMatchupGen1> {
+    attacks.max_by_key(|m| m.max_damage).filter(|m| m.max_damage > 0)
+}
+
+/// A move's damage range, bold if it can KO from the defender's current HP.
+fn attack_line(attack: Option<&MoveMatchupGen1>) -> Line<'static> {
+    let Some(attack) = attack else {
+        return Line::from("-");
+    };
+    let line = Line::from(format!("{} {:.0}-{:.0}%", attack.move_name, attack.min_percent(), attack.max_percent()));
+    if attack.ko_chance > 0.0 { line.add_modifier(Modifier::BOLD) } else { line }
+}
+
+/// A party member as it stands: its stats for its level and its current HP.
+fn battler(tracked: &TrackedPokemonGen1) -> BattlerGen1 {
+    let pokemon = tracked.pokemon();
+    let moves = pokemon.moves.clone();
+    let mut battler = BattlerGen1::new(pokemon, moves, tracked.species.base.speed);
+    battler.hp = tracked.current_hp();
+    battler
+}
+
+fn load_run(path: &str) -> Result<RunGen1, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    serde_json::from_str(&text).map_err(|e| format!("{path}: {e}"))
+}
+
+fn modified(path: &str) -> Option<SystemTime> {
+    Path::new(path).metadata().and_then(|m| m.modified()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use engine::{GameGen1, ProgressGen1, find_species_gen_1};
+
+    fn attack(move_name: &str, max_damage: u16, ko_chance: f64) -> MoveMatchupGen1 {
+        MoveMatchupGen1 {
+            attacker: 0,
+            defender: 0,
+            move_index: 0,
+            move_name: move_name.into(),
+            min_damage: max_damage * 217 / 255,
+            max_damage,
+            crit_max_damage: max_damage * 2,
+            defender_hp: 50,
+            defender_max_hp: 100,
+            ko_chance,
+            hits_to_ko: (max_damage > 0).then(|| 100_u16.div_ceil(max_damage)),
+            predictable: true,
+        }
+    }
+
+    /// Deaths read as the trainer's Pokémon, then the move and turn.
+    #[test]
+    fn test_cause() {
+        assert_eq!(cause(None), "unknown cause");
+        assert_eq!(cause(Some(&DeathGen1::default())), "unknown cause");
+
+        let death = DeathGen1 {
+            trainer: Some("Misty".into()),
+            opponent: Some("Starmie".into()),
+            move_name: Some("Bubble Beam".into()),
+            turn: Some(3),
+            ..Default::default()
+        };
+        assert_eq!(cause(Some(&death)), "Misty's Starmie (Bubble Beam, turn 3)");
+        assert_eq!(cause(Some(&DeathGen1 { turn: None, trainer: None, ..death.clone() })), "Starmie (Bubble Beam)");
+        assert_eq!(cause(Some(&DeathGen1 { soul_link: true, ..death })), "its Soul Link partner died");
+    }
+
+    /// The best move hits hardest; moves that do nothing don't count.
+    #[test]
+    fn test_best() {
+        let attacks = [attack("Tackle", 20, 0.0), attack("Water Gun", 35, 0.0), attack("Growl", 0, 0.0)];
+        assert_eq!(best(attacks.iter()).unwrap().move_name, "Water Gun");
+        assert!(best(attacks[2..].iter()).is_none());
+        assert!(best(std::iter::empty()).is_none());
+    }
+
+    /// Damage reads as a share of max HP, bold when it can KO.
+    #[test]
+    fn test_attack_line() {
+        assert_eq!(attack_line(None).to_string(), "-");
+
+        let water_gun = attack_line(Some(&attack("Water Gun", 35, 0.0)));
+        assert_eq!(water_gun.to_string(), "Water Gun 29-35%");
+        assert!(!water_gun.style.add_modifier.contains(Modifier::BOLD));
+        let surf = attack_line(Some(&attack("Surf", 60, 0.4)));
+        assert!(surf.style.add_modifier.contains(Modifier::BOLD));
+    }
+
+    /// The Champion's team follows the tracked starter in Red and Blue.
+    #[test]
+    fn test_champion_from_starter() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        run.progress = ProgressGen1 { elite_four: 4, ..ProgressGen1::with_badges(8) };
+        let strongest = next_boss(&run).unwrap();
+        assert_eq!(strongest.class, TrainerClassGen1::Rival3);
+
+        run.catch("Pallet Town", find_species_gen_1("Squirtle").unwrap(), 5, "Shelly").unwrap();
+        let champion = next_boss(&run).unwrap();
+        assert_eq!(champion.variant, Some("Player chose Squirtle"));
+        assert_eq!(champion.party.last().unwrap().species, "Venusaur");
+
+        // Before the Champion, the starter doesn't matter
+        run.progress.elite_four = 0;
+        assert_eq!(next_boss(&run).unwrap().name, "Lorelei");
+    }
+}
@@ -0,0 +1,89 @@
+use engine::{
+    EncounterTableGen1, GameGen1, TypeGen1, find_encounters_by_area_gen_1, find_encounters_by_type_gen_1,
+    learnset_in_version_gen_1,
+};
+
+use crate::matchup::render_table;
+
+const USAGE: &str = "usage: nuzpedia encounters [--blue | --yellow] (<area> | --type <type> [--badges <count>])";
+
+/// Runs `nuzpedia encounters`: prints the encounter odds for an area with the
+/// moves each Pokémon knows at its highest level, or every way to find a
+/// type with a number of badges.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut args = args.iter();
+    let mut game = GameGen1::Red;
+    let mut typ = None;
+    let mut badges = 8;
+    let mut area = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--red" => game = GameGen1::Red,
+            "--blue" => game = GameGen1::Blue,
+            "--yellow" => game = GameGen1::Yellow,
+            "--type" => typ = Some(parse_type(args.next().ok_or(USAGE)?)?),
+            "--badges" => badges = args.next().and_then(|b| b.parse().ok()).ok_or(USAGE)?,
+            _ => area.push(arg.as_str()),
+        }
+    }
+
+    match typ {
+        Some(typ) => by_type(typ, game, badges),
+        None if !area.is_empty() => by_area(&area.join(" "), game),
+        None => Err(USAGE.into()),
+    }
+}
+
+fn by_area(area: &str, game: GameGen1) -> Result<(), String> {
+    let tables = find_encounters_by_area_gen_1(area, game);
+    if tables.is_empty() {
+        return Err(format!("no encounters on '{area}' in {}", game.name()));
+    }
+    for table in tables {
+        println!("{} - {:?}{}", table.area, table.method, table.note.map(|n| format!(" ({n})")).unwrap_or_default());
+        let header: Vec<String> = ["Pokémon", "Levels", "Chance", "Moves"].map(String::from).into();
+        let rows: Vec<Vec<String>> = table.distribution().iter()
+            .map(|c| vec![
+                c.species.name.into(),
+                levels(table, c.min_level, c.max_level),
+                format!("{:.1}%", c.chance * 100.0),
+                learnset_in_version_gen_1(c.species, game.version()).moves_at(c.max_level.max(1)).join(", "),
+            ])
+            .collect();
+        println!("{}", render_table(&header, &rows));
+    }
+    Ok(())
+}
+
+fn by_type(typ: TypeGen1, game: GameGen1, badges: u8) -> Result<(), String> {
+    let header: Vec<String> = ["Pokémon", "Area", "Method", "Levels", "Chance"].map(String::from).into();
+    let rows: Vec<Vec<String>> = find_encounters_by_type_gen_1(typ, game, badges).iter()
+        .map(|(table, c)| vec![
+            c.species.name.into(),
+            table.area.into(),
+            format!("{:?}", table.method),
+            levels(table, c.min_level, c.max_level),
+            format!("{:.1}%", c.chance * 100.0),
+        ])
+        .collect();
+    if rows.is_empty() {
+        println!("No {typ:?} types in {} with {badges} badges.", game.name());
+    } else {
+        println!("{}", render_table(&header, &rows));
+    }
+    Ok(())
+}
+
+fn levels(table: &EncounterTableGen1, min: u8, max: u8) -> String {
+    match (min, max) {
+        (0, _) if table.method == engine::EncounterMethodGen1::Trade => "as traded".into(),
+        (min, max) if min == max => min.to_string(),
+        (min, max) => format!("{min}-{max}"),
+    }
+}
+
+fn parse_type(name: &str) -> Result<TypeGen1, String> {
+    TypeGen1::iter()
+        .find(|t| format!("{t:?}").eq_ignore_ascii_case(name))
+        .ok_or(format!("unknown type '{name}'"))
+}
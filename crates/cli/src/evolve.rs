@@ -0,0 +1,59 @@
+use engine::{EvolutionMethodGen1, StatsGen1, VersionGen1, evolution_plans_gen_1, find_species_gen_1};
+
+use crate::matchup::{parse_party_member, render_table};
+
+const USAGE: &str = "usage: nuzpedia evolve [--yellow] <Species:Level>";
+
+/// Runs `nuzpedia evolve`: for each thing a Pokémon can evolve into, prints the
+/// moves it would miss or learn later by evolving now, and how its stats
+/// would change. `--yellow` uses Yellow's learnsets.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (version, arg) = match args {
+        [arg] => (VersionGen1::RedBlue, arg),
+        [flag, arg] if flag == "--yellow" => (VersionGen1::Yellow, arg),
+        _ => return Err(USAGE.into()),
+    };
+    let pokemon = parse_party_member(arg)?.pokemon;
+    let species = find_species_gen_1(&pokemon.name).ok_or(USAGE)?;
+    let plans = evolution_plans_gen_1(species, &pokemon, version);
+    if plans.is_empty() {
+        println!("{} doesn't evolve.", species.name);
+    }
+
+    for plan in plans {
+        let how = match plan.evolution.method {
+            EvolutionMethodGen1::Level(level) => format!("at level {level}"),
+            EvolutionMethodGen1::Stone(stone) => format!("with a {}", stone.name()),
+            EvolutionMethodGen1::Trade => "by trading".into(),
+        };
+        let ready = if plan.ready { "" } else { " (not yet)" };
+        println!("{} evolves into {} {how}{ready}", plan.evolution.from, plan.evolution.to);
+
+        for (level, name) in &plan.missed_moves {
+            println!("  Misses {name} (level {level})");
+        }
+        for (name, now, evolved) in &plan.delayed_moves {
+            println!("  Learns {name} at {evolved} instead of {now}");
+        }
+
+        let header: Vec<String> = ["Stat", "Now", "Evolved", "Change"].map(String::from).into();
+        let rows: Vec<Vec<String>> = stat_rows(&plan.before).into_iter()
+            .zip(stat_rows(&plan.after))
+            .map(|((stat, before), (_, after))| {
+                vec![stat.into(), before.to_string(), after.to_string(), format!("{:+}", after as i16 - before as i16)]
+            })
+            .collect();
+        println!("{}", render_table(&header, &rows));
+    }
+    Ok(())
+}
+
+fn stat_rows(stats: &StatsGen1) -> [(&'static str, u16); 5] {
+    [
+        ("HP", stats.hp),
+        ("Attack", stats.attack),
+        ("Defense", stats.defense),
+        ("Special", stats.special),
+        ("Speed", stats.speed),
+    ]
+}
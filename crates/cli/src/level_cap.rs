@@ -0,0 +1,58 @@
+use engine::{ProgressGen1, VersionGen1, level_cap_gen_1};
+
+use crate::matchup::{parse_party_member, render_table};
+
+const USAGE: &str = "usage: nuzpedia cap [--yellow] [--badges <count>] [--elite-four <count>] [<Species:Level>...]";
+
+/// Runs `nuzpedia cap`: prints the level cap for the next boss, and how the given
+/// party compares to it.
+///
+/// Badges are counted in gym order, so `--badges 2` means Brock and Misty
+/// have been beaten.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut args = args.iter();
+    let mut version = VersionGen1::RedBlue;
+    let mut badges = 0;
+    let mut elite_four = 0;
+    let mut party = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--yellow" => version = VersionGen1::Yellow,
+            "--badges" => badges = count(args.next(), 8)?,
+            "--elite-four" => elite_four = count(args.next(), 4)?,
+            _ => party.push(parse_party_member(arg)?),
+        }
+    }
+    let progress = ProgressGen1 { elite_four: elite_four as u8, ..ProgressGen1::with_badges(badges) };
+
+    let Some(cap) = level_cap_gen_1(version, &progress) else {
+        println!("No level cap: the next boss isn't in the trainer data.");
+        return Ok(());
+    };
+    println!("Next boss: {} ({})", cap.boss.name, cap.boss.location);
+    println!("Level cap: {}", cap.level);
+
+    if !party.is_empty() {
+        let header: Vec<String> = ["Pokémon", "Level", ""].map(String::from).into();
+        let rows: Vec<Vec<String>> = party.iter()
+            .map(|b| {
+                let level = b.pokemon.stats.lvl;
+                let verdict = match level.checked_sub(cap.level) {
+                    Some(0) => "at cap".into(),
+                    Some(over) => format!("over cap by {over}"),
+                    None => format!("{} levels to go", cap.level - level),
+                };
+                vec![b.pokemon.name.clone(), level.to_string(), verdict]
+            })
+            .collect();
+        println!();
+        println!("{}", render_table(&header, &rows));
+    }
+    Ok(())
+}
+
+fn count(arg: Option<&String>, max: usize) -> Result<usize, String> {
+    arg.and_then(|a| a.parse().ok())
+        .filter(|&n| n <= max)
+        .ok_or(USAGE.into())
+}
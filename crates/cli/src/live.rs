@@ -0,0 +1,117 @@
+use engine::{SnapshotBattlerGen1, VersionGen1, parse_wram_gen_1};
+
+use crate::matchup::print_matchup;
+
+const USAGE: &str = "usage: nuzpedia live [--yellow] <wram.bin>";
+
+/// Runs `nuzpedia live`: reads the battle in progress from an emulator's dump of
+/// work RAM and prints the matchup between the two active Pokémon, with
+/// their current HP, stat stages and status. Only Red and Blue dumps can be
+/// read; `--yellow` says so rather than misreading one. Reflect and Light
+/// Screen are shown but not applied to the damage, with a warning.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (version, path) = match args {
+        [flag, path] if flag == "--yellow" => (VersionGen1::Yellow, path),
+        [path] => (VersionGen1::RedBlue, path),
+        _ => return Err(USAGE.into()),
+    };
+    let data = std::fs::read(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    let snapshot = parse_wram_gen_1(&data, version).map_err(|e| format!("can't read {path}: {e}"))?;
+
+    if let Some((class, number)) = snapshot.trainer {
+        println!("Against {} #{number}", class.name());
+    }
+    for (side, battler) in [("Yours", &snapshot.player), ("Theirs", &snapshot.enemy)] {
+        println!("{side}: {}", describe(battler));
+    }
+    for note in [&snapshot.player, &snapshot.enemy].into_iter().filter_map(screens_note) {
+        println!("{note}");
+    }
+    println!();
+    print_matchup(&[snapshot.player.battler], &[snapshot.enemy.battler]);
+    Ok(())
+}
+
+fn describe(battler: &SnapshotBattlerGen1) -> String {
+    let pokemon = &battler.battler.pokemon;
+    let stages = &pokemon.stat_stages;
+    let mut notes: Vec<String> = [("Atk", stages.attack), ("Def", stages.defense), ("Spc", stages.special), ("Spe", stages.speed)]
+        .into_iter()
+        .filter(|&(_, stage)| stage != 0)
+        .map(|(stat, stage)| format!("{stat} {stage:+}"))
+        .collect();
+    if pokemon.status != engine::StatusGen1::Healthy {
+        notes.push(format!("{:?}", pokemon.status));
+    }
+    let volatile = &battler.volatile;
+    for (flag, name) in [
+        (volatile.confused, "confused"),
+        (volatile.substitute, "substitute"),
+        (volatile.reflect, "Reflect"),
+        (volatile.light_screen, "Light Screen"),
+        (volatile.seeded, "seeded"),
+        (volatile.badly_poisoned, "badly poisoned"),
+        (volatile.recharging, "recharging"),
+        (volatile.invulnerable, "invulnerable"),
+    ] {
+        if flag {
+            notes.push(name.into());
+        }
+    }
+    format!(
+        "{} L{} {}/{} HP{}",
+        battler.species.name,
+        pokemon.stats.lvl,
+        battler.battler.hp,
+        pokemon.stats.hp,
+        if notes.is_empty() { String::new() } else { format!(" ({})", notes.join(", ")) }
+    )
+}
+
+/// A warning that the damage calc leaves out a Pokémon's Reflect or Light
+/// Screen, so moves against it do about half what the matchup shows.
+fn screens_note(battler: &SnapshotBattlerGen1) -> Option<String> {
+    let volatile = &battler.volatile;
+    let screens: Vec<_> = [(volatile.reflect, "Reflect", "physical"), (volatile.light_screen, "Light Screen", "special")]
+        .into_iter()
+        .filter(|&(up, _, _)| up)
+        .collect();
+    if screens.is_empty() {
+        return None;
+    }
+    let names: Vec<_> = screens.iter().map(|&(_, name, _)| name).collect();
+    let categories: Vec<_> = screens.iter().map(|&(_, _, category)| category).collect();
+    Some(format!(
+        "Warning: {}'s {} {} not applied below; {} moves do about half that to it, except on a critical hit.",
+        battler.species.name,
+        names.join(" and "),
+        if names.len() == 1 { "is" } else { "are" },
+        categories.join(" and "),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use engine::{BattlerGen1, VolatileFlagsGen1, find_species_gen_1};
+
+    fn snapshot(volatile: VolatileFlagsGen1) -> SnapshotBattlerGen1 {
+        let species = find_species_gen_1("Starmie").unwrap();
+        let battler = BattlerGen1::new(species.pokemon(40, Default::default()), Vec::new(), species.base.speed);
+        SnapshotBattlerGen1 { species, battler, pp: Vec::new(), volatile }
+    }
+
+    /// Screens get a warning, since the calc doesn't apply them.
+    #[test]
+    fn test_screens_note() {
+        assert_eq!(screens_note(&snapshot(VolatileFlagsGen1::default())), None);
+
+        let reflect = snapshot(VolatileFlagsGen1 { reflect: true, ..Default::default() });
+        let note = screens_note(&reflect).unwrap();
+        assert!(note.starts_with("Warning: Starmie's Reflect is not applied below; physical moves"), "{note}");
+        assert!(describe(&reflect).contains("Reflect"));
+
+        let both = snapshot(VolatileFlagsGen1 { reflect: true, light_screen: true, ..Default::default() });
+        assert!(screens_note(&both).unwrap().contains("Reflect and Light Screen are not applied below; physical and special"));
+    }
+}
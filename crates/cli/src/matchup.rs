@@ -0,0 +1,213 @@
+use engine::{
+    BattlerGen1, MatchupMatrixGen1, MoveMatchupGen1, TrainerGen1, VersionGen1,
+    matchup_matrix_gen_1, parse_showdown_gen_1, trainers_gen_1,
+};
+use engine::pokemon::IVsGen1;
+use engine::species::name_key;
+
+use crate::calc::{find_move, find_species};
+use crate::team::{battlers, load_team};
+
+const USAGE: &str = "usage: nuzpedia matchup <trainer> [--location <text>] [--variant <text>] [--yellow] [--showdown <file|->] [--team <file.toml>] <Species:Level[:Move/Move/...]>...";
+
+/// Runs `nuzpedia matchup`: prints the matchup matrix between the given party
+/// and a trainer's team.
+///
+/// Party Pokémon are given as `Species:Level:Move/Move/...` and are assumed
+/// to have perfect DVs and no stat experience, or read from a Showdown team
+/// with `--showdown` or a team file with `--team`.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let (trainer, party) = parse_trainer_and_party(args, USAGE)?;
+    println!("{} ({}){}", trainer.name, trainer.location, trainer.variant.map(|v| format!(" - {v}")).unwrap_or_default());
+    println!();
+    print_matchup(&party, &trainer.battlers());
+    Ok(())
+}
+
+/// Prints the damage each side's moves do to the other side and who's
+/// faster.
+pub fn print_matchup(party: &[BattlerGen1], enemies: &[BattlerGen1]) {
+    let matrix = matchup_matrix_gen_1(party, enemies);
+    println!("Your moves:");
+    println!("{}", attack_table(party, &matrix.ally_attacks, &matrix.enemies));
+    println!("Their moves:");
+    println!("{}", attack_table(enemies, &matrix.enemy_attacks, &matrix.allies));
+    println!("Speed:");
+    println!("{}", speed_table(&matrix));
+}
+
+/// Parses `<trainer> [--location <text>] [--variant <text>] [--yellow]
+/// [--showdown <file|->] [--team <file.toml>] <party>...` into the trainer
+/// and the player's party. A Showdown team is read from the file, or from
+/// stdin for `-`; teams come before any other party members, in the order
+/// given.
+pub fn parse_trainer_and_party(args: &[String], usage: &str) -> Result<(&'static TrainerGen1, Vec<BattlerGen1>), String> {
+    let mut args = args.iter();
+    let name = args.next().ok_or(usage)?;
+    let mut location = None;
+    let mut variant = None;
+    let mut version = VersionGen1::RedBlue;
+    let mut party = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--location" => location = Some(args.next().ok_or(usage)?),
+            "--variant" => variant = Some(args.next().ok_or(usage)?),
+            "--yellow" => version = VersionGen1::Yellow,
+            "--showdown" => party.extend(read_showdown_team(args.next().ok_or(usage)?)?),
+            "--team" => party.extend(load_team(args.next().ok_or(usage)?)?),
+            _ => party.push(parse_party_member(arg)?),
+        }
+    }
+    if party.is_empty() {
+        return Err(usage.into());
+    }
+    Ok((find_trainer(name, location, variant, version)?, party))
+}
+
+/// Parses `Species:Level[:Move/Move/...]` into a battler.
+pub fn parse_party_member(text: &str) -> Result<BattlerGen1, String> {
+    let mut parts = text.split(':');
+    let species_name = parts.next().unwrap_or_default();
+    let species = find_species(species_name)?;
+    let level = parts.next()
+        .and_then(|l| l.parse::<u8>().ok())
+        .filter(|l| (1..=100).contains(l))
+        .ok_or(format!("'{text}' needs a level between 1 and 100"))?;
+    let ivs = IVsGen1 { hp: 15, attack: 15, defense: 15, special: 15, speed: 15 };
+    let pokemon = species.pokemon(level, ivs);
+    // Without a moveset, assume it knows its level-up moves
+    let moves = parts.next()
+        .map(|m| m.split('/').map(find_move).collect())
+        .transpose()?
+        .unwrap_or_else(|| pokemon.moves.clone());
+
+    Ok(BattlerGen1::new(pokemon, moves, species.base.speed))
+}
+
+/// Reads a Showdown team from a file, or from stdin for `-`.
+fn read_showdown_team(path: &str) -> Result<Vec<BattlerGen1>, String> {
+    let text = if path == "-" {
+        std::io::read_to_string(std::io::stdin()).map_err(|e| format!("can't read stdin: {e}"))?
+    } else {
+        std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?
+    };
+    let team = parse_showdown_gen_1(&text).map_err(|e| format!("{path}: {e}"))?;
+    Ok(battlers(team))
+}
+
+fn find_trainer(
+    name: &str,
+    location: Option<&String>,
+    variant: Option<&String>,
+    version: VersionGen1,
+) -> Result<&'static TrainerGen1, String> {
+    let contains = |text: &str, filter: Option<&String>| filter.is_none_or(|f| name_key(text).contains(&name_key(f)));
+    let matches: Vec<_> = trainers_gen_1().iter()
+        .filter(|t| name_key(t.name) == name_key(name) && t.version == version)
+        .filter(|t| contains(t.location, location) && contains(t.variant.unwrap_or_default(), variant))
+        .collect();
+    match matches.as_slice() {
+        [] => Err(format!("no trainer named '{name}' matches")),
+        [trainer] => Ok(trainer),
+        _ => {
+            let options: Vec<_> = matches.iter()
+                .map(|t| format!("  {} ({}){}", t.name, t.location, t.variant.map(|v| format!(" - {v}")).unwrap_or_default()))
+                .collect();
+            Err(format!("'{name}' is ambiguous; narrow it down with --location or --variant:\n{}", options.join("\n")))
+        }
+    }
+}
+
+/// One row per attacker move, one column per defender.
+fn attack_table(
+    attackers: &[BattlerGen1],
+    attacks: &[MoveMatchupGen1],
+    defenders: &[String],
+) -> String {
+    let mut header = vec![String::new()];
+    header.extend(defenders.iter().cloned());
+
+    let mut rows = Vec::new();
+    for (a, attacker) in attackers.iter().enumerate() {
+        for (m, mov) in attacker.moves.iter().enumerate() {
+            let mut row = vec![format!("{} {}", attacker.pokemon.name, mov.name)];
+            row.extend((0..defenders.len()).map(|d| {
+                attacks.iter()
+                    .find(|x| x.attacker == a && x.move_index == m && x.defender == d)
+                    .map(attack_cell)
+                    .unwrap_or_default()
+            }));
+            rows.push(row);
+        }
+    }
+    render_table(&header, &rows)
+}
+
+fn attack_cell(matchup: &MoveMatchupGen1) -> String {
+    if !matchup.predictable {
+        return "?".into();
+    }
+    if matchup.max_damage == 0 {
+        return "-".into();
+    }
+    let range = format!("{:.0}-{:.0}%", matchup.min_percent(), matchup.max_percent());
+    if matchup.ko_chance > 0.0 {
+        format!("{range} KO {:.0}%", matchup.ko_chance * 100.0)
+    } else {
+        match matchup.hits_to_ko {
+            Some(hits) => format!("{range} {hits}HKO"),
+            None => range,
+        }
+    }
+}
+
+fn speed_table(matrix: &MatchupMatrixGen1) -> String {
+    let mut header = vec![String::new()];
+    header.extend(matrix.enemies.iter().cloned());
+
+    let rows: Vec<Vec<String>> = matrix.allies.iter().enumerate()
+        .map(|(a, ally)| {
+            let mut row = vec![ally.clone()];
+            row.extend((0..matrix.enemies.len()).map(|e| {
+                let speed = matrix.speed(a, e).expect("every pairing has a speed entry");
+                let verdict = if speed.ally_speed > speed.enemy_speed {
+                    "faster"
+                } else if speed.ally_speed < speed.enemy_speed {
+                    "slower"
+                } else {
+                    "tie"
+                };
+                format!("{} vs {} {verdict}", speed.ally_speed, speed.enemy_speed)
+            }));
+            row
+        })
+        .collect();
+    render_table(&header, &rows)
+}
+
+/// Left-aligned columns separated by `|`.
+pub fn render_table(header: &[String], rows: &[Vec<String>]) -> String {
+    let widths: Vec<usize> = (0..header.len())
+        .map(|c| {
+            std::iter::once(header)
+                .chain(rows.iter().map(Vec::as_slice))
+                .map(|row| row.get(c).map_or(0, |cell| cell.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let mut out = vec![line(header)];
+    out.push(widths.iter().map(|&w| "-".repeat(w)).collect::<Vec<_>>().join("-+-"));
+    out.extend(rows.iter().map(|row| line(row)));
+    out.join("\n") + "\n"
+}
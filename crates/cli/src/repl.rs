@@ -0,0 +1,428 @@
+use std::path::PathBuf;
+
+use engine::{BattlerGen1, MOVES_GEN_1, SPECIES_GEN_1, StatusGen1, parse_showdown_gen_1};
+use engine::species::name_key;
+use rustyline::completion::{Completer, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+
+use crate::calc::{Roll, find_move, find_species, parse_pokemon, print_calc};
+use crate::matchup::print_matchup;
+use crate::team::{battlers, load_team};
+
+const USAGE: &str = "usage: nuzpedia repl";
+
+const HELP: &str = "\
+load team <file.toml>           load your team (the lead becomes the attacker)
+load vs <file.toml>             load the enemy team (the lead becomes the defender)
+load showdown <file>            load your team from a Showdown export
+set attacker|defender <pokemon> a team member by name, or \"Species [L<level>]\" (L50 by default)
+boost [defender] <stat> <+n|-n> change a stage: atk, def, spc or spe
+status [defender] <status>      none, burn, poison, paralysis, freeze or sleep [turns]
+hp <value|percent%>             set the defender's current HP
+swap                            swap the attacker and defender
+calc <move> [crit] [min|avg|max|all]
+matchup                         the matchup matrix between the two teams
+show                            the attacker and defender
+help, quit";
+
+/// The level of a Pokémon set without one.
+const DEFAULT_LEVEL: u8 = 50;
+
+const COMMANDS: [&str; 11] = ["load", "set", "boost", "status", "hp", "swap", "calc", "matchup", "show", "help", "quit"];
+
+/// What's carried between REPL commands.
+#[derive(Default)]
+struct Session {
+    team: Vec<BattlerGen1>,
+    enemies: Vec<BattlerGen1>,
+    attacker: Option<BattlerGen1>,
+    defender: Option<BattlerGen1>,
+}
+
+/// Runs `nuzpedia repl`: an interactive session that keeps the teams, the
+/// attacker and the defender (with their stages, status and HP) between
+/// commands, with history and tab completion of commands, species and
+/// moves. History is kept in `~/.nuzpedia_history`.
+pub fn run(args: &[String]) -> Result<(), String> {
+    if !args.is_empty() {
+        return Err(USAGE.into());
+    }
+    let mut editor: Editor<ReplHelper, _> = Editor::new().map_err(|e| e.to_string())?;
+    editor.set_helper(Some(ReplHelper));
+    let history = std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".nuzpedia_history"));
+    if let Some(history) = &history {
+        // There's no history the first time
+        let _ = editor.load_history(history);
+    }
+
+    println!("Type \"help\" for commands.");
+    let mut session = Session::default();
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted | ReadlineError::Eof) => break,
+            Err(e) => return Err(e.to_string()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(line);
+        if matches!(line, "quit" | "exit") {
+            break;
+        }
+        if let Err(error) = session.execute(line) {
+            println!("{error}");
+        }
+    }
+    if let Some(history) = &history {
+        let _ = editor.save_history(history);
+    }
+    Ok(())
+}
+
+impl Session {
+    fn execute(&mut self, line: &str) -> Result<(), String> {
+        let (command, rest) = line.split_once(' ').map_or((line, ""), |(c, r)| (c, r.trim()));
+        match command {
+            "load" => self.load(rest),
+            "set" => self.set(rest),
+            "boost" => {
+                let (words, battler) = self.target(rest)?;
+                boost(battler, &words)?;
+                show(battler);
+                Ok(())
+            }
+            "status" => {
+                let (words, battler) = self.target(rest)?;
+                battler.pokemon.status = parse_status(&words)?;
+                show(battler);
+                Ok(())
+            }
+            "hp" => {
+                let defender = self.defender.as_mut().ok_or("set a defender first")?;
+                let max = defender.pokemon.stats.hp;
+                defender.hp = match rest.strip_suffix('%') {
+                    // In u32, since max HP can go up to 999
+                    Some(percent) => percent.parse::<u32>().ok().map(|p| (u32::from(max) * p.min(100)).div_ceil(100) as u16),
+                    None => rest.parse::<u16>().ok().map(|hp| hp.min(max)),
+                }
+                .ok_or(format!("'{rest}' isn't an HP value or percentage"))?;
+                show(defender);
+                Ok(())
+            }
+            "swap" => {
+                std::mem::swap(&mut self.attacker, &mut self.defender);
+                self.show();
+                Ok(())
+            }
+            "calc" => self.calc(rest),
+            "matchup" => {
+                if self.team.is_empty() || self.enemies.is_empty() {
+                    return Err("load a team and an enemy team first".into());
+                }
+                print_matchup(&self.team, &self.enemies);
+                Ok(())
+            }
+            "show" => {
+                self.show();
+                Ok(())
+            }
+            "help" => {
+                println!("{HELP}");
+                Ok(())
+            }
+            _ => Err(format!("unknown command '{command}'; type \"help\" for commands")),
+        }
+    }
+
+    fn load(&mut self, args: &str) -> Result<(), String> {
+        let (kind, path) = args.split_once(' ').ok_or("usage: load team|vs|showdown <file>")?;
+        let path = path.trim();
+        match kind {
+            "team" => self.team = load_team(path)?,
+            "vs" => self.enemies = load_team(path)?,
+            "showdown" => {
+                let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+                self.team = battlers(parse_showdown_gen_1(&text).map_err(|e| format!("{path}: {e}"))?);
+            }
+            _ => return Err("usage: load team|vs|showdown <file>".into()),
+        }
+        if kind == "vs" {
+            self.defender = self.enemies.first().cloned();
+        } else {
+            self.attacker = self.team.first().cloned();
+        }
+        let loaded = if kind == "vs" { &self.enemies } else { &self.team };
+        println!("Loaded {}", loaded.iter().map(|b| b.pokemon.name.as_str()).collect::<Vec<_>>().join(", "));
+        Ok(())
+    }
+
+    fn set(&mut self, args: &str) -> Result<(), String> {
+        let (side, pokemon) = args.split_once(' ').ok_or("usage: set attacker|defender <pokemon>")?;
+        let pokemon = pokemon.trim();
+        // A loaded team member first, then a new Pokémon
+        let member = self.team.iter()
+            .chain(&self.enemies)
+            .find(|b| name_key(&b.pokemon.name) == name_key(pokemon))
+            .cloned();
+        let battler = match member {
+            Some(member) => member,
+            // A bare species name gets the default level
+            None if find_species(pokemon).is_ok() => parse_pokemon(&format!("{pokemon} L{DEFAULT_LEVEL}"))?,
+            None => parse_pokemon(pokemon)?,
+        };
+        show(&battler);
+        match side {
+            "attacker" => self.attacker = Some(battler),
+            "defender" => self.defender = Some(battler),
+            _ => return Err("usage: set attacker|defender <pokemon>".into()),
+        }
+        Ok(())
+    }
+
+    /// The attacker, or the defender if the words start with "defender",
+    /// and the rest of the words.
+    fn target<'a>(&mut self, args: &'a str) -> Result<(Vec<&'a str>, &mut BattlerGen1), String> {
+        let mut words: Vec<&str> = args.split_whitespace().collect();
+        let side = match words.first() {
+            Some(&"defender") => &mut self.defender,
+            Some(&"attacker") => &mut self.attacker,
+            _ => {
+                words.insert(0, "attacker");
+                &mut self.attacker
+            }
+        };
+        let battler = side.as_mut().ok_or(format!("set an {} first", words[0]))?;
+        Ok((words[1..].to_vec(), battler))
+    }
+
+    fn calc(&self, args: &str) -> Result<(), String> {
+        let (Some(attacker), Some(defender)) = (&self.attacker, &self.defender) else {
+            return Err("set an attacker and a defender first".into());
+        };
+        let mut crit = false;
+        let mut roll = Roll::All;
+        let mut words: Vec<&str> = args.split_whitespace().collect();
+        while let Some(&last) = words.last() {
+            match (last, Roll::parse(last)) {
+                ("crit", _) => crit = true,
+                (_, Some(r)) => roll = r,
+                _ => break,
+            }
+            words.pop();
+        }
+        if words.is_empty() {
+            return Err("usage: calc <move> [crit] [min|avg|max|all]".into());
+        }
+        print_calc(attacker, defender, find_move(&words.join(" "))?, crit, roll);
+        Ok(())
+    }
+
+    fn show(&self) {
+        for (side, battler) in [("Attacker", &self.attacker), ("Defender", &self.defender)] {
+            print!("{side}: ");
+            match battler {
+                Some(battler) => show(battler),
+                None => println!("none"),
+            }
+        }
+    }
+}
+
+/// Prints a battler's level, HP, stats, stages and status on one line.
+fn show(battler: &BattlerGen1) {
+    let pokemon = &battler.pokemon;
+    let (stats, stages) = (&pokemon.stats, &pokemon.stat_stages);
+    let stage = |name: &str, stage: i8| (stage != 0).then(|| format!(" {name} {stage:+}"));
+    let stages: String = [("atk", stages.attack), ("def", stages.defense), ("spc", stages.special), ("spe", stages.speed)]
+        .into_iter()
+        .filter_map(|(name, s)| stage(name, s))
+        .collect();
+    let status = match pokemon.status {
+        StatusGen1::Healthy => String::new(),
+        StatusGen1::Asleep(turns) => format!(" asleep ({turns} turns)"),
+        status => format!(" {}", format!("{status:?}").to_lowercase()),
+    };
+    println!(
+        "L{} {} {}/{} HP, {}/{}/{}/{}{stages}{status}",
+        stats.lvl, pokemon.name, battler.hp, stats.hp, stats.attack, stats.defense, stats.special, stats.speed,
+    );
+}
+
+/// Applies `<stat> <+n|-n>`, keeping the stage within ±6.
+fn boost(battler: &mut BattlerGen1, words: &[&str]) -> Result<(), String> {
+    let usage = "usage: boost [defender] <atk|def|spc|spe> <+n|-n>";
+    let [stat, change] = words else {
+        return Err(usage.into());
+    };
+    let change: i8 = change.trim_start_matches('+').parse().map_err(|_| usage)?;
+    let stages = &mut battler.pokemon.stat_stages;
+    let stage = match *stat {
+        "atk" | "attack" => &mut stages.attack,
+        "def" | "defense" => &mut stages.defense,
+        "spc" | "special" => &mut stages.special,
+        "spe" | "speed" => &mut stages.speed,
+        _ => return Err(usage.into()),
+    };
+    *stage = stage.saturating_add(change).clamp(-6, 6);
+    Ok(())
+}
+
+fn parse_status(words: &[&str]) -> Result<StatusGen1, String> {
+    let status = match words {
+        ["none" | "healthy"] => StatusGen1::Healthy,
+        ["burn" | "brn" | "burned"] => StatusGen1::Burned,
+        ["poison" | "psn" | "poisoned"] => StatusGen1::Poisoned,
+        ["paralysis" | "par" | "paralyzed"] => StatusGen1::Paralyzed,
+        ["freeze" | "frz" | "frozen"] => StatusGen1::Frozen,
+        ["sleep" | "slp" | "asleep"] => StatusGen1::Asleep(7),
+        ["sleep" | "slp" | "asleep", turns] => match turns.parse() {
+            Ok(turns @ 1..=7) => StatusGen1::Asleep(turns),
+            _ => return Err(format!("sleep lasts 1 to 7 turns, not {turns}")),
+        },
+        _ => return Err("usage: status [defender] none|burn|poison|paralysis|freeze|sleep [turns]".into()),
+    };
+    Ok(status)
+}
+
+/// Tab completion of commands, species and moves.
+struct ReplHelper;
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context<'_>) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let line = &line[..pos];
+        let words: Vec<&str> = line.split(' ').collect();
+        // The names to pick from, and where the partial name starts
+        let (names, start): (Vec<&str>, usize) = match words.as_slice() {
+            [_] => (COMMANDS.to_vec(), 0),
+            ["calc", ..] => (MOVES_GEN_1.iter().map(|m| m.name).collect(), "calc ".len()),
+            ["set", side @ ("attacker" | "defender"), ..] => {
+                (SPECIES_GEN_1.iter().map(|s| s.name).collect(), "set ".len() + side.len() + 1)
+            }
+            ["set", _] => (vec!["attacker", "defender"], "set ".len()),
+            ["load", _] => (vec!["team", "vs", "showdown"], "load ".len()),
+            _ => return Ok((pos, Vec::new())),
+        };
+        let typed = name_key(&line[start..]);
+        let candidates = names.into_iter()
+            .filter(|name| name_key(name).starts_with(&typed))
+            .map(|name| Pair { display: name.into(), replacement: name.into() })
+            .collect();
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+#[cfg(test)]
+mod tests {
+    use rustyline::history::DefaultHistory;
+
+    use super::*;
+
+    fn pikachu() -> BattlerGen1 {
+        parse_pokemon("Pikachu L25").unwrap()
+    }
+
+    /// Stages stay within ±6, even for changes past what an `i8` holds.
+    #[test]
+    fn test_boost() {
+        let mut battler = pikachu();
+        boost(&mut battler, &["atk", "+2"]).unwrap();
+        boost(&mut battler, &["spe", "-1"]).unwrap();
+        assert_eq!((battler.pokemon.stat_stages.attack, battler.pokemon.stat_stages.speed), (2, -1));
+
+        boost(&mut battler, &["attack", "+6"]).unwrap();
+        assert_eq!(battler.pokemon.stat_stages.attack, 6);
+        boost(&mut battler, &["atk", "+127"]).unwrap();
+        assert_eq!(battler.pokemon.stat_stages.attack, 6);
+        boost(&mut battler, &["def", "-128"]).unwrap();
+        assert_eq!(battler.pokemon.stat_stages.defense, -6);
+
+        assert!(boost(&mut battler, &["atk", "+128"]).is_err());
+        assert!(boost(&mut battler, &["hp", "+1"]).is_err());
+        assert!(boost(&mut battler, &["atk"]).is_err());
+    }
+
+    /// Statuses by name or abbreviation, with sleep turns checked.
+    #[test]
+    fn test_parse_status() {
+        assert_eq!(parse_status(&["none"]), Ok(StatusGen1::Healthy));
+        assert_eq!(parse_status(&["brn"]), Ok(StatusGen1::Burned));
+        assert_eq!(parse_status(&["paralyzed"]), Ok(StatusGen1::Paralyzed));
+        assert_eq!(parse_status(&["sleep"]), Ok(StatusGen1::Asleep(7)));
+        assert_eq!(parse_status(&["slp", "3"]), Ok(StatusGen1::Asleep(3)));
+        assert!(parse_status(&["sleep", "8"]).is_err());
+        assert!(parse_status(&["confused"]).is_err());
+        assert!(parse_status(&[]).is_err());
+    }
+
+    /// A bare species gets the default level; a team member keeps its own.
+    #[test]
+    fn test_set() {
+        let mut session = Session::default();
+        session.set("attacker Pikachu").unwrap();
+        assert_eq!(session.attacker.as_ref().unwrap().pokemon.stats.lvl, DEFAULT_LEVEL);
+        session.set("defender Mr. Mime L30").unwrap();
+        assert_eq!(session.defender.as_ref().unwrap().pokemon.stats.lvl, 30);
+
+        session.team = vec![pikachu()];
+        session.set("attacker pikachu").unwrap();
+        assert_eq!(session.attacker.as_ref().unwrap().pokemon.stats.lvl, 25);
+        assert!(session.set("attacker Pikachoo").is_err());
+        assert!(session.set("bystander Pikachu").is_err());
+    }
+
+    /// HP percentages work for any max HP, up to Chansey's 703.
+    #[test]
+    fn test_hp() {
+        let mut chansey = parse_pokemon("Chansey L100").unwrap();
+        chansey.pokemon.evs.hp = 65_535;
+        find_species("Chansey").unwrap().calculate_stats(&mut chansey.pokemon);
+        assert_eq!(chansey.pokemon.stats.hp, 703);
+
+        let mut session = Session { defender: Some(chansey), ..Session::default() };
+        let hp = |session: &Session| session.defender.as_ref().unwrap().hp;
+        session.execute("hp 50%").unwrap();
+        assert_eq!(hp(&session), 352);
+        session.execute("hp 100%").unwrap();
+        assert_eq!(hp(&session), 703);
+        session.execute("hp 250%").unwrap();
+        assert_eq!(hp(&session), 703);
+        session.execute("hp 1000").unwrap();
+        assert_eq!(hp(&session), 703);
+        assert!(session.execute("hp lots").is_err());
+    }
+
+    /// Commands, then sides, species and moves depending on the command.
+    #[test]
+    fn test_complete() {
+        let history = DefaultHistory::new();
+        let complete = |line: &str| {
+            let (start, pairs) = ReplHelper.complete(line, line.len(), &Context::new(&history)).unwrap();
+            (start, pairs.into_iter().map(|p| p.replacement).collect::<Vec<_>>())
+        };
+        assert_eq!(complete("sw"), (0, vec!["swap".into()]));
+        assert_eq!(complete("s"), (0, vec!["set".into(), "status".into(), "swap".into(), "show".into()]));
+        assert_eq!(complete("set d"), (4, vec!["defender".into()]));
+        assert_eq!(complete("set attacker pikac"), (13, vec!["Pikachu".into()]));
+        assert_eq!(complete("calc thunder sh"), (5, vec!["Thunder Shock".into()]));
+        assert_eq!(complete("load s"), (5, vec!["showdown".into()]));
+        assert_eq!(complete("boost a"), (7, Vec::<String>::new()));
+    }
+}
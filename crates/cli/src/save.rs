@@ -0,0 +1,60 @@
+use engine::{SaveGen1, SavedPokemonGen1, StatusGen1, parse_save_gen_1};
+
+use crate::matchup::render_table;
+
+const USAGE: &str = "usage: nuzpedia save <file.sav>";
+
+/// Runs `nuzpedia save`: prints the player, party and boxes from an emulator's
+/// save file.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let [path] = args else {
+        return Err(USAGE.into());
+    };
+    let data = std::fs::read(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    let save = parse_save_gen_1(&data).map_err(|e| format!("can't read {path}: {e}"))?;
+    print_save(&save);
+    Ok(())
+}
+
+fn print_save(save: &SaveGen1) {
+    println!(
+        "{} (ID {:05}), rival {}: ₽{}, {} badges",
+        save.player_name, save.player_id, save.rival_name, save.money, save.progress.badge_count()
+    );
+    println!();
+    println!("Party:");
+    let header: Vec<String> = ["Pokémon", "Species", "Level", "HP", "Status", "Moves"].map(String::from).into();
+    let rows: Vec<Vec<String>> = save.party.iter().map(party_row).collect();
+    println!("{}", render_table(&header, &rows));
+
+    for (i, pokemon) in save.boxes.iter().enumerate().filter(|(_, b)| !b.is_empty()) {
+        let names: Vec<String> = pokemon.iter()
+            .map(|p| format!("{} ({} L{})", p.nickname, p.species.name, p.pokemon.stats.lvl))
+            .collect();
+        println!("Box {}: {}", i + 1, names.join(", "));
+    }
+}
+
+fn party_row(saved: &SavedPokemonGen1) -> Vec<String> {
+    let pokemon = &saved.pokemon;
+    let status = match pokemon.status {
+        StatusGen1::Healthy => "",
+        StatusGen1::Burned => "BRN",
+        StatusGen1::Poisoned => "PSN",
+        StatusGen1::Paralyzed => "PAR",
+        StatusGen1::Asleep(_) => "SLP",
+        StatusGen1::Frozen => "FRZ",
+    };
+    let moves: Vec<String> = pokemon.moves.iter()
+        .zip(&saved.pp)
+        .map(|(m, pp)| format!("{} ({pp})", m.name))
+        .collect();
+    vec![
+        saved.nickname.clone(),
+        saved.species.name.into(),
+        pokemon.stats.lvl.to_string(),
+        format!("{}/{}", saved.hp, pokemon.stats.hp),
+        status.into(),
+        moves.join(", "),
+    ]
+}
@@ -0,0 +1,45 @@
+use engine::{BattlerGen1, PokemonGen1, TeamFileGen1, find_species_gen_1};
+
+use crate::matchup::print_matchup;
+
+pub const USAGE: &str = "usage: nuzpedia --team <file.toml> --vs <file.toml>
+       nuzpedia <calc|repl|matchup|danger|cap|encounters|evolve|save|live|dashboard> ...";
+
+/// Runs `nuzpedia --team mine.toml --vs enemy.toml`: prints the matchup
+/// matrix between two teams read from team files.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut args = args.iter();
+    let mut team = None;
+    let mut enemies = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--team" => team = Some(load_team(args.next().ok_or(USAGE)?)?),
+            "--vs" => enemies = Some(load_team(args.next().ok_or(USAGE)?)?),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let (Some(team), Some(enemies)) = (team, enemies) else {
+        return Err(USAGE.into());
+    };
+    print_matchup(&team, &enemies);
+    Ok(())
+}
+
+/// Reads and checks a TOML team file (see [`TeamFileGen1`] for the format).
+pub fn load_team(path: &str) -> Result<Vec<BattlerGen1>, String> {
+    let text = std::fs::read_to_string(path).map_err(|e| format!("can't read {path}: {e}"))?;
+    let file: TeamFileGen1 = toml::from_str(&text).map_err(|e| format!("{path}: {e}"))?;
+    let team = file.pokemon().map_err(|e| format!("{path}: {e}"))?;
+    Ok(battlers(team))
+}
+
+/// Puts Pokémon into battle with the moves they know.
+pub fn battlers(team: Vec<PokemonGen1>) -> Vec<BattlerGen1> {
+    team.into_iter()
+        .map(|pokemon| {
+            let base_speed = find_species_gen_1(&pokemon.name).map_or(0, |s| s.base.speed);
+            let moves = pokemon.moves.clone();
+            BattlerGen1::new(pokemon, moves, base_speed)
+        })
+        .collect()
+}
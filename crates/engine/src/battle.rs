@@ -0,0 +1,923 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+
+use crate::{PokemonGen1, MoveCategory, MoveGen1, MoveEffectGen1, StatGen1, StatStagesGen1, StatusGen1, TypeGen1};
+use crate::item::ItemGen1;
+use crate::damage::{apply_stat_modifier, calc_damage_rolls_gen_1, fixed_damage_rolls_gen_1};
+
+/// Chance that a paralysed Pokémon is fully paralysed and loses its turn (63/256).
+pub const FULL_PARALYSIS_CHANCE: f64 = 63.0 / 256.0;
+
+/// Moves with a raised critical hit ratio in Gen 1 (8x the normal rate).
+const HIGH_CRIT_MOVES: [&str; 4] = ["Slash", "Karate Chop", "Razor Leaf", "Crabhammer"];
+
+/// A Pokémon taking part in a battle.
+///
+/// Wraps a [`PokemonGen1`] with the state that only exists during a battle:
+/// its usable moves and remaining HP.
+///
+/// # Fields
+/// - `pokemon`: The Pokémon itself (stats, stages and status are read from here)
+/// - `moves`: The moves it can select (up to 4)
+/// - `hp`: Remaining HP (0 means fainted)
+/// - `base_speed`: The species' base Speed, which sets the Gen 1 critical hit rate
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BattlerGen1 {
+    pub pokemon: PokemonGen1,
+    pub moves: Vec<MoveGen1>,
+    pub hp: u16,
+    pub base_speed: u8,
+}
+
+/// One side of a battle: a party and the index of its active Pokémon.
+///
+/// # Fields
+/// - `party`: Up to [`PARTY_SIZE`] battlers
+/// - `active`: Index of the Pokémon currently in battle
+/// - `ai_actions_used`: Items and switches the trainer AI has used so far
+/// - `turns_out`: Turns the active Pokémon has been in battle (capped at 2)
+///
+/// The last two only matter to the trainer AI, so they're only kept up
+/// to date for the opponent.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SideGen1 {
+    pub party: Vec<BattlerGen1>,
+    pub active: usize,
+    pub ai_actions_used: u8,
+    pub turns_out: u8,
+}
+
+/// A full battle between the player and an opponent.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BattleStateGen1 {
+    pub player: SideGen1,
+    pub opponent: SideGen1,
+}
+
+/// An action a side can take on its turn.
+///
+/// # Variants
+/// - `Move(i)`: Use the active Pokémon's `i`-th move
+/// - `Switch(i)`: Switch to the `i`-th party member
+/// - `Item(item)`: Use an item on the active Pokémon
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ActionGen1 {
+    Move(usize),
+    Switch(usize),
+    Item(ItemGen1),
+}
+
+/// Identifies one side of a battle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SideIdGen1 {
+    Player,
+    Opponent,
+}
+
+/// Chooses actions for a side that isn't under the searcher's control.
+///
+/// Implementations return every action the side might take together with
+/// its probability; the probabilities should sum to 1. Returning an empty
+/// list means the side does nothing this turn.
+pub trait OpponentPolicyGen1 {
+    fn action_probabilities(&self, state: &BattleStateGen1) -> Vec<(ActionGen1, f64)>;
+}
+
+/// Picks uniformly between the active Pokémon's moves.
+///
+/// This is how Gen 1 trainers without any AI modifiers choose their moves.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UniformMovePolicyGen1;
+
+impl OpponentPolicyGen1 for UniformMovePolicyGen1 {
+    fn action_probabilities(&self, state: &BattleStateGen1) -> Vec<(ActionGen1, f64)> {
+        let moves = state.opponent.active().moves.len();
+        (0..moves)
+            .map(|i| (ActionGen1::Move(i), 1.0 / moves as f64))
+            .collect()
+    }
+}
+
+// ================= Battle State =================
+
+impl BattlerGen1 {
+    /// Creates a battler at full HP.
+    pub fn new(pokemon: PokemonGen1, moves: Vec<MoveGen1>, base_speed: u8) -> Self {
+        let hp = pokemon.stats.hp;
+        Self { pokemon, moves, hp, base_speed }
+    }
+
+    /// Whether this battler has no HP left.
+    pub fn is_fainted(&self) -> bool {
+        self.hp == 0
+    }
+}
+
+impl SideGen1 {
+    /// Creates a side led by the first party member.
+    pub fn new(party: Vec<BattlerGen1>) -> Self {
+        Self { party, active: 0, ai_actions_used: 0, turns_out: 0 }
+    }
+
+    /// The Pokémon currently in battle.
+    pub fn active(&self) -> &BattlerGen1 {
+        &self.party[self.active]
+    }
+
+    /// Whether every party member has fainted.
+    pub fn is_defeated(&self) -> bool {
+        self.party.iter().all(BattlerGen1::is_fainted)
+    }
+
+    /// Number of fainted party members.
+    pub fn fainted_count(&self) -> usize {
+        self.party.iter().filter(|b| b.is_fainted()).count()
+    }
+
+    /// Index of the first party member that can still battle.
+    pub fn next_available(&self) -> Option<usize> {
+        self.party.iter().position(|b| !b.is_fainted())
+    }
+
+    /// Party members (other than the active one) that can be switched in.
+    pub fn switch_targets(&self) -> impl Iterator<Item = usize> + '_ {
+        self.party.iter()
+            .enumerate()
+            .filter(move |(i, b)| *i != self.active && !b.is_fainted())
+            .map(|(i, _)| i)
+    }
+}
+
+impl BattleStateGen1 {
+    /// Creates a battle between two parties, each led by its first member.
+    pub fn new(player: Vec<BattlerGen1>, opponent: Vec<BattlerGen1>) -> Self {
+        Self {
+            player: SideGen1::new(player),
+            opponent: SideGen1::new(opponent),
+        }
+    }
+
+    /// Returns the requested side.
+    pub fn side(&self, id: SideIdGen1) -> &SideGen1 {
+        match id {
+            SideIdGen1::Player => &self.player,
+            SideIdGen1::Opponent => &self.opponent,
+        }
+    }
+
+    fn side_mut(&mut self, id: SideIdGen1) -> &mut SideGen1 {
+        match id {
+            SideIdGen1::Player => &mut self.player,
+            SideIdGen1::Opponent => &mut self.opponent,
+        }
+    }
+
+    /// Actions the player can legally choose this turn.
+    ///
+    /// If the player's active Pokémon has fainted, only switches are allowed.
+    pub fn player_actions(&self) -> Vec<ActionGen1> {
+        let side = &self.player;
+        let mut actions = Vec::new();
+        if !side.active().is_fainted() {
+            actions.extend((0..side.active().moves.len()).map(ActionGen1::Move));
+        }
+        actions.extend(side.switch_targets().map(ActionGen1::Switch));
+        actions
+    }
+}
+
+// ================= Positions =================
+
+/// Maximum number of Pokémon in a Gen 1 party.
+pub const PARTY_SIZE: usize = 6;
+
+/// The parts of a battler that change during a battle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(crate) struct Volatile {
+    pub(crate) hp: u16,
+    pub(crate) status: StatusGen1,
+    pub(crate) stages: StatStagesGen1,
+}
+
+/// Everything about a battle that can change from turn to turn.
+///
+/// Static data (stats, types, moves) stays in the [`BattleStateGen1`] the
+/// position was taken from, which keeps positions `Copy` and cheap to hash
+/// when enumerating thousands of chance outcomes. Empty party slots have
+/// 0 HP, so they always count as fainted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Position {
+    active: [usize; 2],
+    battlers: [[Volatile; PARTY_SIZE]; 2],
+    ai_actions_used: u8,
+    turns_out: u8,
+}
+
+/// A hash map keyed by positions.
+///
+/// Positions are hashed millions of times during a search, so this uses a
+/// simple multiplicative hash instead of the default SipHash. Keys never
+/// come from untrusted input, so HashDoS resistance isn't needed.
+pub(crate) type PositionMap<K, V> = HashMap<K, V, BuildHasherDefault<PositionHasher>>;
+
+/// Multiply-rotate hasher used by [`PositionMap`].
+#[derive(Default)]
+pub(crate) struct PositionHasher(u64);
+
+impl PositionHasher {
+    fn add(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+}
+
+impl Hasher for PositionHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+}
+
+impl SideIdGen1 {
+    fn index(self) -> usize {
+        match self {
+            SideIdGen1::Player => 0,
+            SideIdGen1::Opponent => 1,
+        }
+    }
+
+    fn other(self) -> Self {
+        match self {
+            SideIdGen1::Player => SideIdGen1::Opponent,
+            SideIdGen1::Opponent => SideIdGen1::Player,
+        }
+    }
+}
+
+impl Volatile {
+    /// Packs the volatile state into a single word for hashing.
+    fn packed(&self) -> u64 {
+        let status = match self.status {
+            StatusGen1::Healthy => 0,
+            StatusGen1::Burned => 1,
+            StatusGen1::Poisoned => 2,
+            StatusGen1::Paralyzed => 3,
+            StatusGen1::Frozen => 4,
+            StatusGen1::Asleep(turns) => 8 + turns as u64,
+        };
+        let stages = &self.stages;
+        self.hp as u64
+            | status << 16
+            | (stages.attack as u8 as u64) << 32
+            | (stages.defense as u8 as u64) << 40
+            | (stages.special as u8 as u64) << 48
+            | (stages.speed as u8 as u64) << 56
+    }
+}
+
+impl Hash for Position {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_u64(
+            (self.active[0] | self.active[1] << 8) as u64
+                | (self.ai_actions_used as u64) << 16
+                | (self.turns_out as u64) << 24,
+        );
+        for volatile in self.battlers.iter().flatten() {
+            state.write_u64(volatile.packed());
+        }
+    }
+}
+
+impl Position {
+    /// Takes the changing parts of a battle state.
+    pub(crate) fn of(state: &BattleStateGen1) -> Self {
+        let mut position = Self {
+            active: [state.player.active, state.opponent.active],
+            battlers: [[Volatile::default(); PARTY_SIZE]; 2],
+            ai_actions_used: state.opponent.ai_actions_used,
+            turns_out: state.opponent.turns_out,
+        };
+        for side in [SideIdGen1::Player, SideIdGen1::Opponent] {
+            for (i, battler) in state.side(side).party.iter().enumerate() {
+                position.battlers[side.index()][i] = Volatile {
+                    hp: battler.hp,
+                    status: battler.pokemon.status,
+                    stages: battler.pokemon.stat_stages,
+                };
+            }
+        }
+        position
+    }
+
+    /// Rebuilds a full battle state from `template` with this position applied.
+    pub(crate) fn to_state(self, template: &BattleStateGen1) -> BattleStateGen1 {
+        let mut state = template.clone();
+        state.opponent.ai_actions_used = self.ai_actions_used;
+        state.opponent.turns_out = self.turns_out;
+        for side in [SideIdGen1::Player, SideIdGen1::Opponent] {
+            let target = state.side_mut(side);
+            target.active = self.active[side.index()];
+            for (i, battler) in target.party.iter_mut().enumerate() {
+                let volatile = self.battlers[side.index()][i];
+                battler.hp = volatile.hp;
+                battler.pokemon.status = volatile.status;
+                battler.pokemon.stat_stages = volatile.stages;
+            }
+        }
+        state
+    }
+
+    pub(crate) fn active(&self, side: SideIdGen1) -> usize {
+        self.active[side.index()]
+    }
+
+    pub(crate) fn volatile(&self, side: SideIdGen1, i: usize) -> &Volatile {
+        &self.battlers[side.index()][i]
+    }
+
+    fn active_volatile(&self, side: SideIdGen1) -> &Volatile {
+        self.volatile(side, self.active(side))
+    }
+
+    fn active_volatile_mut(&mut self, side: SideIdGen1) -> &mut Volatile {
+        &mut self.battlers[side.index()][self.active[side.index()]]
+    }
+
+    /// Whether the active Pokémon on `side` has fainted.
+    pub(crate) fn is_active_fainted(&self, side: SideIdGen1) -> bool {
+        self.active_volatile(side).hp == 0
+    }
+
+    /// Whether every Pokémon on `side` has fainted.
+    pub(crate) fn is_defeated(&self, side: SideIdGen1) -> bool {
+        self.battlers[side.index()].iter().all(|v| v.hp == 0)
+    }
+
+    /// Number of fainted Pokémon on `side`, out of a party of `party_size`.
+    pub(crate) fn fainted_count(&self, side: SideIdGen1, party_size: usize) -> usize {
+        self.battlers[side.index()][..party_size].iter().filter(|v| v.hp == 0).count()
+    }
+
+    /// Index of the first Pokémon on `side` that can still battle.
+    fn next_available(&self, side: SideIdGen1) -> Option<usize> {
+        self.battlers[side.index()].iter().position(|v| v.hp > 0)
+    }
+}
+
+// ================= Turn Order =================
+
+/// Calculates a Pokémon's Speed after stat stages and paralysis.
+///
+/// Gen 1 quarters the Speed of a paralysed Pokémon.
+pub fn effective_speed_gen_1(pokemon: &PokemonGen1) -> u32 {
+    speed_with(pokemon.stats.speed, &pokemon.status, &pokemon.stat_stages)
+}
+
+fn speed_with(speed: u16, status: &StatusGen1, stages: &StatStagesGen1) -> u32 {
+    let speed = apply_stat_modifier(speed, stages.speed);
+    if *status == StatusGen1::Paralyzed {
+        (speed / 4).max(1)
+    } else {
+        speed
+    }
+}
+
+/// Returns the priority bracket of a move.
+///
+/// Quick Attack is the only Gen 1 move that reliably moves first.
+pub fn move_priority_gen_1(mov: &MoveGen1) -> i8 {
+    match mov.name.as_str() {
+        "Quick Attack" => 1,
+        _ => 0,
+    }
+}
+
+/// Probability that the player's action resolves before the opponent's.
+///
+/// Switches always go before moves; otherwise priority and then effective
+/// Speed decide, with Speed ties broken by a coin flip. Trainer AI items
+/// are used in the trainer's normal move slot, so they count as priority 0.
+pub fn player_first_chance_gen_1(
+    state: &BattleStateGen1,
+    player: ActionGen1,
+    opponent: ActionGen1,
+) -> f64 {
+    TurnResolver::new(state).player_first_chance(&Position::of(state), player, opponent)
+}
+
+// ================= Chance Events =================
+
+/// Probability that a move hits.
+///
+/// Gen 1 stores accuracy out of 255 and rolls a number out of 256, so even
+/// 100% accurate moves miss 1/256 of the time.
+pub fn hit_chance_gen_1(mov: &MoveGen1) -> f64 {
+    let threshold = (mov.accuracy.min(100) as u32 * 255) / 100;
+    threshold as f64 / 256.0
+}
+
+/// Probability that a move lands a critical hit.
+///
+/// Gen 1 uses half the attacker's base Speed out of 256, multiplied by 8
+/// (capped at 255) for high critical hit ratio moves.
+pub fn crit_chance_gen_1(base_speed: u8, mov: &MoveGen1) -> f64 {
+    let mut threshold = base_speed as u32 / 2;
+    if HIGH_CRIT_MOVES.contains(&mov.name.as_str()) {
+        threshold = (threshold * 8).min(255);
+    }
+    threshold as f64 / 256.0
+}
+
+// ================= Turn Resolution =================
+
+/// Enumerates every outcome of one turn along with its probability.
+///
+/// Covers full paralysis, sleep and freeze, misses, critical hits, each
+/// distinct damage roll and residual burn/poison damage. Outcomes that lead
+/// to identical states are merged, so the result is usually much smaller
+/// than the raw number of branches. Fainted opponents are replaced by the
+/// next available party member, as Gen 1 trainers do; a fainted player
+/// Pokémon is left in place so the caller can choose its replacement.
+///
+/// Secondary move effects (e.g. Thunderbolt's paralysis chance) and PP are
+/// not modelled.
+///
+/// # Arguments
+/// * `state` - The battle before the turn.
+/// * `player` - The player's action, or `None` to skip.
+/// * `opponent` - The opponent's action, or `None` to skip.
+pub fn resolve_turn_gen_1(
+    state: &BattleStateGen1,
+    player: Option<ActionGen1>,
+    opponent: Option<ActionGen1>,
+) -> Vec<(f64, BattleStateGen1)> {
+    TurnResolver::new(state)
+        .resolve(&Position::of(state), player, opponent)
+        .into_iter()
+        .map(|(chance, position)| (chance, position.to_state(state)))
+        .collect()
+}
+
+/// Identifies a cached set of damage rolls: attacking side, attacker,
+/// move, defender, critical hit, and the attacker's and defender's
+/// status and stages. HP only counts for Super Fang, whose damage depends
+/// on it.
+type RollKey = (SideIdGen1, usize, usize, usize, bool, Volatile, Volatile);
+
+/// Resolves turns for positions of a single battle.
+///
+/// Damage rolls are cached, since the same matchup comes up again and
+/// again while enumerating a battle.
+pub(crate) struct TurnResolver<'a> {
+    template: &'a BattleStateGen1,
+    rolls: PositionMap<RollKey, Vec<u16>>,
+}
+
+impl<'a> TurnResolver<'a> {
+    pub(crate) fn new(template: &'a BattleStateGen1) -> Self {
+        Self { template, rolls: PositionMap::default() }
+    }
+
+    /// The battle this resolver was created for.
+    pub(crate) fn template(&self) -> &'a BattleStateGen1 {
+        self.template
+    }
+
+    fn battler(&self, position: &Position, side: SideIdGen1) -> &'a BattlerGen1 {
+        &self.template.side(side).party[position.active(side)]
+    }
+
+    /// Actions the player can legally choose in `position`.
+    ///
+    /// If the player's active Pokémon has fainted, only switches are allowed.
+    pub(crate) fn player_actions(&self, position: &Position) -> Vec<ActionGen1> {
+        let side = SideIdGen1::Player;
+        let mut actions = Vec::new();
+        if !position.is_active_fainted(side) {
+            let moves = self.battler(position, side).moves.len();
+            actions.extend((0..moves).map(ActionGen1::Move));
+        }
+        let party = self.template.player.party.len();
+        actions.extend(
+            (0..party)
+                .filter(|&i| i != position.active(side) && position.volatile(side, i).hp > 0)
+                .map(ActionGen1::Switch),
+        );
+        actions
+    }
+
+    /// Probability that the player's action resolves before the opponent's.
+    fn player_first_chance(&self, position: &Position, player: ActionGen1, opponent: ActionGen1) -> f64 {
+        let priority = |side: SideIdGen1, action: ActionGen1| match action {
+            ActionGen1::Switch(_) => i8::MAX,
+            ActionGen1::Move(i) => move_priority_gen_1(&self.battler(position, side).moves[i]),
+            ActionGen1::Item(_) => 0,
+        };
+
+        let player_priority = priority(SideIdGen1::Player, player);
+        let opponent_priority = priority(SideIdGen1::Opponent, opponent);
+        if player_priority != opponent_priority {
+            return if player_priority > opponent_priority { 1.0 } else { 0.0 };
+        }
+
+        let speed = |side: SideIdGen1| {
+            let volatile = position.active_volatile(side);
+            let stats = &self.battler(position, side).pokemon.stats;
+            speed_with(stats.speed, &volatile.status, &volatile.stages)
+        };
+        match speed(SideIdGen1::Player).cmp(&speed(SideIdGen1::Opponent)) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Less => 0.0,
+            std::cmp::Ordering::Equal => 0.5,
+        }
+    }
+
+    /// Enumerates every outcome of one turn; see [`resolve_turn_gen_1`].
+    pub(crate) fn resolve(
+        &mut self,
+        position: &Position,
+        player: Option<ActionGen1>,
+        opponent: Option<ActionGen1>,
+    ) -> Vec<(f64, Position)> {
+        type Order = [(SideIdGen1, Option<ActionGen1>); 2];
+        let orders: Vec<(f64, Order)> = match (player, opponent) {
+            (Some(p), Some(o)) => {
+                let first = self.player_first_chance(position, p, o);
+                vec![
+                    (first, [(SideIdGen1::Player, player), (SideIdGen1::Opponent, opponent)]),
+                    (1.0 - first, [(SideIdGen1::Opponent, opponent), (SideIdGen1::Player, player)]),
+                ]
+            }
+            _ => vec![(1.0, [(SideIdGen1::Player, player), (SideIdGen1::Opponent, opponent)])],
+        };
+
+        let mut merged: PositionMap<Position, f64> = PositionMap::default();
+        for (order_chance, order) in orders {
+            if order_chance == 0.0 {
+                continue;
+            }
+
+            let mut outcomes = vec![(order_chance, *position)];
+            for (side, action) in order {
+                let Some(action) = action else { continue };
+                let mut next = Vec::new();
+                for (chance, p) in outcomes {
+                    for (c, outcome) in self.apply_action(&p, side, action) {
+                        next.push((chance * c, outcome));
+                    }
+                }
+                outcomes = next;
+            }
+
+            for (chance, mut outcome) in outcomes {
+                outcome.turns_out = (outcome.turns_out + 1).min(2);
+                if outcome.is_active_fainted(SideIdGen1::Opponent)
+                    && let Some(next) = outcome.next_available(SideIdGen1::Opponent)
+                {
+                    outcome.active[SideIdGen1::Opponent.index()] = next;
+                    outcome.turns_out = 0;
+                }
+                *merged.entry(outcome).or_insert(0.0) += chance;
+            }
+        }
+
+        merged.into_iter().map(|(position, chance)| (chance, position)).collect()
+    }
+
+    /// Enumerates the outcomes of a single side's action.
+    fn apply_action(&mut self, position: &Position, side: SideIdGen1, action: ActionGen1) -> Vec<(f64, Position)> {
+        match action {
+            ActionGen1::Switch(i) => {
+                let mut next = *position;
+                next.active[side.index()] = i;
+                if side == SideIdGen1::Opponent {
+                    next.ai_actions_used += 1;
+                    next.turns_out = 0;
+                }
+                vec![(1.0, next)]
+            }
+            ActionGen1::Item(item) => {
+                let mut next = *position;
+                let max_hp = self.battler(position, side).pokemon.stats.hp;
+                use_item(next.active_volatile_mut(side), max_hp, item);
+                if side == SideIdGen1::Opponent {
+                    next.ai_actions_used += 1;
+                }
+                vec![(1.0, next)]
+            }
+            ActionGen1::Move(i) => {
+                // A Pokémon knocked out earlier in the turn doesn't get to move
+                if position.is_active_fainted(side) {
+                    return vec![(1.0, *position)];
+                }
+                let max_hp = self.battler(position, side).pokemon.stats.hp;
+                let mut outcomes = self.use_move(position, side, i);
+                for (_, outcome) in outcomes.iter_mut() {
+                    apply_residual_damage(outcome.active_volatile_mut(side), max_hp);
+                }
+                outcomes
+            }
+        }
+    }
+
+    /// Enumerates the outcomes of the active Pokémon on `side` using a move.
+    fn use_move(&mut self, position: &Position, side: SideIdGen1, move_index: usize) -> Vec<(f64, Position)> {
+        let attacker = *position.active_volatile(side);
+
+        // Sleep and freeze skip the turn entirely
+        match attacker.status {
+            StatusGen1::Asleep(turns) => {
+                let mut next = *position;
+                next.active_volatile_mut(side).status = if turns <= 1 {
+                    StatusGen1::Healthy
+                } else {
+                    StatusGen1::Asleep(turns - 1)
+                };
+                return vec![(1.0, next)];
+            }
+            StatusGen1::Frozen => return vec![(1.0, *position)],
+            _ => {}
+        }
+
+        let mut outcomes = Vec::new();
+        let mut acting = 1.0;
+        if attacker.status == StatusGen1::Paralyzed {
+            outcomes.push((FULL_PARALYSIS_CHANCE, *position));
+            acting -= FULL_PARALYSIS_CHANCE;
+        }
+
+        let battler = self.battler(position, side);
+        let mov = &battler.moves[move_index];
+        let defender_side = side.other();
+
+        // Moves that raise the user's stats never miss
+        if let MoveEffectGen1::StatUp(stat, stages) = mov.effect {
+            let mut next = *position;
+            change_stage(&mut next.active_volatile_mut(side).stages, stat, stages as i8);
+            outcomes.push((acting, next));
+            return outcomes;
+        }
+
+        let hit = hit_chance_gen_1(mov);
+        outcomes.push((acting * (1.0 - hit), *position));
+
+        if mov.category == MoveCategory::Status {
+            let defender = self.battler(position, defender_side);
+            let target = position.active_volatile(defender_side);
+            let healthy = target.status == StatusGen1::Healthy;
+            let mut inflict = |status: StatusGen1, chance: f64| {
+                let mut next = *position;
+                next.active_volatile_mut(defender_side).status = status;
+                outcomes.push((acting * hit * chance, next));
+            };
+            match mov.effect {
+                MoveEffectGen1::Sleep if healthy => {
+                    for turns in 1..=7 {
+                        inflict(StatusGen1::Asleep(turns), 1.0 / 7.0);
+                    }
+                }
+                MoveEffectGen1::Poison if healthy && !defender.pokemon.types.contains(&TypeGen1::Poison) => {
+                    inflict(StatusGen1::Poisoned, 1.0);
+                }
+                MoveEffectGen1::Paralyze
+                    if healthy
+                        && !(mov.typ == TypeGen1::Electric
+                            && defender.pokemon.types.contains(&TypeGen1::Ground)) =>
+                {
+                    inflict(StatusGen1::Paralyzed, 1.0);
+                }
+                MoveEffectGen1::StatDown(stat, stages) => {
+                    let mut next = *position;
+                    change_stage(&mut next.active_volatile_mut(defender_side).stages, stat, -(stages as i8));
+                    outcomes.push((acting * hit, next));
+                }
+                _ => outcomes.push((acting * hit, *position)),
+            }
+            return outcomes;
+        }
+
+        let defender_hp = position.active_volatile(defender_side).hp;
+        let crit = crit_chance_gen_1(battler.base_speed, mov);
+
+        for (is_critical, chance) in [(false, 1.0 - crit), (true, crit)] {
+            if chance == 0.0 {
+                continue;
+            }
+
+            // Collapse rolls into distinct remaining HP values
+            let rolls = self.rolls(position, side, move_index, is_critical);
+            let mut remaining: Vec<(u16, usize)> = Vec::new();
+            for &damage in rolls {
+                let hp = defender_hp.saturating_sub(damage);
+                match remaining.iter_mut().find(|(h, _)| *h == hp) {
+                    Some((_, count)) => *count += 1,
+                    None => remaining.push((hp, 1)),
+                }
+            }
+
+            for (hp, count) in remaining {
+                let mut next = *position;
+                next.active_volatile_mut(defender_side).hp = hp;
+                let roll_chance = count as f64 / rolls.len() as f64;
+                outcomes.push((acting * hit * chance * roll_chance, next));
+            }
+        }
+
+        outcomes
+    }
+
+    /// Every damage roll for a move between the active Pokémon, cached.
+    /// Moves that skip the damage formula use [`fixed_damage_rolls_gen_1`],
+    /// except that Counter is taken to do nothing.
+    fn rolls(&mut self, position: &Position, side: SideIdGen1, move_index: usize, is_critical: bool) -> &[u16] {
+        let defender_side = side.other();
+        let attacker = self.battler(position, side);
+        let defender = self.battler(position, defender_side);
+        let mov = &attacker.moves[move_index];
+        let defender_hp = position.active_volatile(defender_side).hp;
+        let uses_hp = matches!(mov.effect, MoveEffectGen1::SuperFang | MoveEffectGen1::OneHitKo);
+        let without_hp = |v: &Volatile| Volatile { hp: 0, ..*v };
+        let key = (
+            side,
+            position.active(side),
+            move_index,
+            position.active(defender_side),
+            is_critical,
+            without_hp(position.active_volatile(side)),
+            Volatile { hp: if uses_hp { defender_hp } else { 0 }, ..*position.active_volatile(defender_side) },
+        );
+
+        self.rolls.entry(key).or_insert_with(|| {
+            let mut attacking = attacker.pokemon.clone();
+            attacking.status = key.5.status;
+            attacking.stat_stages = key.5.stages;
+            let mut defending = defender.pokemon.clone();
+            defending.status = key.6.status;
+            defending.stat_stages = key.6.stages;
+            match fixed_damage_rolls_gen_1(&attacking, &defending, defender_hp, mov) {
+                Some(rolls) if rolls.is_empty() => vec![0],
+                Some(rolls) => rolls,
+                None => calc_damage_rolls_gen_1(&attacking, &defending, mov, is_critical),
+            }
+        })
+    }
+}
+
+/// Applies an item to a battler: heals (capped at max HP), cures status or
+/// raises a stat by one stage.
+fn use_item(battler: &mut Volatile, max_hp: u16, item: ItemGen1) {
+    if let Some(amount) = item.heal_amount() {
+        battler.hp = battler.hp.saturating_add(amount).min(max_hp);
+    }
+    if item.cures_status() {
+        battler.status = StatusGen1::Healthy;
+    }
+    if let Some(stat) = item.boosted_stat() {
+        change_stage(&mut battler.stages, stat, 1);
+    }
+}
+
+/// Changes a stat stage, clamped to -6..=+6. Accuracy and evasion aren't
+/// tracked, so changes to them are ignored.
+fn change_stage(stages: &mut StatStagesGen1, stat: StatGen1, delta: i8) {
+    let stage = match stat {
+        StatGen1::Attack => &mut stages.attack,
+        StatGen1::Defense => &mut stages.defense,
+        StatGen1::Special => &mut stages.special,
+        StatGen1::Speed => &mut stages.speed,
+        StatGen1::Accuracy | StatGen1::Evasion => return,
+    };
+    *stage = (*stage + delta).clamp(-6, 6);
+}
+
+/// Applies end-of-turn burn or poison damage (1/16 of max HP).
+fn apply_residual_damage(battler: &mut Volatile, max_hp: u16) {
+    if battler.hp == 0 {
+        return;
+    }
+    if matches!(battler.status, StatusGen1::Burned | StatusGen1::Poisoned) {
+        let damage = (max_hp / 16).max(1);
+        battler.hp = battler.hp.saturating_sub(damage);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MoveCategory, StatsGen1, TypeGen1};
+
+    fn battler(speed: u8, status: StatusGen1) -> BattlerGen1 {
+        let pokemon = PokemonGen1 {
+            types: [TypeGen1::Normal, TypeGen1::None],
+            stats: StatsGen1 { lvl: 50, hp: 120, attack: 80, defense: 80, special: 80, speed: speed.into() },
+            status,
+            ..Default::default()
+        };
+        let tackle = MoveGen1 {
+            name: "Tackle".into(),
+            power: 35,
+            accuracy: 95,
+            category: MoveCategory::Physical,
+            ..Default::default()
+        };
+        BattlerGen1::new(pokemon, vec![tackle], speed)
+    }
+
+    /// Paralysis quarters Speed, letting a slower Pokémon outspeed it.
+    #[test]
+    fn test_paralysis_turn_order() {
+        let state = BattleStateGen1::new(
+            vec![battler(100, StatusGen1::Paralyzed)],
+            vec![battler(60, StatusGen1::Healthy)],
+        );
+        let first = player_first_chance_gen_1(&state, ActionGen1::Move(0), ActionGen1::Move(0));
+        assert_eq!(first, 0.0);
+
+        let tied = BattleStateGen1::new(
+            vec![battler(60, StatusGen1::Healthy)],
+            vec![battler(60, StatusGen1::Healthy)],
+        );
+        let first = player_first_chance_gen_1(&tied, ActionGen1::Move(0), ActionGen1::Move(0));
+        assert_eq!(first, 0.5);
+    }
+
+    /// Verifies the Gen 1 accuracy and critical hit thresholds.
+    #[test]
+    fn test_chance_thresholds() {
+        let slash = MoveGen1 { name: "Slash".into(), ..Default::default() };
+        let tackle = MoveGen1::default();
+
+        assert_eq!(hit_chance_gen_1(&tackle), 255.0 / 256.0);
+        assert_eq!(crit_chance_gen_1(100, &tackle), 50.0 / 256.0);
+        assert_eq!(crit_chance_gen_1(100, &slash), 255.0 / 256.0);
+    }
+
+    /// Outcome probabilities of a turn always sum to 1.
+    #[test]
+    fn test_turn_outcomes_sum_to_one() {
+        let state = BattleStateGen1::new(
+            vec![battler(90, StatusGen1::Paralyzed)],
+            vec![battler(70, StatusGen1::Burned)],
+        );
+        let outcomes = resolve_turn_gen_1(&state, Some(ActionGen1::Move(0)), Some(ActionGen1::Move(0)));
+        let total: f64 = outcomes.iter().map(|(chance, _)| chance).sum();
+
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(outcomes.iter().all(|(_, s)| s.opponent.active().hp < 120)); // Burn always chips
+    }
+
+    /// A sleeping Pokémon doesn't move, and wakes up when the counter runs out.
+    #[test]
+    fn test_sleep_counter() {
+        let state = BattleStateGen1::new(
+            vec![battler(90, StatusGen1::Asleep(1))],
+            vec![battler(70, StatusGen1::Healthy)],
+        );
+        let outcomes = resolve_turn_gen_1(&state, Some(ActionGen1::Move(0)), None);
+
+        assert_eq!(outcomes.len(), 1);
+        let (chance, next) = &outcomes[0];
+        assert_eq!(*chance, 1.0);
+        assert_eq!(next.player.active().pokemon.status, StatusGen1::Healthy);
+        assert_eq!(next.opponent.active().hp, 120);
+    }
+
+    /// Fixed-damage moves always do their fixed amount, not a formula roll.
+    #[test]
+    fn test_fixed_damage() {
+        let mut dragonite = battler(90, StatusGen1::Healthy);
+        dragonite.moves = vec![crate::find_move_gen_1("Dragon Rage").unwrap()];
+        let state = BattleStateGen1::new(vec![dragonite], vec![battler(70, StatusGen1::Healthy)]);
+        let outcomes = resolve_turn_gen_1(&state, Some(ActionGen1::Move(0)), None);
+
+        let hit: f64 = outcomes.iter().filter(|(_, s)| s.opponent.active().hp == 80).map(|(chance, _)| chance).sum();
+        assert_eq!(hit, hit_chance_gen_1(&state.player.active().moves[0]));
+        assert!(outcomes.iter().all(|(_, s)| matches!(s.opponent.active().hp, 80 | 120)));
+    }
+}
@@ -1,4 +1,5 @@
-use crate::{PokemonGen1, MoveGen1, MoveCategory, StatusGen1};
+use crate::{PokemonGen1, MoveGen1, MoveCategory, MoveEffectGen1, StatusGen1};
+use crate::battle::effective_speed_gen_1;
 use crate::types::type_effectiveness_gen_1;
 
 /// Controls how much damage randomisation is calculated in Generation 1 battles.
@@ -8,7 +9,10 @@ use crate::types::type_effectiveness_gen_1;
 /// - Average: 236/255 (~92.5%)
 /// - Max: 255/255 (100%)
 /// - Random: Uniformly random value in \[217,255\] (85-100%)
+///
+/// Use [`calc_damage_rolls_gen_1`] when every possible roll is needed.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DamageRoll {
     /// Minimum possible damage (85%)
     Min,
@@ -24,7 +28,7 @@ pub enum DamageRoll {
 ///
 /// # Formula
 /// Gen 1 uses integer division with truncation toward zero:
-/// ```
+/// ```text
 /// modified_stat = (base_stat * numerator) / denominator
 /// ```
 /// where `(numerator, denominator)` are determined by the stage:
@@ -43,11 +47,11 @@ pub enum DamageRoll {
 /// The modified stat value, clamped to a minimum of 1.
 ///
 /// # Examples
-/// ```
+/// ```ignore
 /// assert_eq!(apply_stat_modifier(100, -1), 66); // 100 * 2/3
 /// assert_eq!(apply_stat_modifier(100, 2), 200); // 100 * 4/2
 /// ```
-fn apply_stat_modifier(base_stat: u8, stage: i8) -> u32 {
+pub(crate) fn apply_stat_modifier(base_stat: u16, stage: i8) -> u32 {
     // Gen 1 uses integer division with truncation toward zero
     let (numerator, denominator) = match stage.clamp(-6, 6) {
         -6 => (2, 8),
@@ -74,7 +78,7 @@ fn apply_stat_modifier(base_stat: u8, stage: i8) -> u32 {
 /// Calculates damage for a move in Generation 1.
 ///
 /// Follows the [Gen 1 damage formula](https://bulbapedia.bulbagarden.net/wiki/Damage#Generation_I):
-/// ```
+/// ```text
 /// damage = ((((2 * Level / 5 + 2) * Power * Attack) / Defense) / 50 + 2) * STAB * TypeEff * Roll
 /// ```
 ///
@@ -92,12 +96,29 @@ fn apply_stat_modifier(base_stat: u8, stage: i8) -> u32 {
 /// - Critical hits ignore attack drops and defense boosts.
 /// - Selfdestruct/Explosion halve the defender's Defense.
 /// - Burn halves Attack for physical moves (unless critical).
+/// - If Attack or Defense is over 255 after all that, both are quartered.
 ///
 /// # Examples
 /// ```
-/// let pikachu = PokemonGen1::new("Pikachu", [TypeGen1::Electric, TypeGen1::None]);
-/// let starmie = PokemonGen1::new("Starmie", [TypeGen1::Water, TypeGen1::Psychic]);
-/// let thunderbolt = MoveGen1::new("Thunderbolt", TypeGen1::Electric, 90, MoveCategory::Special);
+/// use engine::{PokemonGen1, MoveGen1, MoveCategory, TypeGen1, DamageRoll, calc_damage_gen_1};
+///
+/// let pikachu = PokemonGen1 {
+///     name: "Pikachu".into(),
+///     types: [TypeGen1::Electric, TypeGen1::None],
+///     ..Default::default()
+/// };
+/// let starmie = PokemonGen1 {
+///     name: "Starmie".into(),
+///     types: [TypeGen1::Water, TypeGen1::Psychic],
+///     ..Default::default()
+/// };
+/// let thunderbolt = MoveGen1 {
+///     name: "Thunderbolt".into(),
+///     typ: TypeGen1::Electric,
+///     power: 90,
+///     category: MoveCategory::Special,
+///     ..Default::default()
+/// };
 ///
 /// let damage = calc_damage_gen_1(
 ///     &pikachu,
@@ -113,6 +134,154 @@ pub fn calc_damage_gen_1(
     mov: &MoveGen1,
     is_critical: bool,
     roll: DamageRoll,
+) -> u16 {
+    let damage = unrolled_damage_gen_1(attacker, defender, mov, is_critical);
+
+    // Apply random factor: Gen 1 rolls 217-255 (85-100% of damage)
+    match roll {
+        DamageRoll::Min => apply_roll(damage, DAMAGE_ROLL_MIN),
+        DamageRoll::Average => apply_roll(damage, 236),
+        DamageRoll::Max => damage,
+        DamageRoll::Random => {
+            let random_factor = DAMAGE_ROLL_MIN + (rand::random::<u8>() % DAMAGE_ROLL_COUNT as u8);
+            apply_roll(damage, random_factor)
+        }
+    }
+}
+
+/// Calculates every possible damage roll for a move in Generation 1.
+///
+/// Gen 1 picks the random factor uniformly from \[217,255\], so each of the
+/// [`DAMAGE_ROLL_COUNT`] entries is equally likely. Rolls are returned in
+/// ascending order of the random factor, so the first entry matches
+/// `DamageRoll::Min` and the last matches `DamageRoll::Max`.
+///
+/// # Examples
+/// ```
+/// use engine::{PokemonGen1, MoveGen1, StatsGen1, calc_damage_rolls_gen_1, DAMAGE_ROLL_COUNT};
+///
+/// let rattata = PokemonGen1 {
+///     stats: StatsGen1 { lvl: 5, attack: 12, defense: 10, ..Default::default() },
+///     ..Default::default()
+/// };
+/// let tackle = MoveGen1 { power: 35, ..Default::default() };
+///
+/// let rolls = calc_damage_rolls_gen_1(&rattata, &rattata, &tackle, false);
+/// assert_eq!(rolls.len(), DAMAGE_ROLL_COUNT);
+/// assert!(rolls.windows(2).all(|pair| pair[0] <= pair[1]));
+/// ```
+pub fn calc_damage_rolls_gen_1(
+    attacker: &PokemonGen1,
+    defender: &PokemonGen1,
+    mov: &MoveGen1,
+    is_critical: bool,
+) -> Vec<u16> {
+    let damage = unrolled_damage_gen_1(attacker, defender, mov, is_critical);
+
+    (DAMAGE_ROLL_MIN..=255)
+        .map(|factor| apply_roll(damage, factor))
+        .collect()
+}
+
+/// Damage for moves that skip the damage formula: Sonic Boom and Dragon
+/// Rage always do the same, Seismic Toss and Night Shade do the attacker's
+/// level, and Super Fang halves the defender's current HP. These have no
+/// random factor and hit just as hard on a critical hit.
+///
+/// # Returns
+/// `None` for moves that use the damage formula.
+///
+/// # Example
+/// ```
+/// use engine::{PokemonGen1, find_move_gen_1, fixed_damage_gen_1};
+///
+/// let attacker = PokemonGen1::default();
+/// let dragon_rage = find_move_gen_1("Dragon Rage").unwrap();
+/// assert_eq!(fixed_damage_gen_1(&attacker, 100, &dragon_rage), Some(40));
+/// let super_fang = find_move_gen_1("Super Fang").unwrap();
+/// assert_eq!(fixed_damage_gen_1(&attacker, 1, &super_fang), Some(1));
+/// ```
+pub fn fixed_damage_gen_1(attacker: &PokemonGen1, defender_hp: u16, mov: &MoveGen1) -> Option<u16> {
+    match mov.effect {
+        MoveEffectGen1::FixedDamage(damage) => Some(damage as u16),
+        MoveEffectGen1::LevelDamage => Some(attacker.stats.lvl as u16),
+        MoveEffectGen1::SuperFang => Some((defender_hp / 2).max(1)),
+        _ => None,
+    }
+}
+
+/// Every roll of a move that skips the damage formula, in ascending order
+/// and all equally likely:
+/// - The single roll of [`fixed_damage_gen_1`]
+/// - The defender's whole HP for Fissure, Horn Drill and Guillotine, or 0
+///   if they can't land: the user needs at least the defender's level and
+///   Speed, and the defender can't be immune to the move's type
+/// - 1 up to (but not including) 1.5× the user's level for Psywave
+/// - None for Counter, whose damage depends on the last hit the user took
+///   (see [`damage_is_predictable_gen_1`])
+///
+/// # Returns
+/// `None` for moves that use the damage formula.
+///
+/// # Example
+/// ```
+/// use engine::{PokemonGen1, StatsGen1, find_move_gen_1, fixed_damage_rolls_gen_1};
+///
+/// let attacker = PokemonGen1 { stats: StatsGen1 { lvl: 50, speed: 100, ..Default::default() }, ..Default::default() };
+/// let defender = PokemonGen1 { stats: StatsGen1 { lvl: 14, speed: 60, ..Default::default() }, ..Default::default() };
+/// let fissure = find_move_gen_1("Fissure").unwrap();
+/// assert_eq!(fixed_damage_rolls_gen_1(&attacker, &defender, 40, &fissure), Some(vec![40]));
+/// let psywave = find_move_gen_1("Psywave").unwrap();
+/// assert_eq!(fixed_damage_rolls_gen_1(&attacker, &defender, 40, &psywave).unwrap().len(), 74);
+/// ```
+pub fn fixed_damage_rolls_gen_1(
+    attacker: &PokemonGen1,
+    defender: &PokemonGen1,
+    defender_hp: u16,
+    mov: &MoveGen1,
+) -> Option<Vec<u16>> {
+    if let Some(damage) = fixed_damage_gen_1(attacker, defender_hp, mov) {
+        return Some(vec![damage]);
+    }
+    match mov.effect {
+        MoveEffectGen1::OneHitKo => {
+            let lands = attacker.stats.lvl >= defender.stats.lvl
+                && effective_speed_gen_1(attacker) >= effective_speed_gen_1(defender)
+                && type_effectiveness_gen_1(mov.typ, &defender.types) > 0.0;
+            Some(vec![if lands { defender_hp } else { 0 }])
+        }
+        MoveEffectGen1::Psywave => {
+            let max = (attacker.stats.lvl as u16 * 3 / 2).max(2);
+            Some((1..max).collect())
+        }
+        MoveEffectGen1::Counter => Some(Vec::new()),
+        _ => None,
+    }
+}
+
+/// Whether a move's damage can be worked out from the two Pokémon alone.
+/// Counter's can't, since it returns double the last hit its user took.
+pub fn damage_is_predictable_gen_1(mov: &MoveGen1) -> bool {
+    mov.effect != MoveEffectGen1::Counter
+}
+
+/// Lowest random factor a Gen 1 damage roll can use (out of 255).
+const DAMAGE_ROLL_MIN: u8 = 217;
+
+/// Number of equally likely random factors in a Gen 1 damage roll.
+pub const DAMAGE_ROLL_COUNT: usize = 39;
+
+/// Scales damage by a random factor in \[217,255\].
+fn apply_roll(damage: u16, factor: u8) -> u16 {
+    ((damage as u32 * factor as u32) / 255) as u16
+}
+
+/// Calculates damage before the random factor is applied.
+fn unrolled_damage_gen_1(
+    attacker: &PokemonGen1,
+    defender: &PokemonGen1,
+    mov: &MoveGen1,
+    is_critical: bool,
 ) -> u16 {
     // Handle status moves (0 damage)
     if mov.power == 0 {
@@ -126,74 +295,50 @@ pub fn calc_damage_gen_1(
     let crit: u32 = if is_critical { 2 } else { 1 };
 
     // Use Attack or Special based on move type
-    let (mut attacker_stat, mut defender_stat) = match mov.category {
+    let (attacker_stat, defender_stat) = match mov.category {
         MoveCategory::Physical => (attacker.stats.attack, defender.stats.defense),
         MoveCategory::Special => (attacker.stats.special, defender.stats.special),
         MoveCategory::Status => unreachable!(), // Handled by power check
     };
+    let (mut attack, mut defense) = (attacker_stat as u32, defender_stat as u32);
 
-    // Apply stat changes (crits ignore attack drops/defense boosts)
+    // Apply stat changes and burn (crits ignore both)
     if !is_critical {
-        attacker_stat = apply_stat_modifier(
-            attacker_stat, 
-            match mov.category {
-                MoveCategory::Physical => {
-                    let mut stat = attacker.stat_stages.attack;
-                    if attacker.status == StatusGen1::Burned {
-                        stat = stat / 2;
-                        stat.max(1)
-                    } else {
-                        stat
-                    }
-                },
-                MoveCategory::Special => attacker.stat_stages.special,
-                _ => 0,
-            }
-        ) as u8;
-
-        defender_stat = apply_stat_modifier(
-            defender_stat, 
-            match mov.category {
-                MoveCategory::Physical => defender.stat_stages.defense,
-                MoveCategory::Special => defender.stat_stages.special,
-                _ => 0,
-            }
-        ) as u8;
+        let (attack_stage, defense_stage) = match mov.category {
+            MoveCategory::Physical => (attacker.stat_stages.attack, defender.stat_stages.defense),
+            _ => (attacker.stat_stages.special, defender.stat_stages.special),
+        };
+        attack = apply_stat_modifier(attacker_stat, attack_stage);
+        defense = apply_stat_modifier(defender_stat, defense_stage);
+        if mov.category == MoveCategory::Physical && attacker.status == StatusGen1::Burned {
+            attack = (attack / 2).max(1);
+        }
     }
 
     // Halve defense stat if the move is Selfdestruct or Explosion
-    if mov.name == "Selfdestruct" || mov.name == "Explosion" {
-        defender_stat = (defender_stat / 2).max(1);
+    if mov.effect == MoveEffectGen1::Explode || mov.name == "Selfdestruct" || mov.name == "Explosion" {
+        defense = (defense / 2).max(1);
+    }
+
+    // Stats over 255 don't fit in a byte, so Gen 1 quarters both
+    if attack > 255 || defense > 255 {
+        attack = (attack / 4).max(1);
+        defense = (defense / 4).max(1);
     }
 
     // Convert values to u32 to avoid overflow
     let lvl: u32 = attacker.stats.lvl as u32;
     let power: u32 = mov.power as u32;
-    let attack: u32 = attacker_stat as u32;
-    let defense: u32 = defender_stat as u32;
 
     // Base damage formula (excl. random)
-    let base: u32 = (((2 * lvl * crit / 5 + 2) * power * attack) / (defense * 50) + 2) as u32;
+    let base: u32 = ((2 * lvl * crit / 5 + 2) * power * attack) / (defense * 50) + 2;
 
     // Apply STAB and type effectiveness
     let type_eff = type_effectiveness_gen_1(
         mov.typ,
         &defender.types
     );
-    let damage = (base as f64 * stab * type_eff) as u16;
-
-    // Apply random factor: Gen 1 rolls 217-255 (85-100% of damage)
-    let damage: u16 = match roll {
-        DamageRoll::Min => ((damage as u32 * 217) / 255) as u16,
-        DamageRoll::Average => ((damage as u32 * 236) / 255) as u16,
-        DamageRoll::Max => damage,
-        DamageRoll::Random => {
-            let random_factor = 217 + (rand::random::<u8>() % 39);
-            ((damage as u32 * random_factor as u32) / 255) as u16
-        }
-    };
-
-    return damage;
+    (base as f64 * stab * type_eff) as u16
 }
 
 #[cfg(test)]
@@ -240,6 +385,8 @@ mod tests {
             typ: TypeGen1::Electric, 
             power: 90, 
             category: MoveCategory::Special,
+            accuracy: 100,
+            effect: Default::default(),
         };
         
         // Test consistent rolls
@@ -322,4 +469,44 @@ mod tests {
 
         assert!(min < avg && avg < max);
     }
-}
\ No newline at end of file
+
+    /// Boosts past 255 keep raising damage, and a burn halves Attack itself
+    /// rather than its stage.
+    #[test]
+    fn test_boosts_and_burn() {
+        let mut attacker = PokemonGen1 { stats: StatsGen1 { lvl: 100, attack: 150, ..Default::default() }, ..Default::default() };
+        let defender = PokemonGen1 { stats: StatsGen1 { defense: 150, ..Default::default() }, ..Default::default() };
+        let body_slam = MoveGen1 { power: 85, category: MoveCategory::Physical, ..Default::default() };
+        let damage = |attacker: &PokemonGen1| calc_damage_gen_1(attacker, &defender, &body_slam, false, DamageRoll::Max);
+
+        let neutral = damage(&attacker);
+        attacker.stat_stages.attack = 2;
+        // 300 Attack against 150 Defense is quartered to 75 against 37, with STAB
+        assert_eq!(damage(&attacker) as u32, ((2 * 100 / 5 + 2) * 85 * 75 / (37 * 50) + 2) * 3 / 2);
+        assert!(damage(&attacker) > neutral * 2 - 4);
+        attacker.status = StatusGen1::Burned;
+        assert_eq!(damage(&attacker), neutral);
+        attacker.stat_stages.attack = 0;
+        assert!(damage(&attacker) < neutral * 2 / 3);
+    }
+
+    /// Pokémon, moves and rolls serialize with readable names, and read
+    /// back unchanged.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let mut snorlax = crate::find_species_gen_1("Snorlax").unwrap().pokemon(50, Default::default());
+        snorlax.status = StatusGen1::Asleep(3);
+        let json = serde_json::to_string(&snorlax).unwrap();
+        assert!(json.contains(r#""types":["Normal","None"]"#));
+        assert!(json.contains(r#""status":{"Asleep":3}"#));
+        assert!(json.contains(r#""category":"Physical""#));
+        let read: PokemonGen1 = serde_json::from_str(&json).unwrap();
+        assert_eq!(format!("{read:?}"), format!("{snorlax:?}"));
+
+        // Anything left out keeps its default
+        let partial: PokemonGen1 = serde_json::from_str(r#"{"name": "Mew", "status": "Frozen"}"#).unwrap();
+        assert_eq!((partial.stats.lvl, partial.status), (100, StatusGen1::Frozen));
+        assert_eq!(serde_json::to_string(&DamageRoll::Max).unwrap(), r#""Max""#);
+    }
+}
@@ -0,0 +1,349 @@
+use std::cmp::Ordering;
+
+use crate::battle::{BattlerGen1, crit_chance_gen_1, hit_chance_gen_1};
+use crate::matchup::damage_rolls_gen_1;
+use crate::trainer_ai::TrainerClassGen1;
+use crate::{MoveCategory, MoveEffectGen1, MoveGen1, StatGen1};
+
+/// How dangerous a threat is to a Pokémon at full HP.
+///
+/// Ordered from most to least dangerous.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DangerLevelGen1 {
+    /// Can KO in one hit, with a crit, a high roll or after setting up
+    Ohko,
+    /// Can KO in two hits
+    TwoHko,
+    /// Needs three or more hits
+    Safe,
+}
+
+/// The worst one enemy move can do to one ally, starting from full HP.
+///
+/// # Fields
+/// - `ally`/`enemy`/`move_index`: Party and move indices
+/// - `ally_name`/`enemy_name`/`move_name`: Names for display
+/// - `ally_hp`: The ally's max HP
+/// - `max_damage`: Most damage one use does without a critical hit or
+///   boosts (every hit of a multi-hit move does the same damage, so this is
+///   the highest roll times the most hits)
+/// - `max_crit_damage`: The same with a critical hit (crits ignore stat
+///   stages)
+/// - `boosted_max_damage`: Most damage without a critical hit after every
+///   boost the trainer can plausibly set up, if it can set up at all
+/// - `ko_chance`: Chance one use KOs, counting accuracy, crits and rolls
+/// - `crit_ko_chance`: The part of `ko_chance` that needs a critical hit
+/// - `two_hit_ko_chance`: Chance two uses in a row KO
+/// - `level`: Overall danger, counting boosts
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThreatGen1 {
+    pub ally: usize,
+    pub enemy: usize,
+    pub move_index: usize,
+    pub ally_name: String,
+    pub enemy_name: String,
+    pub move_name: String,
+    pub ally_hp: u16,
+    pub max_damage: u16,
+    pub max_crit_damage: u16,
+    pub boosted_max_damage: Option<u16>,
+    pub ko_chance: f64,
+    pub crit_ko_chance: f64,
+    pub two_hit_ko_chance: f64,
+    pub level: DangerLevelGen1,
+}
+
+impl ThreatGen1 {
+    /// A one-line description of the threat, e.g. "Dewgong's Aurora Beam
+    /// can crit-kill your Jolteon with 3.1% odds".
+    pub fn summary(&self) -> String {
+        let subject = format!("{}'s {}", self.enemy_name, self.move_name);
+        // Summing odds can leave rounding errors either side of zero
+        let roll_ko_chance = self.ko_chance - self.crit_ko_chance;
+        if roll_ko_chance > 1e-12 {
+            format!("{subject} can KO your {} with {:.1}% odds", self.ally_name, percent(self.ko_chance))
+        } else if self.crit_ko_chance > 1e-12 {
+            format!("{subject} can crit-kill your {} with {:.1}% odds", self.ally_name, percent(self.crit_ko_chance))
+        } else if self.level == DangerLevelGen1::Ohko {
+            format!("{subject} can KO your {} after setting up", self.ally_name)
+        } else if self.level == DangerLevelGen1::TwoHko {
+            format!(
+                "{subject} can 2HKO your {} ({:.1}% odds without setup)",
+                self.ally_name,
+                percent(self.two_hit_ko_chance)
+            )
+        } else {
+            format!("{subject} does at most {}/{} HP to your {}", self.max_crit_damage.max(self.max_damage), self.ally_hp, self.ally_name)
+        }
+    }
+}
+
+/// Worst-case analysis of every enemy move against every ally at full HP.
+///
+/// Each threat considers the highest roll, critical hits, and every boost
+/// the trainer can plausibly set up: +6 in the attacking stat if the enemy
+/// knows a move raising it, -6 in the ally's defending stat if it knows a
+/// move lowering that, and the X Attacks the trainer class uses. Threats are
+/// ranked most dangerous first.
+///
+/// One-hit KO moves count as KOs whenever they can land, and multi-hit
+/// moves count each number of hits with its odds.
+///
+/// # Example
+/// ```
+/// use engine::{danger_analysis_gen_1, find_trainers_by_name_gen_1, DangerLevelGen1};
+///
+/// let misty = find_trainers_by_name_gen_1("Misty")[0];
+/// let brock = find_trainers_by_name_gen_1("Brock")[0];
+/// let threats = danger_analysis_gen_1(&brock.battlers(), &misty.battlers(), Some(misty.class));
+///
+/// // Bubble Beam is the biggest threat to Brock's Geodude
+/// assert_eq!(threats[0].move_name, "Bubble Beam");
+/// assert_eq!(threats[0].level, DangerLevelGen1::Ohko);
+/// println!("{}", threats[0].summary());
+/// ```
+pub fn danger_analysis_gen_1(
+    allies: &[BattlerGen1],
+    enemies: &[BattlerGen1],
+    class: Option<TrainerClassGen1>,
+) -> Vec<ThreatGen1> {
+    let mut threats = Vec::new();
+    for (a, ally) in allies.iter().enumerate() {
+        let mut ally = ally.clone();
+        ally.hp = ally.pokemon.stats.hp;
+        for (e, enemy) in enemies.iter().enumerate() {
+            for m in 0..enemy.moves.len() {
+                if let Some(threat) = threat(enemy, &ally, m, (a, e), class) {
+                    threats.push(threat);
+                }
+            }
+        }
+    }
+
+    threats.sort_by(|x, y| {
+        x.level.cmp(&y.level)
+            .then(y.ko_chance.partial_cmp(&x.ko_chance).unwrap_or(Ordering::Equal))
+            .then(y.two_hit_ko_chance.partial_cmp(&x.two_hit_ko_chance).unwrap_or(Ordering::Equal))
+            .then(y.max_crit_damage.max(y.max_damage).cmp(&x.max_crit_damage.max(x.max_damage)))
+    });
+    threats
+}
+
+fn threat(
+    enemy: &BattlerGen1,
+    ally: &BattlerGen1,
+    move_index: usize,
+    (a, e): (usize, usize),
+    class: Option<TrainerClassGen1>,
+) -> Option<ThreatGen1> {
+    let mov = &enemy.moves[move_index];
+    let normal_rolls = damage_rolls_gen_1(enemy, ally, move_index, false);
+    let critical_rolls = damage_rolls_gen_1(enemy, ally, move_index, true);
+    if normal_rolls.is_empty() {
+        return None;
+    }
+
+    // One use: miss, or each roll of a normal or critical hit, repeated
+    // for each number of hits
+    let hit = hit_chance_gen_1(mov);
+    let crit = crit_chance_gen_1(enemy.base_speed, mov);
+    let hits = hit_counts(mov);
+    let normal = totals(&normal_rolls, hits);
+    let critical = totals(&critical_rolls, hits);
+    let mut outcomes = vec![(0u32, 1.0 - hit)];
+    outcomes.extend(normal.iter().map(|&(d, p)| (d, hit * (1.0 - crit) * p)));
+    outcomes.extend(critical.iter().map(|&(d, p)| (d, hit * crit * p)));
+
+    let hp = ally.hp;
+    let crit_ko_chance = hit * crit * chance_at_least(&critical, hp);
+    let ko_chance = hit * (1.0 - crit) * chance_at_least(&normal, hp) + crit_ko_chance;
+    let two_hit_ko_chance: f64 = outcomes.iter()
+        .flat_map(|&(d1, p1)| outcomes.iter().map(move |&(d2, p2)| (d1 + d2, p1 * p2)))
+        .filter(|&(total, _)| total >= hp as u32)
+        .map(|(_, p)| p)
+        .sum();
+
+    let most_hits = hits.last().map_or(1, |&(count, _)| count);
+    let max_total = |rolls: &[u16]| rolls.last().map_or(0, |&d| d.saturating_mul(most_hits));
+    let boosted_max_damage = boosted(enemy, ally, move_index, class)
+        .map(|(enemy, ally)| max_total(&damage_rolls_gen_1(&enemy, &ally, move_index, false)));
+
+    let max_damage = max_total(&normal_rolls);
+    let max_crit_damage = max_total(&critical_rolls);
+    let worst = max_damage.max(max_crit_damage).max(boosted_max_damage.unwrap_or(0));
+    let level = if worst >= hp {
+        DangerLevelGen1::Ohko
+    } else if worst as u32 * 2 >= hp as u32 {
+        DangerLevelGen1::TwoHko
+    } else {
+        DangerLevelGen1::Safe
+    };
+
+    Some(ThreatGen1 {
+        ally: a,
+        enemy: e,
+        move_index,
+        ally_name: ally.pokemon.name.clone(),
+        enemy_name: enemy.pokemon.name.clone(),
+        move_name: mov.name.clone(),
+        ally_hp: hp,
+        max_damage,
+        max_crit_damage,
+        boosted_max_damage,
+        ko_chance,
+        crit_ko_chance,
+        two_hit_ko_chance,
+        level,
+    })
+}
+
+/// The attacker and defender after the trainer's best plausible setup for
+/// this move, or `None` if it can't boost the move at all.
+fn boosted(
+    enemy: &BattlerGen1,
+    ally: &BattlerGen1,
+    move_index: usize,
+    class: Option<TrainerClassGen1>,
+) -> Option<(BattlerGen1, BattlerGen1)> {
+    let category = &enemy.moves[move_index].category;
+    let (attack_stat, defense_stat) = match category {
+        MoveCategory::Physical => (StatGen1::Attack, StatGen1::Defense),
+        MoveCategory::Special => (StatGen1::Special, StatGen1::Special),
+        MoveCategory::Status => return None,
+    };
+
+    let raises = enemy.moves.iter().any(|m| matches!(m.effect, MoveEffectGen1::StatUp(stat, _) if stat == attack_stat));
+    let lowers = enemy.moves.iter().any(|m| matches!(m.effect, MoveEffectGen1::StatDown(stat, _) if stat == defense_stat));
+    let x_attacks = match class {
+        Some(c @ (TrainerClassGen1::Blackbelt | TrainerClassGen1::CooltrainerM | TrainerClassGen1::Koga))
+            if attack_stat == StatGen1::Attack => c.ai_uses() as i8,
+        _ => 0,
+    };
+    if !raises && !lowers && x_attacks == 0 {
+        return None;
+    }
+
+    let mut enemy = enemy.clone();
+    let mut ally = ally.clone();
+    let attack_stage = if raises { 6 } else { x_attacks };
+    match attack_stat {
+        StatGen1::Attack => enemy.pokemon.stat_stages.attack = attack_stage.max(enemy.pokemon.stat_stages.attack),
+        _ => enemy.pokemon.stat_stages.special = attack_stage.max(enemy.pokemon.stat_stages.special),
+    }
+    if lowers {
+        match defense_stat {
+            StatGen1::Defense => ally.pokemon.stat_stages.defense = -6,
+            _ => ally.pokemon.stat_stages.special = -6,
+        }
+    }
+    Some((enemy, ally))
+}
+
+/// A chance as a percentage, never negative (so it can't print as "-0.0").
+fn percent(chance: f64) -> f64 {
+    (chance * 100.0).clamp(0.0, 100.0).abs()
+}
+
+/// How many times a move hits, with the odds of each. Gen 1 repeats the
+/// first hit's damage for every hit.
+fn hit_counts(mov: &MoveGen1) -> &'static [(u16, f64)] {
+    match mov.effect {
+        MoveEffectGen1::MultiHit => &[(2, 3.0 / 8.0), (3, 3.0 / 8.0), (4, 1.0 / 8.0), (5, 1.0 / 8.0)],
+        MoveEffectGen1::TwoHit | MoveEffectGen1::Twineedle => &[(2, 1.0)],
+        _ => &[(1, 1.0)],
+    }
+}
+
+/// The total damage of one use for each roll and number of hits, with the
+/// chance of each given that the move hits.
+fn totals(rolls: &[u16], hits: &[(u16, f64)]) -> Vec<(u32, f64)> {
+    rolls.iter()
+        .flat_map(|&d| hits.iter().map(move |&(count, p)| (d as u32 * count as u32, p / rolls.len() as f64)))
+        .collect()
+}
+
+fn chance_at_least(totals: &[(u32, f64)], hp: u16) -> f64 {
+    totals.iter().filter(|&&(d, _)| d >= hp as u32).map(|&(_, p)| p).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_trainers_by_name_gen_1;
+
+    /// Crit-only KOs are reported separately and setup raises the danger.
+    #[test]
+    fn test_threat_levels() {
+        let koga = find_trainers_by_name_gen_1("Koga")[0];
+        let erika = find_trainers_by_name_gen_1("Erika")[0];
+        let threats = danger_analysis_gen_1(&erika.battlers(), &koga.battlers(), Some(koga.class));
+
+        assert!(threats.windows(2).all(|pair| pair[0].level <= pair[1].level));
+        for threat in &threats {
+            assert!(threat.crit_ko_chance <= threat.ko_chance + 1e-12);
+            assert!(threat.ko_chance <= threat.two_hit_ko_chance + 1e-12);
+        }
+
+        // Koga's X Attacks make physical moves more dangerous
+        let tackle = threats.iter()
+            .find(|t| t.enemy == 0 && t.move_name == "Tackle" && t.ally == 1)
+            .unwrap();
+        assert!(tackle.boosted_max_damage.unwrap() > tackle.max_damage);
+    }
+
+    /// One-hit KO moves KO whenever they land, and multi-hit moves count
+    /// every hit.
+    #[test]
+    fn test_ohko_and_multi_hit() {
+        let giovanni = find_trainers_by_name_gen_1("Giovanni");
+        let viridian = giovanni.iter()
+            .find(|t| t.location == "Viridian Gym" && t.version == crate::VersionGen1::RedBlue)
+            .unwrap();
+        let brock = find_trainers_by_name_gen_1("Brock")[0].battlers();
+        let threats = danger_analysis_gen_1(&brock, &viridian.battlers(), Some(viridian.class));
+        let fissure = threats.iter().find(|t| t.move_name == "Fissure" && t.ally_name == "Onix").unwrap();
+        assert_eq!(fissure.level, DangerLevelGen1::Ohko);
+        assert!((fissure.ko_chance - 0.3).abs() < 0.01);
+        assert!(fissure.summary().contains("can KO your Onix"));
+
+        let yellow = giovanni.iter()
+            .find(|t| t.location == "Viridian Gym" && t.version == crate::VersionGen1::Yellow)
+            .unwrap();
+        let enemies = yellow.battlers();
+        let rhydon = enemies.iter().position(|b| b.pokemon.name == "Rhydon").unwrap();
+        let fury_attack = enemies[rhydon].moves.iter().position(|m| m.name == "Fury Attack").unwrap();
+        let threats = danger_analysis_gen_1(&brock, &enemies, Some(yellow.class));
+        let threat = threats.iter()
+            .find(|t| t.enemy == rhydon && t.move_index == fury_attack && t.ally == 0)
+            .unwrap();
+        let one_hit = *damage_rolls_gen_1(&enemies[rhydon], &brock[0], fury_attack, false).last().unwrap();
+        assert_eq!(threat.max_damage, one_hit * 5);
+    }
+
+    /// Rounding errors in the odds never show up as negative percentages.
+    #[test]
+    fn test_summary_rounding() {
+        let mut threat = ThreatGen1 {
+            ally: 0,
+            enemy: 0,
+            move_index: 0,
+            ally_name: "Onix".into(),
+            enemy_name: "Rhydon".into(),
+            move_name: "Stomp".into(),
+            ally_hp: 100,
+            max_damage: 60,
+            max_crit_damage: 90,
+            boosted_max_damage: None,
+            ko_chance: 0.0,
+            crit_ko_chance: 1e-17,
+            two_hit_ko_chance: -1e-18,
+            level: DangerLevelGen1::TwoHko,
+        };
+        assert_eq!(threat.summary(), "Rhydon's Stomp can 2HKO your Onix (0.0% odds without setup)");
+        threat.ko_chance = 0.5;
+        threat.crit_ko_chance = 0.5 + 1e-17;
+        assert_eq!(threat.summary(), "Rhydon's Stomp can crit-kill your Onix with 50.0% odds");
+    }
+}
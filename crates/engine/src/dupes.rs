@@ -0,0 +1,143 @@
+use crate::SpeciesGen1;
+use crate::encounters::EncounterTableGen1;
+use crate::evolution::same_evolution_line_gen_1;
+
+/// How a dupes clause works.
+///
+/// # Fields
+/// - `rerolls`: How many dupes in a row may be skipped in one area, or
+///   `None` for as many as it takes
+/// - `evolution_lines`: Whether anything in the same evolution line as a
+///   caught Pokémon counts as a dupe, rather than only the same species
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DupesClauseGen1 {
+    pub rerolls: Option<u8>,
+    pub evolution_lines: bool,
+}
+
+/// The chance of one species being the encounter that counts in an area.
+///
+/// # Fields
+/// - `species`: The species
+/// - `chance`: Chance it's the encounter that counts
+/// - `dupe`: Whether it's a dupe, which only happens once the rerolls run
+///   out
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FirstEncounterChanceGen1 {
+    pub species: &'static SpeciesGen1,
+    pub chance: f64,
+    pub dupe: bool,
+}
+
+impl DupesClauseGen1 {
+    /// Whether `species` is a dupe of anything in `caught`.
+    pub fn is_dupe(&self, species: &'static SpeciesGen1, caught: &[&'static SpeciesGen1]) -> bool {
+        caught.iter().any(|&c| {
+            c.dex == species.dex || (self.evolution_lines && same_evolution_line_gen_1(c, species))
+        })
+    }
+}
+
+impl Default for DupesClauseGen1 {
+    /// Unlimited rerolls, with whole evolution lines counting as dupes.
+    fn default() -> Self {
+        Self { rerolls: None, evolution_lines: true }
+    }
+}
+
+/// The chance of each species being an area's first valid encounter when
+/// dupes are skipped, most likely first.
+///
+/// Every encounter is an independent draw from the table. A dupe is skipped
+/// while rerolls remain; after that the next encounter counts whatever it
+/// is. If everything in the table is a dupe, the clause can't help and the
+/// table's own odds are returned.
+///
+/// # Example
+/// ```
+/// use engine::{
+///     find_encounters_by_area_gen_1, find_species_gen_1, first_encounter_odds_gen_1,
+///     DupesClauseGen1, GameGen1,
+/// };
+///
+/// // With a Pidgeotto already caught, Route 1 always gives a Rattata
+/// let route_1 = find_encounters_by_area_gen_1("Route 1", GameGen1::Red)[0];
+/// let caught = [find_species_gen_1("Pidgeotto").unwrap()];
+/// let odds = first_encounter_odds_gen_1(route_1, &caught, &DupesClauseGen1::default());
+/// assert_eq!(odds[0].species.name, "Rattata");
+/// assert!((odds[0].chance - 1.0).abs() < 1e-9);
+/// ```
+pub fn first_encounter_odds_gen_1(
+    table: &EncounterTableGen1,
+    caught: &[&'static SpeciesGen1],
+    clause: &DupesClauseGen1,
+) -> Vec<FirstEncounterChanceGen1> {
+    let distribution = table.distribution();
+    let dupe_chance: f64 = distribution.iter()
+        .filter(|c| clause.is_dupe(c.species, caught))
+        .map(|c| c.chance)
+        .sum();
+
+    // Chance of every allowed reroll being used up on dupes
+    let exhausted = match clause.rerolls {
+        _ if dupe_chance >= 1.0 => 1.0,
+        Some(rerolls) => dupe_chance.powi(rerolls as i32),
+        None => 0.0,
+    };
+    // Chance of reaching each draw, summed over the draws that may count
+    let draws = if dupe_chance >= 1.0 { 0.0 } else { (1.0 - exhausted * dupe_chance) / (1.0 - dupe_chance) };
+
+    let mut odds: Vec<FirstEncounterChanceGen1> = distribution.iter()
+        .map(|c| {
+            let dupe = clause.is_dupe(c.species, caught);
+            let chance = if dupe { c.chance * exhausted } else { c.chance * draws };
+            FirstEncounterChanceGen1 { species: c.species, chance, dupe }
+        })
+        .filter(|c| c.chance > 0.0)
+        .collect();
+    odds.sort_by(|a, b| b.chance.total_cmp(&a.chance));
+    odds
+}
+
+/// The chance an area's first valid encounter is something new.
+pub fn new_species_chance_gen_1(
+    table: &EncounterTableGen1,
+    caught: &[&'static SpeciesGen1],
+    clause: &DupesClauseGen1,
+) -> f64 {
+    first_encounter_odds_gen_1(table, caught, clause).iter()
+        .filter(|c| !c.dupe)
+        .map(|c| c.chance)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GameGen1, find_encounters_by_area_gen_1, find_species_gen_1};
+
+    /// Limited rerolls leave some chance of a dupe, and the odds always add
+    /// up to one.
+    #[test]
+    fn test_limited_rerolls() {
+        let forest = find_encounters_by_area_gen_1("Viridian Forest", GameGen1::Blue)[0];
+        let caught = [find_species_gen_1("Butterfree").unwrap()];
+        let species_only = DupesClauseGen1 { rerolls: Some(1), evolution_lines: false };
+        let lines = DupesClauseGen1 { evolution_lines: true, ..species_only };
+
+        // Butterfree itself isn't in the forest, so only its line matters
+        assert!((new_species_chance_gen_1(forest, &caught, &species_only) - 1.0).abs() < 1e-9);
+
+        // Caterpie and Metapod are 216/256 of the forest; one reroll leaves
+        // (216/256)^2 of a dupe
+        let dupe = 216.0 / 256.0;
+        let odds = first_encounter_odds_gen_1(forest, &caught, &lines);
+        let total: f64 = odds.iter().map(|c| c.chance).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!((new_species_chance_gen_1(forest, &caught, &lines) - (1.0 - dupe * dupe)).abs() < 1e-9);
+
+        let pikachu = odds.iter().find(|c| c.species.name == "Pikachu").unwrap();
+        assert!((pikachu.chance - 14.0 / 256.0 * (1.0 + dupe)).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{PokemonGen1, SpeciesGen1, StatsGen1};
+use crate::pokemon::{EVsGen1, IVsGen1};
+
+/// A Pokémon's stats as shown on its summary screen at one level.
+///
+/// # Fields
+/// - `stats`: The displayed stats, with `lvl` set to its level
+/// - `evs`: An estimate of its stat experience at the time; all 0 for a
+///   freshly caught Pokémon
+#[derive(Debug, Clone)]
+pub struct StatObservationGen1 {
+    pub stats: StatsGen1,
+    pub evs: EVsGen1,
+}
+
+/// The DVs a Pokémon could have for each stat, in increasing order.
+///
+/// The HP DV isn't rolled separately in Gen 1: its bits are the lowest bits
+/// of the Attack, Defense, Speed and Special DVs, in that order. So the HP
+/// DVs and the other stats narrow each other down.
+///
+/// # Fields
+/// - `hp`: Possible HP DVs
+/// - `attack`: Possible Attack DVs
+/// - `defense`: Possible Defense DVs
+/// - `special`: Possible Special DVs
+/// - `speed`: Possible Speed DVs
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DvRangesGen1 {
+    pub hp: Vec<u8>,
+    pub attack: Vec<u8>,
+    pub defense: Vec<u8>,
+    pub special: Vec<u8>,
+    pub speed: Vec<u8>,
+}
+
+impl DvRangesGen1 {
+    /// The lowest possible DV for each stat.
+    pub fn min(&self) -> IVsGen1 {
+        self.pick(|dvs| dvs[0])
+    }
+
+    /// The highest possible DV for each stat.
+    pub fn max(&self) -> IVsGen1 {
+        self.pick(|dvs| dvs[dvs.len() - 1])
+    }
+
+    /// The DVs, if every stat has been narrowed down to one.
+    pub fn exact(&self) -> Option<IVsGen1> {
+        self.all().iter().all(|dvs| dvs.len() == 1).then(|| self.min())
+    }
+
+    fn all(&self) -> [&Vec<u8>; 5] {
+        [&self.hp, &self.attack, &self.defense, &self.special, &self.speed]
+    }
+
+    fn pick(&self, pick: impl Fn(&[u8]) -> u8) -> IVsGen1 {
+        IVsGen1 {
+            hp: pick(&self.hp),
+            attack: pick(&self.attack),
+            defense: pick(&self.defense),
+            special: pick(&self.special),
+            speed: pick(&self.speed),
+        }
+    }
+}
+
+/// The HP DV that goes with the other four DVs.
+///
+/// # Example
+/// ```
+/// use engine::hp_dv_gen_1;
+///
+/// // Trainer DVs: Attack 9, everything else 8
+/// assert_eq!(hp_dv_gen_1(9, 8, 8, 8), 8);
+/// assert_eq!(hp_dv_gen_1(15, 15, 15, 15), 15);
+/// ```
+pub fn hp_dv_gen_1(attack: u8, defense: u8, special: u8, speed: u8) -> u8 {
+    (attack & 1) << 3 | (defense & 1) << 2 | (speed & 1) << 1 | (special & 1)
+}
+
+/// The DVs a Pokémon of `species` could have, given its stats at one or
+/// more levels. Each observation rules out DVs that don't give its stats;
+/// the more levels seen, the narrower the ranges. `None` if no DVs fit,
+/// which usually means a stat or the stat experience estimate is wrong.
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, infer_dvs_gen_1, StatObservationGen1};
+/// use engine::pokemon::IVsGen1;
+///
+/// let abra = find_species_gen_1("Abra").unwrap();
+/// let ivs = IVsGen1 { hp: 5, attack: 2, defense: 13, special: 15, speed: 10 };
+/// let observed = |level| StatObservationGen1 { stats: abra.pokemon(level, ivs.clone()).stats, evs: Default::default() };
+///
+/// let at_6 = infer_dvs_gen_1(abra, &[observed(6)]).unwrap();
+/// let at_50 = infer_dvs_gen_1(abra, &[observed(6), observed(50)]).unwrap();
+/// assert!(at_50.special.len() < at_6.special.len());
+/// assert!(at_50.special.contains(&15));
+/// ```
+pub fn infer_dvs_gen_1(species: &SpeciesGen1, observations: &[StatObservationGen1]) -> Option<DvRangesGen1> {
+    let base = &species.base;
+    let fits = |base_stat: u8, is_hp: bool, stat: fn(&StatsGen1) -> u16, ev: fn(&EVsGen1) -> u16| -> Vec<u8> {
+        (0..16)
+            .filter(|&dv| {
+                observations.iter().all(|o| {
+                    let pokemon = PokemonGen1 { stats: StatsGen1 { lvl: o.stats.lvl, ..Default::default() }, ..Default::default() };
+                    pokemon.calculate_stat(base_stat, dv, ev(&o.evs), is_hp) == stat(&o.stats)
+                })
+            })
+            .collect()
+    };
+    let hp = fits(base.hp, true, |s| s.hp, |e| e.hp);
+    let attack = fits(base.attack, false, |s| s.attack, |e| e.attack);
+    let defense = fits(base.defense, false, |s| s.defense, |e| e.defense);
+    let special = fits(base.special, false, |s| s.special, |e| e.special);
+    let speed = fits(base.speed, false, |s| s.speed, |e| e.speed);
+
+    // Keep only the DVs that some full set of DVs, HP included, agrees with
+    let mut ranges = DvRangesGen1 { hp: Vec::new(), attack: Vec::new(), defense: Vec::new(), special: Vec::new(), speed: Vec::new() };
+    for &a in &attack {
+        for &d in &defense {
+            for &sc in &special {
+                for &sp in &speed {
+                    let h = hp_dv_gen_1(a, d, sc, sp);
+                    if hp.contains(&h) {
+                        for (dvs, dv) in [(&mut ranges.hp, h), (&mut ranges.attack, a), (&mut ranges.defense, d),
+                            (&mut ranges.special, sc), (&mut ranges.speed, sp)] {
+                            if !dvs.contains(&dv) {
+                                dvs.push(dv);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    if ranges.hp.is_empty() {
+        return None;
+    }
+    for dvs in [&mut ranges.hp, &mut ranges.attack, &mut ranges.defense, &mut ranges.special, &mut ranges.speed] {
+        dvs.sort_unstable();
+    }
+    Some(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_species_gen_1;
+
+    fn observe(species: &SpeciesGen1, level: u8, ivs: &IVsGen1, evs: EVsGen1) -> StatObservationGen1 {
+        let mut pokemon = species.pokemon(level, ivs.clone());
+        pokemon.evs = evs.clone();
+        species.calculate_stats(&mut pokemon);
+        StatObservationGen1 { stats: pokemon.stats, evs }
+    }
+
+    /// The true DVs always survive, and observations across many levels pin
+    /// them down.
+    #[test]
+    fn test_narrowing() {
+        let magikarp = find_species_gen_1("Magikarp").unwrap();
+        let ivs = IVsGen1 { hp: 0, attack: 6, defense: 3, special: 11, speed: 2 };
+        let ivs = IVsGen1 { hp: hp_dv_gen_1(ivs.attack, ivs.defense, ivs.special, ivs.speed), ..ivs };
+        let observations: Vec<_> = [5, 20, 35, 50]
+            .map(|level| observe(magikarp, level, &ivs, EVsGen1 { speed: 2500, ..Default::default() }))
+            .into();
+
+        let first = infer_dvs_gen_1(magikarp, &observations[..1]).unwrap();
+        assert!(first.min().attack <= 6 && first.max().attack >= 6);
+        let all = infer_dvs_gen_1(magikarp, &observations).unwrap();
+        assert_eq!(all.exact().map(|dvs| [dvs.hp, dvs.attack, dvs.defense, dvs.special, dvs.speed]), Some([5, 6, 3, 11, 2]));
+    }
+
+    /// The HP DV rules out parities of the other DVs, and impossible stats
+    /// give nothing.
+    #[test]
+    fn test_hp_dv() {
+        let snorlax = find_species_gen_1("Snorlax").unwrap();
+        let ivs = IVsGen1 { hp: 15, attack: 15, defense: 15, special: 15, speed: 15 };
+        // At level 10 only a 15 gives 55 HP, but the other stats are vague
+        let at_10 = observe(snorlax, 10, &ivs, EVsGen1::default());
+        let dvs = infer_dvs_gen_1(snorlax, std::slice::from_ref(&at_10)).unwrap();
+        assert_eq!(dvs.hp, [15]);
+        assert!(dvs.attack.iter().chain(&dvs.defense).chain(&dvs.special).chain(&dvs.speed).all(|dv| dv % 2 == 1));
+
+        let wrong = StatObservationGen1 { stats: StatsGen1 { speed: 1, ..at_10.stats }, ..at_10 };
+        assert_eq!(infer_dvs_gen_1(snorlax, &[wrong]), None);
+    }
+}
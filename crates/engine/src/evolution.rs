@@ -0,0 +1,312 @@
+use crate::{PokemonGen1, SpeciesGen1, StatsGen1, VersionGen1};
+use crate::learnsets::learnset_in_version_gen_1;
+use crate::species::find_species_gen_1;
+
+/// The evolution stones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StoneGen1 {
+    Moon,
+    Fire,
+    Thunder,
+    Water,
+    Leaf,
+}
+
+/// What makes a Pokémon evolve.
+///
+/// # Variants
+/// - `Level`: Reaching a level
+/// - `Stone`: Using an evolution stone
+/// - `Trade`: Being traded
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EvolutionMethodGen1 {
+    Level(u8),
+    Stone(StoneGen1),
+    Trade,
+}
+
+/// One species evolving into another.
+///
+/// # Fields
+/// - `from`: Species name before evolving
+/// - `to`: Species name after evolving
+/// - `method`: What makes it evolve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EvolutionGen1 {
+    pub from: &'static str,
+    pub to: &'static str,
+    pub method: EvolutionMethodGen1,
+}
+
+/// What evolving a Pokémon now would mean.
+///
+/// # Fields
+/// - `evolution`: The evolution
+/// - `ready`: Whether it can evolve now (always true for stones and trades)
+/// - `missed_moves`: Moves it would learn by leveling up later if it
+///   stayed as it is, but never will if it evolves now, as `(level, move)`
+/// - `delayed_moves`: Moves both forms learn later, but the evolution
+///   learns later still, as `(move, level now, level after evolving)`
+/// - `before`/`after`: Its stats now and once evolved, at the same level
+#[derive(Debug, Clone)]
+pub struct EvolutionPlanGen1 {
+    pub evolution: &'static EvolutionGen1,
+    pub ready: bool,
+    pub missed_moves: Vec<(u8, &'static str)>,
+    pub delayed_moves: Vec<(&'static str, u8, u8)>,
+    pub before: StatsGen1,
+    pub after: StatsGen1,
+}
+
+impl StoneGen1 {
+    /// The stone's in-game name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            StoneGen1::Moon => "Moon Stone",
+            StoneGen1::Fire => "Fire Stone",
+            StoneGen1::Thunder => "Thunder Stone",
+            StoneGen1::Water => "Water Stone",
+            StoneGen1::Leaf => "Leaf Stone",
+        }
+    }
+}
+
+impl EvolutionGen1 {
+    /// The species before evolving.
+    pub fn from_species(&self) -> &'static SpeciesGen1 {
+        find_species_gen_1(self.from).expect("evolutions use known species")
+    }
+
+    /// The species after evolving.
+    pub fn to_species(&self) -> &'static SpeciesGen1 {
+        find_species_gen_1(self.to).expect("evolutions use known species")
+    }
+}
+
+use EvolutionMethodGen1::{Level, Stone, Trade};
+
+/// Every evolution, in Pokédex order of the species evolving. Gen 1 has no
+/// baby Pokémon, so Hitmonlee and Hitmonchan aren't related to anything.
+static EVOLUTIONS_GEN_1: [EvolutionGen1; 72] = [
+    evolution("Bulbasaur", "Ivysaur", Level(16)),
+    evolution("Ivysaur", "Venusaur", Level(32)),
+    evolution("Charmander", "Charmeleon", Level(16)),
+    evolution("Charmeleon", "Charizard", Level(36)),
+    evolution("Squirtle", "Wartortle", Level(16)),
+    evolution("Wartortle", "Blastoise", Level(36)),
+    evolution("Caterpie", "Metapod", Level(7)),
+    evolution("Metapod", "Butterfree", Level(10)),
+    evolution("Weedle", "Kakuna", Level(7)),
+    evolution("Kakuna", "Beedrill", Level(10)),
+    evolution("Pidgey", "Pidgeotto", Level(18)),
+    evolution("Pidgeotto", "Pidgeot", Level(36)),
+    evolution("Rattata", "Raticate", Level(20)),
+    evolution("Spearow", "Fearow", Level(20)),
+    evolution("Ekans", "Arbok", Level(22)),
+    evolution("Pikachu", "Raichu", Stone(StoneGen1::Thunder)),
+    evolution("Sandshrew", "Sandslash", Level(22)),
+    evolution("Nidoran♀", "Nidorina", Level(16)),
+    evolution("Nidorina", "Nidoqueen", Stone(StoneGen1::Moon)),
+    evolution("Nidoran♂", "Nidorino", Level(16)),
+    evolution("Nidorino", "Nidoking", Stone(StoneGen1::Moon)),
+    evolution("Clefairy", "Clefable", Stone(StoneGen1::Moon)),
+    evolution("Vulpix", "Ninetales", Stone(StoneGen1::Fire)),
+    evolution("Jigglypuff", "Wigglytuff", Stone(StoneGen1::Moon)),
+    evolution("Zubat", "Golbat", Level(22)),
+    evolution("Oddish", "Gloom", Level(21)),
+    evolution("Gloom", "Vileplume", Stone(StoneGen1::Leaf)),
+    evolution("Paras", "Parasect", Level(24)),
+    evolution("Venonat", "Venomoth", Level(31)),
+    evolution("Diglett", "Dugtrio", Level(26)),
+    evolution("Meowth", "Persian", Level(28)),
+    evolution("Psyduck", "Golduck", Level(33)),
+    evolution("Mankey", "Primeape", Level(28)),
+    evolution("Growlithe", "Arcanine", Stone(StoneGen1::Fire)),
+    evolution("Poliwag", "Poliwhirl", Level(25)),
+    evolution("Poliwhirl", "Poliwrath", Stone(StoneGen1::Water)),
+    evolution("Abra", "Kadabra", Level(16)),
+    evolution("Kadabra", "Alakazam", Trade),
+    evolution("Machop", "Machoke", Level(28)),
+    evolution("Machoke", "Machamp", Trade),
+    evolution("Bellsprout", "Weepinbell", Level(21)),
+    evolution("Weepinbell", "Victreebel", Stone(StoneGen1::Leaf)),
+    evolution("Tentacool", "Tentacruel", Level(30)),
+    evolution("Geodude", "Graveler", Level(25)),
+    evolution("Graveler", "Golem", Trade),
+    evolution("Ponyta", "Rapidash", Level(40)),
+    evolution("Slowpoke", "Slowbro", Level(37)),
+    evolution("Magnemite", "Magneton", Level(30)),
+    evolution("Doduo", "Dodrio", Level(31)),
+    evolution("Seel", "Dewgong", Level(34)),
+    evolution("Grimer", "Muk", Level(38)),
+    evolution("Shellder", "Cloyster", Stone(StoneGen1::Water)),
+    evolution("Gastly", "Haunter", Level(25)),
+    evolution("Haunter", "Gengar", Trade),
+    evolution("Drowzee", "Hypno", Level(26)),
+    evolution("Krabby", "Kingler", Level(28)),
+    evolution("Voltorb", "Electrode", Level(30)),
+    evolution("Exeggcute", "Exeggutor", Stone(StoneGen1::Leaf)),
+    evolution("Cubone", "Marowak", Level(28)),
+    evolution("Koffing", "Weezing", Level(35)),
+    evolution("Rhyhorn", "Rhydon", Level(42)),
+    evolution("Horsea", "Seadra", Level(32)),
+    evolution("Goldeen", "Seaking", Level(33)),
+    evolution("Staryu", "Starmie", Stone(StoneGen1::Water)),
+    evolution("Magikarp", "Gyarados", Level(20)),
+    evolution("Eevee", "Vaporeon", Stone(StoneGen1::Water)),
+    evolution("Eevee", "Jolteon", Stone(StoneGen1::Thunder)),
+    evolution("Eevee", "Flareon", Stone(StoneGen1::Fire)),
+    evolution("Omanyte", "Omastar", Level(40)),
+    evolution("Kabuto", "Kabutops", Level(40)),
+    evolution("Dratini", "Dragonair", Level(30)),
+    evolution("Dragonair", "Dragonite", Level(55)),
+];
+
+/// What a species can evolve into. Only Eevee has more than one option.
+pub fn evolutions_gen_1(species: &SpeciesGen1) -> Vec<&'static EvolutionGen1> {
+    EVOLUTIONS_GEN_1.iter().filter(|e| e.from == species.name).collect()
+}
+
+/// What a species evolves from, if anything.
+pub fn pre_evolution_gen_1(species: &SpeciesGen1) -> Option<&'static EvolutionGen1> {
+    EVOLUTIONS_GEN_1.iter().find(|e| e.to == species.name)
+}
+
+/// Every species in the same evolution line as `species`, first stage
+/// first. Species that don't evolve are alone in their line.
+///
+/// # Example
+/// ```
+/// use engine::{evolution_line_gen_1, find_species_gen_1};
+///
+/// let line = evolution_line_gen_1(find_species_gen_1("Kadabra").unwrap());
+/// let names: Vec<_> = line.iter().map(|s| s.name).collect();
+/// assert_eq!(names, ["Abra", "Kadabra", "Alakazam"]);
+/// ```
+pub fn evolution_line_gen_1(species: &'static SpeciesGen1) -> Vec<&'static SpeciesGen1> {
+    let mut first = species;
+    while let Some(evolution) = pre_evolution_gen_1(first) {
+        first = evolution.from_species();
+    }
+
+    let mut line = vec![first];
+    let mut i = 0;
+    while let Some(&stage) = line.get(i) {
+        line.extend(evolutions_gen_1(stage).into_iter().map(EvolutionGen1::to_species));
+        i += 1;
+    }
+    line
+}
+
+/// Whether two species are in the same evolution line.
+pub fn same_evolution_line_gen_1(a: &'static SpeciesGen1, b: &'static SpeciesGen1) -> bool {
+    evolution_line_gen_1(a).iter().any(|s| s.dex == b.dex)
+}
+
+/// What evolving a Pokémon of `species` now would mean in `version`, for
+/// each thing it can evolve into: the level-up moves it would give up, and
+/// its stats before and after at its current level, DVs and stat
+/// experience.
+///
+/// # Example
+/// ```
+/// use engine::{evolution_plans_gen_1, find_species_gen_1, TRAINER_DVS_GEN_1, VersionGen1};
+///
+/// // Using a Moon Stone on Nidorino gives up the rest of its level-up moves
+/// let nidorino = find_species_gen_1("Nidorino").unwrap();
+/// let plans = evolution_plans_gen_1(nidorino, &nidorino.pokemon(20, TRAINER_DVS_GEN_1), VersionGen1::RedBlue);
+/// assert_eq!(plans[0].evolution.to, "Nidoking");
+/// assert_eq!(plans[0].missed_moves, [(23, "Focus Energy"), (32, "Fury Attack"), (41, "Horn Drill"), (50, "Double Kick")]);
+/// assert!(plans[0].after.attack > plans[0].before.attack);
+/// ```
+pub fn evolution_plans_gen_1(species: &SpeciesGen1, pokemon: &PokemonGen1, version: VersionGen1) -> Vec<EvolutionPlanGen1> {
+    let level = pokemon.stats.lvl;
+    let learnset = learnset_in_version_gen_1(species, version);
+    evolutions_gen_1(species).into_iter()
+        .map(|evolution| {
+            let evolved = evolution.to_species();
+            let evolved_learnset = learnset_in_version_gen_1(evolved, version);
+            let later_in_evolution = |name: &str| {
+                evolved_learnset.level_up.iter().find(|&&(l, m)| l >= level && m == name).map(|&(l, _)| l)
+            };
+
+            let mut missed_moves = Vec::new();
+            let mut delayed_moves = Vec::new();
+            for &(learned, name) in learnset.level_up.iter().filter(|&&(l, _)| l > level) {
+                match later_in_evolution(name) {
+                    None => missed_moves.push((learned, name)),
+                    Some(evolved_level) if evolved_level > learned => delayed_moves.push((name, learned, evolved_level)),
+                    Some(_) => {}
+                }
+            }
+
+            let mut after = pokemon.clone();
+            evolved.calculate_stats(&mut after);
+            EvolutionPlanGen1 {
+                evolution,
+                ready: !matches!(evolution.method, EvolutionMethodGen1::Level(min) if level < min),
+                missed_moves,
+                delayed_moves,
+                before: pokemon.stats.clone(),
+                after: after.stats,
+            }
+        })
+        .collect()
+}
+
+const fn evolution(from: &'static str, to: &'static str, method: EvolutionMethodGen1) -> EvolutionGen1 {
+    EvolutionGen1 { from, to, method }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TRAINER_DVS_GEN_1;
+
+    fn species(name: &str) -> &'static SpeciesGen1 {
+        find_species_gen_1(name).unwrap()
+    }
+
+    /// Lines run first stage first, and Eevee branches three ways.
+    #[test]
+    fn test_evolution_lines() {
+        let names = |name| evolution_line_gen_1(species(name)).iter().map(|s| s.name).collect::<Vec<_>>();
+        assert_eq!(names("Flareon"), ["Eevee", "Vaporeon", "Jolteon", "Flareon"]);
+        assert_eq!(names("Charizard"), ["Charmander", "Charmeleon", "Charizard"]);
+        assert_eq!(names("Hitmonlee"), ["Hitmonlee"]);
+        assert!(!same_evolution_line_gen_1(species("Vaporeon"), species("Poliwag")));
+        assert!(EVOLUTIONS_GEN_1.iter().all(|e| e.from_species().dex < e.to_species().dex));
+    }
+
+    /// Evolving early delays Charmeleon's Slash, and a level evolution
+    /// isn't ready before its level.
+    #[test]
+    fn test_evolution_plans() {
+        let charmander = species("Charmander");
+        let plan = &evolution_plans_gen_1(charmander, &charmander.pokemon(15, TRAINER_DVS_GEN_1), VersionGen1::RedBlue)[0];
+        assert!(!plan.ready);
+        assert_eq!(plan.delayed_moves, [("Rage", 22, 24), ("Slash", 30, 33), ("Flamethrower", 38, 42), ("Fire Spin", 46, 56)]);
+        assert!(plan.missed_moves.is_empty());
+
+        let eevee = species("Eevee");
+        let plans = evolution_plans_gen_1(eevee, &eevee.pokemon(25, TRAINER_DVS_GEN_1), VersionGen1::RedBlue);
+        assert_eq!(plans.len(), 3);
+        assert!(plans.iter().all(|p| p.ready));
+        // Jolteon never learns Bite
+        assert_eq!(plans[1].missed_moves, [(37, "Bite"), (45, "Take Down")]);
+    }
+
+    /// Yellow's Pikachu gives up Thunderbolt by evolving; Red and Blue's
+    /// has no such move to miss.
+    #[test]
+    fn test_yellow_evolution_plans() {
+        let pikachu = species("Pikachu");
+        let pokemon = pikachu.pokemon(20, TRAINER_DVS_GEN_1);
+        let missed = |version| evolution_plans_gen_1(pikachu, &pokemon, version)[0].missed_moves.clone();
+        assert!(missed(VersionGen1::Yellow).contains(&(26, "Thunderbolt")));
+        assert!(!missed(VersionGen1::RedBlue).iter().any(|&(_, name)| name == "Thunderbolt"));
+    }
+}
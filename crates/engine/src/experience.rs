@@ -0,0 +1,375 @@
+use crate::SpeciesGen1;
+use crate::pokemon::EVsGen1;
+use crate::trainers::TrainerGen1;
+use crate::species::find_species_gen_1;
+
+/// How much experience a species needs to level up.
+///
+/// # Variants
+/// - `MediumFast`: level³
+/// - `MediumSlow`: 6/5·level³ − 15·level² + 100·level − 140
+/// - `Fast`: 4/5·level³
+/// - `Slow`: 5/4·level³
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GrowthRateGen1 {
+    MediumFast,
+    MediumSlow,
+    Fast,
+    Slow,
+}
+
+/// How experience from a battle is handed out.
+///
+/// # Fields
+/// - `trainer`: Whether it's a trainer battle, which gives 1.5× experience
+/// - `participants`: How many party members battled the defeated Pokémon
+/// - `exp_all`: Whether the Exp. All is in the bag
+/// - `party_size`: How many party members get the Exp. All's share
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BattleExpGen1 {
+    pub trainer: bool,
+    pub participants: u8,
+    pub exp_all: bool,
+    pub party_size: u8,
+}
+
+/// What one party member gains from a defeated Pokémon.
+///
+/// # Fields
+/// - `exp`: Experience points
+/// - `stat_exp`: Stat experience for each stat
+#[derive(Debug, Clone, Default)]
+pub struct ExpGainGen1 {
+    pub exp: u32,
+    pub stat_exp: EVsGen1,
+}
+
+/// A party member's experience and stat experience, for planning.
+///
+/// # Fields
+/// - `species`: Its species, which decides its growth rate
+/// - `exp`: Total experience points
+/// - `evs`: Stat experience
+/// - `traded`: Whether it was traded for, which gives 1.5× experience
+#[derive(Debug, Clone)]
+pub struct LevelingPokemonGen1 {
+    pub species: &'static SpeciesGen1,
+    pub exp: u32,
+    pub evs: EVsGen1,
+    pub traded: bool,
+}
+
+/// A battle the party is planning to fight.
+///
+/// # Fields
+/// - `opponents`: The Pokémon to defeat, as `(species, level)`
+/// - `trainer`: Whether it's a trainer battle
+/// - `participants`: Indices of the party members that battle each opponent
+/// - `exp_all`: Whether the Exp. All is in the bag
+#[derive(Debug, Clone)]
+pub struct PlannedBattleGen1 {
+    pub opponents: Vec<(&'static SpeciesGen1, u8)>,
+    pub trainer: bool,
+    pub participants: Vec<usize>,
+    pub exp_all: bool,
+}
+
+impl GrowthRateGen1 {
+    /// Total experience needed to reach `level`. Level 1 needs none.
+    ///
+    /// # Example
+    /// ```
+    /// use engine::GrowthRateGen1;
+    ///
+    /// assert_eq!(GrowthRateGen1::MediumSlow.exp_for_level(5), 135);
+    /// assert_eq!(GrowthRateGen1::Slow.exp_for_level(100), 1_250_000);
+    /// ```
+    pub fn exp_for_level(&self, level: u8) -> u32 {
+        let n = level.clamp(1, 100) as i64;
+        let exp = match self {
+            GrowthRateGen1::MediumFast => n * n * n,
+            GrowthRateGen1::MediumSlow => 6 * n * n * n / 5 - 15 * n * n + 100 * n - 140,
+            GrowthRateGen1::Fast => 4 * n * n * n / 5,
+            GrowthRateGen1::Slow => 5 * n * n * n / 4,
+        };
+        if n == 1 { 0 } else { exp.max(0) as u32 }
+    }
+
+    /// The level a Pokémon with `exp` experience is at.
+    pub fn level_for_exp(&self, exp: u32) -> u8 {
+        (1..=100).rev().find(|&level| exp >= self.exp_for_level(level)).unwrap_or(1)
+    }
+}
+
+impl LevelingPokemonGen1 {
+    /// A Pokémon that has just reached `level`, with no stat experience.
+    pub fn new(species: &'static SpeciesGen1, level: u8) -> Self {
+        Self { species, exp: growth_rate_gen_1(species).exp_for_level(level), evs: EVsGen1::default(), traded: false }
+    }
+
+    /// Its current level.
+    pub fn level(&self) -> u8 {
+        growth_rate_gen_1(self.species).level_for_exp(self.exp)
+    }
+
+    /// Experience still needed to reach `level` (0 if it's already there).
+    pub fn exp_to_level(&self, level: u8) -> u32 {
+        growth_rate_gen_1(self.species).exp_for_level(level).saturating_sub(self.exp)
+    }
+
+    /// Adds experience, up to what level 100 needs, and stat experience,
+    /// up to 65535 per stat.
+    pub fn gain(&mut self, gain: &ExpGainGen1) {
+        let max = growth_rate_gen_1(self.species).exp_for_level(100);
+        self.exp = self.exp.saturating_add(gain.exp).min(max);
+        self.evs.gain(&gain.stat_exp);
+    }
+}
+
+impl PlannedBattleGen1 {
+    /// A wild battle against one Pokémon.
+    pub fn wild(species: &'static SpeciesGen1, level: u8, participants: Vec<usize>) -> Self {
+        Self { opponents: vec![(species, level)], trainer: false, participants, exp_all: false }
+    }
+
+    /// A battle against a trainer's whole party.
+    ///
+    /// # Panics
+    /// If the trainer's party has a species that isn't in the species table.
+    pub fn against_trainer(trainer: &TrainerGen1, participants: Vec<usize>) -> Self {
+        let opponents = trainer.party.iter()
+            .map(|p| (find_species_gen_1(p.species).unwrap_or_else(|| panic!("unknown species {}", p.species)), p.level))
+            .collect();
+        Self { opponents, trainer: true, participants, exp_all: false }
+    }
+}
+
+/// A species' growth rate.
+pub fn growth_rate_gen_1(species: &SpeciesGen1) -> GrowthRateGen1 {
+    EXP_DATA_GEN_1[species.dex as usize - 1].0
+}
+
+/// The base experience a species gives when defeated.
+pub fn base_exp_yield_gen_1(species: &SpeciesGen1) -> u8 {
+    EXP_DATA_GEN_1[species.dex as usize - 1].1
+}
+
+/// The stat experience a species gives when defeated: its base stats.
+pub fn stat_exp_yield_gen_1(species: &SpeciesGen1) -> EVsGen1 {
+    let base = &species.base;
+    EVsGen1 {
+        hp: base.hp as u16,
+        attack: base.attack as u16,
+        defense: base.defense as u16,
+        special: base.special as u16,
+        speed: base.speed as u16,
+    }
+}
+
+/// What one party member gains from defeating a Pokémon.
+///
+/// Experience is base experience × level / 7, then 1.5× if the party member
+/// was traded and 1.5× again in a trainer battle. The Exp. All halves the
+/// experience and stat experience; participants split one half, and the
+/// whole party splits what's left of the other. As in the games, that
+/// second split comes after the first, so it's divided by the number of
+/// participants too.
+///
+/// # Example
+/// ```
+/// use engine::{exp_gain_gen_1, find_species_gen_1, BattleExpGen1};
+///
+/// // Brock's Onix gives one participant 108 × 14 / 7 × 1.5
+/// let onix = find_species_gen_1("Onix").unwrap();
+/// let battle = BattleExpGen1 { trainer: true, participants: 1, exp_all: false, party_size: 1 };
+/// let gain = exp_gain_gen_1(onix, 14, &battle, true, false);
+/// assert_eq!(gain.exp, 324);
+/// assert_eq!(gain.stat_exp.defense, 160);
+/// ```
+pub fn exp_gain_gen_1(
+    defeated: &SpeciesGen1,
+    level: u8,
+    battle: &BattleExpGen1,
+    participant: bool,
+    traded: bool,
+) -> ExpGainGen1 {
+    let base = &defeated.base;
+    let mut yields = [base_exp_yield_gen_1(defeated), base.hp, base.attack, base.defense, base.special, base.speed]
+        .map(|y| y as u32);
+    if battle.exp_all {
+        yields = yields.map(|y| y / 2);
+    }
+
+    let award = |yields: &[u32; 6]| {
+        let mut exp = yields[0] * level as u32 / 7;
+        if traded {
+            exp += exp / 2;
+        }
+        if battle.trainer {
+            exp += exp / 2;
+        }
+        ExpGainGen1 {
+            exp,
+            stat_exp: EVsGen1 {
+                hp: yields[1] as u16,
+                attack: yields[2] as u16,
+                defense: yields[3] as u16,
+                special: yields[4] as u16,
+                speed: yields[5] as u16,
+            },
+        }
+    };
+
+    yields = yields.map(|y| y / battle.participants.max(1) as u32);
+    let mut gain = if participant { award(&yields) } else { ExpGainGen1::default() };
+    if battle.exp_all {
+        yields = yields.map(|y| y / battle.party_size.max(1) as u32);
+        let share = award(&yields);
+        gain.exp += share.exp;
+        gain.stat_exp.gain(&share.stat_exp);
+    }
+    gain
+}
+
+/// The stat experience one party member gains from defeating a Pokémon.
+/// Shorthand for the `stat_exp` of [`exp_gain_gen_1`], which doesn't depend
+/// on level or trades.
+pub fn stat_exp_gain_gen_1(defeated: &SpeciesGen1, battle: &BattleExpGen1, participant: bool) -> EVsGen1 {
+    exp_gain_gen_1(defeated, 1, battle, participant, false).stat_exp
+}
+
+/// The party after each planned battle, in order, assuming nobody faints.
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, find_trainers_by_name_gen_1, project_exp_gen_1, LevelingPokemonGen1, PlannedBattleGen1};
+///
+/// let charmander = LevelingPokemonGen1::new(find_species_gen_1("Charmander").unwrap(), 12);
+/// let brock = find_trainers_by_name_gen_1("Brock")[0];
+/// let after = project_exp_gen_1(&[charmander], &[PlannedBattleGen1::against_trainer(brock, vec![0])]);
+/// assert_eq!(after[0][0].level(), 13);
+/// ```
+pub fn project_exp_gen_1(
+    party: &[LevelingPokemonGen1],
+    battles: &[PlannedBattleGen1],
+) -> Vec<Vec<LevelingPokemonGen1>> {
+    let mut party = party.to_vec();
+    battles.iter()
+        .map(|battle| {
+            let exp = BattleExpGen1 {
+                trainer: battle.trainer,
+                participants: battle.participants.len() as u8,
+                exp_all: battle.exp_all,
+                party_size: party.len() as u8,
+            };
+            for &(species, level) in &battle.opponents {
+                for (i, pokemon) in party.iter_mut().enumerate() {
+                    let gain = exp_gain_gen_1(species, level, &exp, battle.participants.contains(&i), pokemon.traded);
+                    pokemon.gain(&gain);
+                }
+            }
+            party.clone()
+        })
+        .collect()
+}
+
+/// How many of the planned battles can be fought, in order, before anyone
+/// in the party goes over the level cap.
+pub fn battles_within_cap_gen_1(
+    party: &[LevelingPokemonGen1],
+    battles: &[PlannedBattleGen1],
+    level_cap: u8,
+) -> usize {
+    project_exp_gen_1(party, battles).iter()
+        .take_while(|party| party.iter().all(|p| p.level() <= level_cap))
+        .count()
+}
+
+use GrowthRateGen1::{Fast, MediumFast, MediumSlow, Slow};
+
+/// Growth rate and base experience yield for each species, in Pokédex
+/// order.
+static EXP_DATA_GEN_1: [(GrowthRateGen1, u8); 151] = [
+    (MediumSlow, 64), (MediumSlow, 141), (MediumSlow, 208), (MediumSlow, 65), (MediumSlow, 142),
+    (MediumSlow, 209), (MediumSlow, 66), (MediumSlow, 143), (MediumSlow, 210), (MediumFast, 53),
+    (MediumFast, 72), (MediumFast, 160), (MediumFast, 52), (MediumFast, 71), (MediumFast, 159),
+    (MediumSlow, 55), (MediumSlow, 113), (MediumSlow, 172), (MediumFast, 57), (MediumFast, 116),
+    (MediumFast, 58), (MediumFast, 162), (MediumFast, 62), (MediumFast, 147), (MediumFast, 82),
+    (MediumFast, 122), (MediumFast, 93), (MediumFast, 163), (MediumSlow, 59), (MediumSlow, 117),
+    (MediumSlow, 194), (MediumSlow, 60), (MediumSlow, 118), (MediumSlow, 195), (Fast, 68),
+    (Fast, 129), (MediumFast, 63), (MediumFast, 178), (Fast, 76), (Fast, 109),
+    (MediumFast, 54), (MediumFast, 171), (MediumSlow, 78), (MediumSlow, 132), (MediumSlow, 184),
+    (MediumFast, 70), (MediumFast, 128), (MediumFast, 75), (MediumFast, 138), (MediumFast, 81),
+    (MediumFast, 153), (MediumFast, 69), (MediumFast, 148), (MediumFast, 80), (MediumFast, 174),
+    (MediumFast, 74), (MediumFast, 149), (Slow, 91), (Slow, 213), (MediumSlow, 77),
+    (MediumSlow, 131), (MediumSlow, 185), (MediumSlow, 73), (MediumSlow, 145), (MediumSlow, 186),
+    (MediumSlow, 88), (MediumSlow, 146), (MediumSlow, 193), (MediumSlow, 84), (MediumSlow, 151),
+    (MediumSlow, 191), (Slow, 105), (Slow, 205), (MediumSlow, 86), (MediumSlow, 134),
+    (MediumSlow, 177), (MediumFast, 152), (MediumFast, 192), (MediumFast, 99), (MediumFast, 164),
+    (MediumFast, 89), (MediumFast, 161), (MediumFast, 94), (MediumFast, 96), (MediumFast, 158),
+    (MediumFast, 100), (MediumFast, 176), (MediumFast, 90), (MediumFast, 157), (Slow, 97),
+    (Slow, 203), (MediumSlow, 95), (MediumSlow, 126), (MediumSlow, 190), (MediumFast, 108),
+    (MediumFast, 102), (MediumFast, 165), (MediumFast, 115), (MediumFast, 206), (MediumFast, 103),
+    (MediumFast, 150), (Slow, 98), (Slow, 212), (MediumFast, 87), (MediumFast, 124),
+    (MediumFast, 139), (MediumFast, 140), (MediumFast, 127), (MediumFast, 114), (MediumFast, 173),
+    (Slow, 135), (Slow, 204), (Fast, 255), (MediumFast, 166), (MediumFast, 175),
+    (MediumFast, 83), (MediumFast, 155), (MediumFast, 111), (MediumFast, 170), (Slow, 106),
+    (Slow, 207), (MediumFast, 136), (MediumFast, 187), (MediumFast, 137), (MediumFast, 156),
+    (MediumFast, 167), (Slow, 200), (Slow, 211), (Slow, 20), (Slow, 214),
+    (Slow, 219), (MediumFast, 61), (MediumFast, 92), (MediumFast, 196), (MediumFast, 197),
+    (MediumFast, 198), (MediumFast, 130), (MediumFast, 120), (MediumFast, 199), (MediumFast, 119),
+    (MediumFast, 201), (Slow, 202), (Slow, 154), (Slow, 215), (Slow, 216),
+    (Slow, 217), (Slow, 67), (Slow, 144), (Slow, 218), (Slow, 220),
+    (MediumSlow, 64),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn species(name: &str) -> &'static SpeciesGen1 {
+        find_species_gen_1(name).unwrap()
+    }
+
+    /// Growth curves end at their level 100 totals, and levels round down.
+    #[test]
+    fn test_growth_rates() {
+        let totals = [MediumFast, MediumSlow, Fast, Slow].map(|g| g.exp_for_level(100));
+        assert_eq!(totals, [1_000_000, 1_059_860, 800_000, 1_250_000]);
+        assert_eq!(MediumSlow.exp_for_level(1), 0);
+        assert_eq!(MediumSlow.exp_for_level(2), 9);
+        assert_eq!(MediumFast.level_for_exp(999), 9);
+        assert_eq!(MediumFast.level_for_exp(1000), 10);
+        assert_eq!(growth_rate_gen_1(species("Dragonite")), Slow);
+        assert_eq!(base_exp_yield_gen_1(species("Chansey")), 255);
+    }
+
+    /// The Exp. All halves everything, and its share is split by both the
+    /// participants and the party.
+    #[test]
+    fn test_exp_all() {
+        let onix = species("Onix");
+        let battle = BattleExpGen1 { trainer: true, participants: 2, exp_all: true, party_size: 6 };
+        let fought = exp_gain_gen_1(onix, 14, &battle, true, false);
+        let watched = exp_gain_gen_1(onix, 14, &battle, false, false);
+        // 108 / 2 / 2 = 27 for participants, 27 / 6 = 4 for everyone
+        assert_eq!((fought.exp, watched.exp), (81 + 12, 12));
+        assert_eq!((fought.stat_exp.defense, watched.stat_exp.defense), (40 + 6, 6));
+        assert_eq!(exp_gain_gen_1(onix, 14, &battle, false, true).exp, 18);
+
+        let mut pokemon = LevelingPokemonGen1::new(species("Pikachu"), 99);
+        pokemon.evs.speed = 65_500;
+        pokemon.gain(&ExpGainGen1 { exp: 1_000_000, stat_exp: stat_exp_yield_gen_1(species("Jolteon")) });
+        assert_eq!((pokemon.level(), pokemon.exp, pokemon.evs.speed), (100, 1_000_000, 65_535));
+    }
+
+    /// Grinding stops at the last battle that keeps the party under the cap.
+    #[test]
+    fn test_battles_within_cap() {
+        let party = [LevelingPokemonGen1::new(species("Charmander"), 10)];
+        // A level 5 Pidgey gives 55 × 5 / 7 = 39; level 12 needs 973 − 560 more
+        let battles = vec![PlannedBattleGen1::wild(species("Pidgey"), 5, vec![0]); 20];
+        assert_eq!(battles_within_cap_gen_1(&party, &battles, 11), 10);
+    }
+}
@@ -0,0 +1,69 @@
+use crate::StatGen1;
+
+/// Items that can be used during a Generation 1 battle.
+///
+/// # Variants
+/// - `Potion`/`SuperPotion`/`HyperPotion`: Restore 20/50/200 HP
+/// - `FullRestore`: Restores all HP and cures status
+/// - `FullHeal`: Cures status
+/// - `XAttack`/`XDefend`/`XSpeed`/`XSpecial`: Raise a stat by one stage
+/// - `GuardSpec`: Protects against stat drops (Mist)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ItemGen1 {
+    Potion,
+    SuperPotion,
+    HyperPotion,
+    FullRestore,
+    FullHeal,
+    XAttack,
+    XDefend,
+    XSpeed,
+    XSpecial,
+    GuardSpec,
+}
+
+impl ItemGen1 {
+    /// The item's in-game name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ItemGen1::Potion => "Potion",
+            ItemGen1::SuperPotion => "Super Potion",
+            ItemGen1::HyperPotion => "Hyper Potion",
+            ItemGen1::FullRestore => "Full Restore",
+            ItemGen1::FullHeal => "Full Heal",
+            ItemGen1::XAttack => "X Attack",
+            ItemGen1::XDefend => "X Defend",
+            ItemGen1::XSpeed => "X Speed",
+            ItemGen1::XSpecial => "X Special",
+            ItemGen1::GuardSpec => "Guard Spec.",
+        }
+    }
+
+    /// HP restored by the item, if it heals (`u16::MAX` for a full heal).
+    pub fn heal_amount(&self) -> Option<u16> {
+        match self {
+            ItemGen1::Potion => Some(20),
+            ItemGen1::SuperPotion => Some(50),
+            ItemGen1::HyperPotion => Some(200),
+            ItemGen1::FullRestore => Some(u16::MAX),
+            _ => None,
+        }
+    }
+
+    /// Whether the item cures status conditions.
+    pub fn cures_status(&self) -> bool {
+        matches!(self, ItemGen1::FullRestore | ItemGen1::FullHeal)
+    }
+
+    /// The stat the item raises by one stage, if any.
+    pub fn boosted_stat(&self) -> Option<StatGen1> {
+        match self {
+            ItemGen1::XAttack => Some(StatGen1::Attack),
+            ItemGen1::XDefend => Some(StatGen1::Defense),
+            ItemGen1::XSpeed => Some(StatGen1::Speed),
+            ItemGen1::XSpecial => Some(StatGen1::Special),
+            _ => None,
+        }
+    }
+}
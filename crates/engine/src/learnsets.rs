@@ -0,0 +1,649 @@
+use crate::{MoveGen1, SpeciesGen1, VersionGen1, find_move_gen_1};
+use crate::species::name_key;
+
+/// How a species learns its moves.
+///
+/// # Fields
+/// - `species`: Species name, as found by [`find_species_gen_1`](crate::find_species_gen_1)
+/// - `start`: Moves it knows at level 1 (up to 4)
+/// - `level_up`: Moves learned by leveling up, as `(level, move)`, in order
+/// - `tms`: TMs it's compatible with, by number (1-50)
+/// - `hms`: HMs it's compatible with, by number (1-5)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LearnsetGen1 {
+    pub species: &'static str,
+    pub start: &'static [&'static str],
+    pub level_up: &'static [(u8, &'static str)],
+    pub tms: &'static [u8],
+    pub hms: &'static [u8],
+}
+
+/// The move taught by each TM, TM01 first.
+pub static TMS_GEN_1: [&str; 50] = [
+    "Mega Punch", "Razor Wind", "Swords Dance", "Whirlwind", "Mega Kick",
+    "Toxic", "Horn Drill", "Body Slam", "Take Down", "Double-Edge",
+    "Bubble Beam", "Water Gun", "Ice Beam", "Blizzard", "Hyper Beam",
+    "Pay Day", "Submission", "Counter", "Seismic Toss", "Rage",
+    "Mega Drain", "Solar Beam", "Dragon Rage", "Thunderbolt", "Thunder",
+    "Earthquake", "Fissure", "Dig", "Psychic", "Teleport",
+    "Mimic", "Double Team", "Reflect", "Bide", "Metronome",
+    "Self-Destruct", "Egg Bomb", "Fire Blast", "Swift", "Skull Bash",
+    "Soft-Boiled", "Dream Eater", "Sky Attack", "Rest", "Thunder Wave",
+    "Psywave", "Explosion", "Rock Slide", "Tri Attack", "Substitute",
+];
+
+/// The move taught by each HM, HM01 first.
+pub static HMS_GEN_1: [&str; 5] = ["Cut", "Fly", "Surf", "Strength", "Flash"];
+
+impl LearnsetGen1 {
+    /// The moves a wild or trainer Pokémon of this species knows at
+    /// `level`, oldest first.
+    ///
+    /// It starts with the level 1 moves, then learns each level-up move up
+    /// to its level in order, skipping moves it already knows. Once it
+    /// knows four, each new move replaces the oldest.
+    pub fn moves_at(&self, level: u8) -> Vec<&'static str> {
+        let mut moves: Vec<&'static str> = self.start.to_vec();
+        for &(_, name) in self.level_up.iter().filter(|(learned, _)| *learned <= level) {
+            if moves.contains(&name) {
+                continue;
+            }
+            if moves.len() == 4 {
+                moves.remove(0);
+            }
+            moves.push(name);
+        }
+        moves
+    }
+
+    /// The level the species learns a move at by leveling up, if it does.
+    /// Level 1 moves count as level 1.
+    pub fn level_learned(&self, move_name: &str) -> Option<u8> {
+        let key = name_key(move_name);
+        if self.start.iter().any(|m| name_key(m) == key) {
+            return Some(1);
+        }
+        self.level_up.iter().find(|(_, m)| name_key(m) == key).map(|&(level, _)| level)
+    }
+
+    /// The moves it can be taught by TM or HM, TMs first.
+    pub fn machine_moves(&self) -> Vec<&'static str> {
+        self.tms.iter().map(|&tm| TMS_GEN_1[tm as usize - 1])
+            .chain(self.hms.iter().map(|&hm| HMS_GEN_1[hm as usize - 1]))
+            .collect()
+    }
+
+    /// Whether the species can learn a move by leveling up or by TM or HM.
+    pub fn can_learn(&self, move_name: &str) -> bool {
+        let key = name_key(move_name);
+        self.level_learned(move_name).is_some() || self.machine_moves().iter().any(|m| name_key(m) == key)
+    }
+}
+
+/// A species' learnset in Red and Blue.
+pub fn learnset_gen_1(species: &SpeciesGen1) -> &'static LearnsetGen1 {
+    &LEARNSETS_GEN_1[species.dex as usize - 1]
+}
+
+/// A species' learnset in a version. Yellow changed the level-up moves of
+/// Pikachu and the Nidoran lines; everything else is as in Red and Blue.
+///
+/// # Example
+/// ```
+/// use engine::{VersionGen1, find_species_gen_1, learnset_in_version_gen_1};
+///
+/// let pikachu = find_species_gen_1("Pikachu").unwrap();
+/// assert_eq!(learnset_in_version_gen_1(pikachu, VersionGen1::RedBlue).level_learned("Thunderbolt"), None);
+/// assert_eq!(learnset_in_version_gen_1(pikachu, VersionGen1::Yellow).level_learned("Thunderbolt"), Some(26));
+/// ```
+pub fn learnset_in_version_gen_1(species: &SpeciesGen1, version: VersionGen1) -> &'static LearnsetGen1 {
+    match version {
+        VersionGen1::RedBlue => learnset_gen_1(species),
+        VersionGen1::Yellow => YELLOW_LEARNSETS_GEN_1.iter()
+            .find(|learnset| learnset.species == species.name)
+            .unwrap_or_else(|| learnset_gen_1(species)),
+    }
+}
+
+/// The moves a wild or trainer Pokémon knows at a level: the last four it
+/// learned (see [`LearnsetGen1::moves_at`]).
+///
+/// # Example
+/// ```
+/// use engine::{default_moveset_gen_1, find_species_gen_1};
+///
+/// let onix = find_species_gen_1("Onix").unwrap();
+/// let names: Vec<_> = default_moveset_gen_1(onix, 19).into_iter().map(|m| m.name).collect();
+/// assert_eq!(names, ["Tackle", "Screech", "Bind", "Rock Throw"]);
+/// ```
+pub fn default_moveset_gen_1(species: &SpeciesGen1, level: u8) -> Vec<MoveGen1> {
+    default_moveset_in_version_gen_1(species, level, VersionGen1::RedBlue)
+}
+
+/// The moves a wild or trainer Pokémon knows at a level in a version; see
+/// [`default_moveset_gen_1`] and [`learnset_in_version_gen_1`].
+pub fn default_moveset_in_version_gen_1(species: &SpeciesGen1, level: u8, version: VersionGen1) -> Vec<MoveGen1> {
+    learnset_in_version_gen_1(species, version).moves_at(level).into_iter()
+        .map(|name| find_move_gen_1(name).unwrap_or_else(|| panic!("unknown move {name}")))
+        .collect()
+}
+
+const fn learnset(
+    species: &'static str,
+    start: &'static [&'static str],
+    level_up: &'static [(u8, &'static str)],
+    tms: &'static [u8],
+    hms: &'static [u8],
+) -> LearnsetGen1 {
+    LearnsetGen1 { species, start, level_up, tms, hms }
+}
+
+/// Red and Blue learnsets, in Pokédex order.
+static LEARNSETS_GEN_1: [LearnsetGen1; 151] = [
+    learnset("Bulbasaur", &["Tackle", "Growl"],
+        &[(7, "Leech Seed"), (13, "Vine Whip"), (20, "Poison Powder"), (27, "Razor Leaf"), (34, "Growth"), (41, "Sleep Powder"), (48, "Solar Beam")],
+        &[3, 6, 8, 9, 10, 20, 21, 22, 31, 32, 33, 34, 44, 50], &[1]),
+    learnset("Ivysaur", &["Tackle", "Growl", "Leech Seed"],
+        &[(7, "Leech Seed"), (13, "Vine Whip"), (22, "Poison Powder"), (30, "Razor Leaf"), (38, "Growth"), (46, "Sleep Powder"), (54, "Solar Beam")],
+        &[3, 6, 8, 9, 10, 20, 21, 22, 31, 32, 33, 34, 44, 50], &[1]),
+    learnset("Venusaur", &["Tackle", "Growl", "Leech Seed", "Vine Whip"],
+        &[(7, "Leech Seed"), (13, "Vine Whip"), (22, "Poison Powder"), (30, "Razor Leaf"), (43, "Growth"), (55, "Sleep Powder"), (65, "Solar Beam")],
+        &[3, 6, 8, 9, 10, 15, 20, 21, 22, 31, 32, 33, 34, 44, 50], &[1]),
+    learnset("Charmander", &["Scratch", "Growl"],
+        &[(9, "Ember"), (15, "Leer"), (22, "Rage"), (30, "Slash"), (38, "Flamethrower"), (46, "Fire Spin")],
+        &[1, 3, 5, 6, 8, 9, 10, 17, 18, 19, 20, 23, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[1, 4]),
+    learnset("Charmeleon", &["Scratch", "Growl", "Ember"],
+        &[(9, "Ember"), (15, "Leer"), (24, "Rage"), (33, "Slash"), (42, "Flamethrower"), (56, "Fire Spin")],
+        &[1, 3, 5, 6, 8, 9, 10, 17, 18, 19, 20, 23, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[1, 4]),
+    learnset("Charizard", &["Scratch", "Growl", "Ember", "Leer"],
+        &[(9, "Ember"), (15, "Leer"), (24, "Rage"), (36, "Slash"), (46, "Flamethrower"), (55, "Fire Spin")],
+        &[1, 3, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 23, 26, 27, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[1, 4]),
+    learnset("Squirtle", &["Tackle", "Tail Whip"],
+        &[(8, "Bubble"), (15, "Water Gun"), (22, "Bite"), (28, "Withdraw"), (35, "Skull Bash"), (42, "Hydro Pump")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 17, 18, 19, 20, 28, 31, 32, 33, 34, 40, 44, 50], &[3, 4]),
+    learnset("Wartortle", &["Tackle", "Tail Whip", "Bubble"],
+        &[(8, "Bubble"), (15, "Water Gun"), (24, "Bite"), (31, "Withdraw"), (39, "Skull Bash"), (47, "Hydro Pump")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 17, 18, 19, 20, 28, 31, 32, 33, 34, 40, 44, 50], &[3, 4]),
+    learnset("Blastoise", &["Tackle", "Tail Whip", "Bubble", "Water Gun"],
+        &[(8, "Bubble"), (15, "Water Gun"), (24, "Bite"), (31, "Withdraw"), (42, "Skull Bash"), (52, "Hydro Pump")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 26, 27, 28, 31, 32, 33, 34, 40, 44, 50], &[3, 4]),
+    learnset("Caterpie", &["Tackle", "String Shot"], &[], &[], &[]),
+    learnset("Metapod", &["Harden"], &[], &[], &[]),
+    learnset("Butterfree", &["Confusion"],
+        &[(12, "Confusion"), (15, "Poison Powder"), (16, "Stun Spore"), (17, "Sleep Powder"), (21, "Supersonic"), (26, "Whirlwind"), (32, "Psybeam")],
+        &[2, 4, 6, 9, 10, 15, 20, 21, 22, 29, 30, 31, 32, 33, 34, 39, 44, 46, 50], &[5]),
+    learnset("Weedle", &["Poison Sting", "String Shot"], &[], &[], &[]),
+    learnset("Kakuna", &["Harden"], &[], &[], &[]),
+    learnset("Beedrill", &["Fury Attack"],
+        &[(12, "Fury Attack"), (16, "Focus Energy"), (20, "Twineedle"), (25, "Rage"), (30, "Pin Missile"), (35, "Agility")],
+        &[3, 6, 9, 10, 15, 20, 21, 31, 32, 34, 39, 44, 50], &[1]),
+    learnset("Pidgey", &["Gust"],
+        &[(5, "Sand Attack"), (12, "Quick Attack"), (19, "Whirlwind"), (28, "Wing Attack"), (36, "Agility"), (44, "Mirror Move")],
+        &[2, 4, 6, 9, 10, 20, 31, 32, 34, 39, 43, 44, 50], &[2]),
+    learnset("Pidgeotto", &["Gust", "Sand Attack"],
+        &[(5, "Sand Attack"), (12, "Quick Attack"), (21, "Whirlwind"), (31, "Wing Attack"), (40, "Agility"), (49, "Mirror Move")],
+        &[2, 4, 6, 9, 10, 20, 31, 32, 34, 39, 43, 44, 50], &[2]),
+    learnset("Pidgeot", &["Gust", "Sand Attack", "Quick Attack"],
+        &[(5, "Sand Attack"), (12, "Quick Attack"), (21, "Whirlwind"), (31, "Wing Attack"), (44, "Agility"), (54, "Mirror Move")],
+        &[2, 4, 6, 9, 10, 15, 20, 31, 32, 34, 39, 43, 44, 50], &[2]),
+    learnset("Rattata", &["Tackle", "Tail Whip"],
+        &[(7, "Quick Attack"), (14, "Hyper Fang"), (23, "Focus Energy"), (34, "Super Fang")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 24, 25, 28, 31, 32, 34, 39, 44, 50], &[]),
+    learnset("Raticate", &["Tackle", "Tail Whip", "Quick Attack"],
+        &[(7, "Quick Attack"), (14, "Hyper Fang"), (27, "Focus Energy"), (41, "Super Fang")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 15, 20, 24, 25, 28, 31, 32, 34, 39, 44, 50], &[]),
+    learnset("Spearow", &["Peck", "Growl"],
+        &[(9, "Leer"), (15, "Fury Attack"), (22, "Mirror Move"), (29, "Drill Peck"), (36, "Agility")],
+        &[2, 4, 6, 9, 10, 20, 31, 32, 34, 39, 44, 50], &[2]),
+    learnset("Fearow", &["Peck", "Growl", "Leer"],
+        &[(9, "Leer"), (15, "Fury Attack"), (25, "Mirror Move"), (34, "Drill Peck"), (43, "Agility")],
+        &[2, 4, 6, 9, 10, 15, 20, 31, 32, 34, 39, 44, 50], &[2]),
+    learnset("Ekans", &["Wrap", "Leer"],
+        &[(10, "Poison Sting"), (17, "Bite"), (24, "Glare"), (31, "Screech"), (38, "Acid")],
+        &[6, 8, 9, 10, 20, 21, 26, 27, 28, 31, 32, 34, 40, 44, 48, 50], &[]),
+    learnset("Arbok", &["Wrap", "Leer", "Poison Sting"],
+        &[(10, "Poison Sting"), (17, "Bite"), (27, "Glare"), (36, "Screech"), (47, "Acid")],
+        &[6, 8, 9, 10, 15, 20, 21, 26, 27, 28, 31, 32, 34, 40, 44, 48, 50], &[]),
+    learnset("Pikachu", &["Thunder Shock", "Growl"],
+        &[(9, "Thunder Wave"), (16, "Quick Attack"), (26, "Swift"), (33, "Agility"), (43, "Thunder")],
+        &[1, 5, 6, 8, 9, 10, 16, 17, 19, 20, 24, 25, 31, 32, 33, 34, 39, 40, 44, 45, 50], &[5]),
+    learnset("Raichu", &["Thunder Shock", "Growl", "Thunder Wave"], &[],
+        &[1, 5, 6, 8, 9, 10, 15, 16, 17, 19, 20, 24, 25, 31, 32, 33, 34, 39, 40, 44, 45, 50], &[5]),
+    learnset("Sandshrew", &["Scratch"],
+        &[(10, "Sand Attack"), (17, "Slash"), (24, "Poison Sting"), (31, "Swift"), (38, "Fury Swipes")],
+        &[3, 6, 8, 9, 10, 17, 19, 20, 26, 27, 28, 31, 32, 34, 39, 40, 44, 48, 50], &[1, 4]),
+    learnset("Sandslash", &["Scratch", "Sand Attack"],
+        &[(10, "Sand Attack"), (17, "Slash"), (27, "Poison Sting"), (36, "Swift"), (47, "Fury Swipes")],
+        &[3, 6, 8, 9, 10, 15, 17, 19, 20, 26, 27, 28, 31, 32, 34, 39, 40, 44, 48, 50], &[1, 4]),
+    learnset("Nidoran♀", &["Growl", "Tackle"],
+        &[(8, "Scratch"), (14, "Poison Sting"), (21, "Tail Whip"), (29, "Bite"), (36, "Fury Swipes"), (43, "Double Kick")],
+        &[6, 8, 9, 10, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidorina", &["Growl", "Tackle", "Scratch"],
+        &[(8, "Scratch"), (14, "Poison Sting"), (23, "Tail Whip"), (32, "Bite"), (41, "Fury Swipes"), (50, "Double Kick")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidoqueen", &["Tackle", "Scratch", "Tail Whip", "Body Slam"],
+        &[(8, "Scratch"), (14, "Poison Sting"), (23, "Body Slam")],
+        &[1, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 31, 32, 33, 34, 38, 40, 44, 48, 50], &[3, 4]),
+    learnset("Nidoran♂", &["Leer", "Tackle"],
+        &[(8, "Horn Attack"), (14, "Poison Sting"), (21, "Focus Energy"), (29, "Fury Attack"), (36, "Horn Drill"), (43, "Double Kick")],
+        &[6, 7, 8, 9, 10, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidorino", &["Leer", "Tackle", "Horn Attack"],
+        &[(8, "Horn Attack"), (14, "Poison Sting"), (23, "Focus Energy"), (32, "Fury Attack"), (41, "Horn Drill"), (50, "Double Kick")],
+        &[6, 7, 8, 9, 10, 11, 12, 13, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidoking", &["Tackle", "Horn Attack", "Poison Sting", "Thrash"],
+        &[(8, "Horn Attack"), (14, "Poison Sting"), (23, "Thrash")],
+        &[1, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 31, 32, 33, 34, 38, 40, 44, 48, 50], &[3, 4]),
+    learnset("Clefairy", &["Pound", "Growl"],
+        &[(13, "Sing"), (18, "Double Slap"), (24, "Minimize"), (31, "Metronome"), (39, "Defense Curl"), (48, "Light Screen")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 17, 18, 19, 20, 22, 24, 25, 29, 30, 31, 32, 33, 34, 35, 38, 40, 44, 45, 50], &[4, 5]),
+    learnset("Clefable", &["Sing", "Double Slap", "Minimize", "Metronome"], &[],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 22, 24, 25, 29, 30, 31, 32, 33, 34, 35, 38, 40, 44, 45, 50], &[4, 5]),
+    learnset("Vulpix", &["Ember", "Tail Whip"],
+        &[(16, "Quick Attack"), (21, "Roar"), (28, "Confuse Ray"), (35, "Flamethrower"), (42, "Fire Spin")],
+        &[6, 8, 9, 10, 20, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Ninetales", &["Ember", "Tail Whip", "Quick Attack", "Roar"], &[],
+        &[6, 8, 9, 10, 15, 20, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Jigglypuff", &["Sing"],
+        &[(9, "Pound"), (14, "Disable"), (19, "Defense Curl"), (24, "Double Slap"), (29, "Rest"), (34, "Body Slam"), (39, "Double-Edge")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 17, 18, 19, 20, 22, 24, 25, 29, 31, 32, 33, 34, 38, 40, 44, 45, 50], &[4, 5]),
+    learnset("Wigglytuff", &["Sing", "Disable", "Defense Curl", "Double Slap"], &[],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 22, 24, 25, 29, 31, 32, 33, 34, 38, 40, 44, 45, 50], &[4, 5]),
+    learnset("Zubat", &["Leech Life"],
+        &[(10, "Supersonic"), (15, "Bite"), (21, "Confuse Ray"), (28, "Wing Attack"), (36, "Haze")],
+        &[2, 4, 6, 9, 10, 20, 21, 31, 32, 34, 39, 44, 50], &[]),
+    learnset("Golbat", &["Leech Life", "Screech", "Bite"],
+        &[(10, "Supersonic"), (15, "Bite"), (21, "Confuse Ray"), (32, "Wing Attack"), (43, "Haze")],
+        &[2, 4, 6, 9, 10, 15, 20, 21, 31, 32, 34, 39, 44, 50], &[]),
+    learnset("Oddish", &["Absorb"],
+        &[(15, "Poison Powder"), (17, "Stun Spore"), (19, "Sleep Powder"), (24, "Acid"), (33, "Petal Dance"), (46, "Solar Beam")],
+        &[3, 6, 9, 10, 20, 21, 22, 31, 32, 34, 44, 50], &[1]),
+    learnset("Gloom", &["Absorb", "Poison Powder", "Stun Spore"],
+        &[(15, "Poison Powder"), (17, "Stun Spore"), (19, "Sleep Powder"), (28, "Acid"), (38, "Petal Dance"), (52, "Solar Beam")],
+        &[3, 6, 9, 10, 20, 21, 22, 31, 32, 34, 44, 50], &[1]),
+    learnset("Vileplume", &["Stun Spore", "Sleep Powder", "Acid", "Petal Dance"],
+        &[(15, "Poison Powder"), (17, "Stun Spore"), (19, "Sleep Powder")],
+        &[3, 6, 9, 10, 15, 20, 21, 22, 31, 32, 34, 44, 50], &[1]),
+    learnset("Paras", &["Scratch"],
+        &[(13, "Stun Spore"), (20, "Leech Life"), (27, "Spore"), (34, "Slash"), (41, "Growth")],
+        &[3, 6, 8, 9, 10, 20, 21, 22, 28, 31, 32, 34, 44, 50], &[1]),
+    learnset("Parasect", &["Scratch", "Stun Spore", "Leech Life"],
+        &[(13, "Stun Spore"), (20, "Leech Life"), (30, "Spore"), (39, "Slash"), (48, "Growth")],
+        &[3, 6, 8, 9, 10, 15, 20, 21, 22, 28, 31, 32, 34, 44, 50], &[1]),
+    learnset("Venonat", &["Tackle", "Disable"],
+        &[(24, "Poison Powder"), (27, "Leech Life"), (30, "Stun Spore"), (35, "Psybeam"), (38, "Sleep Powder"), (43, "Psychic")],
+        &[6, 9, 10, 20, 21, 22, 29, 31, 32, 34, 44, 46, 50], &[]),
+    learnset("Venomoth", &["Tackle", "Disable", "Poison Powder", "Leech Life"],
+        &[(24, "Poison Powder"), (27, "Leech Life"), (30, "Stun Spore"), (38, "Psybeam"), (43, "Sleep Powder"), (50, "Psychic")],
+        &[2, 4, 6, 9, 10, 15, 20, 21, 22, 29, 31, 32, 34, 39, 44, 46, 50], &[]),
+    learnset("Diglett", &["Scratch"],
+        &[(15, "Growl"), (19, "Dig"), (24, "Sand Attack"), (31, "Slash"), (40, "Earthquake")],
+        &[6, 8, 9, 10, 20, 26, 27, 28, 31, 32, 34, 44, 48, 50], &[1]),
+    learnset("Dugtrio", &["Scratch", "Growl", "Dig"],
+        &[(15, "Growl"), (19, "Dig"), (24, "Sand Attack"), (35, "Slash"), (47, "Earthquake")],
+        &[6, 8, 9, 10, 15, 20, 26, 27, 28, 31, 32, 34, 44, 48, 50], &[1]),
+    learnset("Meowth", &["Scratch", "Growl"],
+        &[(12, "Bite"), (17, "Pay Day"), (24, "Screech"), (33, "Fury Swipes"), (44, "Slash")],
+        &[6, 8, 9, 10, 11, 16, 20, 24, 25, 31, 32, 34, 39, 40, 44, 50], &[]),
+    learnset("Persian", &["Scratch", "Growl", "Bite", "Screech"],
+        &[(12, "Bite"), (17, "Pay Day"), (24, "Screech"), (37, "Fury Swipes"), (51, "Slash")],
+        &[6, 8, 9, 10, 11, 15, 16, 20, 24, 25, 31, 32, 34, 39, 40, 44, 50], &[]),
+    learnset("Psyduck", &["Scratch"],
+        &[(28, "Tail Whip"), (31, "Disable"), (36, "Confusion"), (43, "Fury Swipes"), (52, "Hydro Pump")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 16, 17, 18, 19, 20, 28, 31, 32, 34, 39, 40, 44, 50], &[3, 4]),
+    learnset("Golduck", &["Scratch", "Tail Whip", "Disable"],
+        &[(28, "Tail Whip"), (31, "Disable"), (39, "Confusion"), (48, "Fury Swipes"), (59, "Hydro Pump")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 28, 31, 32, 34, 39, 40, 44, 50], &[3, 4]),
+    learnset("Mankey", &["Scratch", "Leer"],
+        &[(15, "Karate Chop"), (21, "Fury Swipes"), (27, "Focus Energy"), (33, "Seismic Toss"), (39, "Thrash")],
+        &[1, 5, 6, 8, 9, 10, 16, 17, 18, 19, 20, 24, 25, 28, 31, 32, 34, 35, 39, 40, 44, 48, 50], &[4]),
+    learnset("Primeape", &["Scratch", "Leer", "Karate Chop", "Fury Swipes"],
+        &[(15, "Karate Chop"), (21, "Fury Swipes"), (27, "Focus Energy"), (37, "Seismic Toss"), (46, "Thrash")],
+        &[1, 5, 6, 8, 9, 10, 15, 16, 17, 18, 19, 20, 24, 25, 28, 31, 32, 34, 35, 39, 40, 44, 48, 50], &[4]),
+    learnset("Growlithe", &["Bite", "Roar"],
+        &[(18, "Ember"), (23, "Leer"), (30, "Take Down"), (39, "Agility"), (50, "Flamethrower")],
+        &[6, 8, 9, 10, 20, 23, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Arcanine", &["Roar", "Ember", "Leer", "Take Down"], &[],
+        &[6, 8, 9, 10, 15, 20, 23, 28, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Poliwag", &["Bubble"],
+        &[(16, "Hypnosis"), (19, "Water Gun"), (25, "Double Slap"), (31, "Body Slam"), (38, "Amnesia"), (45, "Hydro Pump")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 29, 31, 32, 34, 40, 44, 46, 50], &[3]),
+    learnset("Poliwhirl", &["Bubble", "Hypnosis", "Water Gun"],
+        &[(16, "Hypnosis"), (19, "Water Gun"), (26, "Double Slap"), (33, "Body Slam"), (41, "Amnesia"), (49, "Hydro Pump")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 17, 18, 19, 20, 26, 27, 29, 31, 32, 34, 35, 40, 44, 46, 50], &[3, 4]),
+    learnset("Poliwrath", &["Hypnosis", "Water Gun", "Double Slap", "Body Slam"],
+        &[(16, "Hypnosis"), (19, "Water Gun")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 26, 27, 29, 31, 32, 34, 35, 40, 44, 46, 50], &[3, 4]),
+    learnset("Abra", &["Teleport"], &[],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 29, 30, 31, 32, 33, 34, 35, 40, 44, 45, 46, 50], &[5]),
+    learnset("Kadabra", &["Teleport", "Confusion", "Disable"],
+        &[(16, "Confusion"), (20, "Disable"), (27, "Psybeam"), (31, "Recover"), (38, "Psychic"), (42, "Reflect")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 29, 30, 31, 32, 33, 34, 35, 40, 44, 45, 46, 50], &[5]),
+    learnset("Alakazam", &["Teleport", "Confusion", "Disable"],
+        &[(16, "Confusion"), (20, "Disable"), (27, "Psybeam"), (31, "Recover"), (38, "Psychic"), (42, "Reflect")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 29, 30, 31, 32, 33, 34, 35, 40, 44, 45, 46, 50], &[5]),
+    learnset("Machop", &["Karate Chop"],
+        &[(20, "Low Kick"), (25, "Leer"), (32, "Focus Energy"), (39, "Seismic Toss"), (46, "Submission")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 35, 38, 44, 48, 50], &[4]),
+    learnset("Machoke", &["Karate Chop", "Low Kick", "Leer"],
+        &[(20, "Low Kick"), (25, "Leer"), (36, "Focus Energy"), (44, "Seismic Toss"), (52, "Submission")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 35, 38, 44, 48, 50], &[4]),
+    learnset("Machamp", &["Karate Chop", "Low Kick", "Leer"],
+        &[(20, "Low Kick"), (25, "Leer"), (36, "Focus Energy"), (44, "Seismic Toss"), (52, "Submission")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 35, 38, 44, 48, 50], &[4]),
+    learnset("Bellsprout", &["Vine Whip", "Growth"],
+        &[(13, "Wrap"), (15, "Poison Powder"), (18, "Sleep Powder"), (21, "Stun Spore"), (26, "Acid"), (33, "Razor Leaf"), (42, "Slam")],
+        &[3, 6, 9, 10, 20, 21, 22, 31, 32, 34, 44, 50], &[1]),
+    learnset("Weepinbell", &["Vine Whip", "Growth", "Wrap"],
+        &[(13, "Wrap"), (15, "Poison Powder"), (18, "Sleep Powder"), (23, "Stun Spore"), (29, "Acid"), (38, "Razor Leaf"), (49, "Slam")],
+        &[3, 6, 9, 10, 20, 21, 22, 31, 32, 34, 44, 50], &[1]),
+    learnset("Victreebel", &["Sleep Powder", "Stun Spore", "Acid", "Razor Leaf"],
+        &[(13, "Wrap"), (15, "Poison Powder"), (18, "Sleep Powder")],
+        &[3, 6, 9, 10, 15, 20, 21, 22, 31, 32, 34, 44, 50], &[1]),
+    learnset("Tentacool", &["Acid"],
+        &[(7, "Supersonic"), (13, "Wrap"), (18, "Poison Sting"), (22, "Water Gun"), (27, "Constrict"), (33, "Barrier"), (40, "Screech"), (48, "Hydro Pump")],
+        &[3, 6, 9, 10, 11, 12, 13, 14, 20, 21, 31, 32, 34, 40, 44, 50], &[1, 3]),
+    learnset("Tentacruel", &["Acid", "Supersonic", "Wrap"],
+        &[(7, "Supersonic"), (13, "Wrap"), (18, "Poison Sting"), (22, "Water Gun"), (27, "Constrict"), (35, "Barrier"), (43, "Screech"), (50, "Hydro Pump")],
+        &[3, 6, 9, 10, 11, 12, 13, 14, 15, 20, 21, 31, 32, 34, 40, 44, 50], &[1, 3]),
+    learnset("Geodude", &["Tackle"],
+        &[(11, "Defense Curl"), (16, "Rock Throw"), (21, "Self-Destruct"), (26, "Harden"), (31, "Earthquake"), (36, "Explosion")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 35, 36, 38, 44, 47, 48, 50], &[4]),
+    learnset("Graveler", &["Tackle", "Defense Curl"],
+        &[(11, "Defense Curl"), (16, "Rock Throw"), (21, "Self-Destruct"), (29, "Harden"), (36, "Earthquake"), (43, "Explosion")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 35, 36, 38, 44, 47, 48, 50], &[4]),
+    learnset("Golem", &["Tackle", "Defense Curl"],
+        &[(11, "Defense Curl"), (16, "Rock Throw"), (21, "Self-Destruct"), (29, "Harden"), (36, "Earthquake"), (43, "Explosion")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 35, 36, 38, 44, 47, 48, 50], &[4]),
+    learnset("Ponyta", &["Ember"],
+        &[(30, "Tail Whip"), (32, "Stomp"), (35, "Growl"), (39, "Fire Spin"), (43, "Take Down"), (48, "Agility")],
+        &[6, 7, 8, 9, 10, 20, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Rapidash", &["Ember", "Tail Whip", "Stomp", "Growl"],
+        &[(30, "Tail Whip"), (32, "Stomp"), (35, "Growl"), (39, "Fire Spin"), (47, "Take Down"), (55, "Agility")],
+        &[6, 7, 8, 9, 10, 15, 20, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Slowpoke", &["Confusion"],
+        &[(18, "Disable"), (22, "Headbutt"), (27, "Growl"), (33, "Water Gun"), (40, "Amnesia"), (48, "Psychic")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 16, 20, 22, 26, 27, 28, 29, 30, 31, 32, 33, 34, 38, 39, 40, 44, 45, 46, 50], &[3, 4, 5]),
+    learnset("Slowbro", &["Confusion", "Disable", "Headbutt"],
+        &[(18, "Disable"), (22, "Headbutt"), (27, "Growl"), (33, "Water Gun"), (37, "Withdraw"), (44, "Amnesia"), (55, "Psychic")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 22, 26, 27, 28, 29, 30, 31, 32, 33, 34, 38, 39, 40, 44, 45, 46, 50], &[3, 4, 5]),
+    learnset("Magnemite", &["Tackle"],
+        &[(21, "Sonic Boom"), (25, "Thunder Shock"), (29, "Supersonic"), (35, "Thunder Wave"), (41, "Swift"), (47, "Screech")],
+        &[6, 9, 10, 20, 24, 25, 30, 31, 32, 33, 34, 39, 44, 45, 50], &[5]),
+    learnset("Magneton", &["Tackle", "Sonic Boom", "Thunder Shock"],
+        &[(21, "Sonic Boom"), (25, "Thunder Shock"), (29, "Supersonic"), (38, "Thunder Wave"), (46, "Swift"), (54, "Screech")],
+        &[6, 9, 10, 15, 20, 24, 25, 30, 31, 32, 33, 34, 39, 44, 45, 50], &[5]),
+    learnset("Farfetch'd", &["Peck", "Sand Attack"],
+        &[(7, "Leer"), (15, "Fury Attack"), (23, "Swords Dance"), (31, "Agility"), (39, "Slash")],
+        &[2, 3, 4, 6, 8, 9, 10, 20, 31, 32, 34, 39, 40, 44, 50], &[1, 2]),
+    learnset("Doduo", &["Peck"],
+        &[(20, "Growl"), (24, "Fury Attack"), (30, "Drill Peck"), (36, "Rage"), (40, "Tri Attack"), (44, "Agility")],
+        &[4, 6, 8, 9, 10, 20, 31, 32, 33, 34, 39, 40, 43, 44, 49, 50], &[2]),
+    learnset("Dodrio", &["Peck", "Growl", "Fury Attack"],
+        &[(20, "Growl"), (24, "Fury Attack"), (30, "Drill Peck"), (39, "Rage"), (45, "Tri Attack"), (51, "Agility")],
+        &[4, 6, 8, 9, 10, 15, 20, 31, 32, 33, 34, 39, 40, 43, 44, 49, 50], &[2]),
+    learnset("Seel", &["Headbutt"],
+        &[(30, "Growl"), (35, "Aurora Beam"), (40, "Rest"), (45, "Take Down"), (50, "Ice Beam")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 31, 32, 34, 40, 44, 50], &[3, 4]),
+    learnset("Dewgong", &["Headbutt", "Growl", "Aurora Beam"],
+        &[(30, "Growl"), (35, "Aurora Beam"), (44, "Rest"), (50, "Take Down"), (56, "Ice Beam")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 15, 20, 31, 32, 34, 40, 44, 50], &[3, 4]),
+    learnset("Grimer", &["Pound", "Disable"],
+        &[(30, "Poison Gas"), (33, "Minimize"), (37, "Sludge"), (42, "Harden"), (48, "Screech"), (55, "Acid Armor")],
+        &[6, 8, 20, 21, 24, 25, 31, 32, 34, 36, 38, 44, 47, 50], &[]),
+    learnset("Muk", &["Pound", "Disable", "Poison Gas"],
+        &[(30, "Poison Gas"), (33, "Minimize"), (37, "Sludge"), (45, "Harden"), (53, "Screech"), (60, "Acid Armor")],
+        &[6, 8, 15, 20, 21, 24, 25, 31, 32, 34, 36, 38, 44, 47, 50], &[]),
+    learnset("Shellder", &["Tackle", "Withdraw"],
+        &[(18, "Supersonic"), (23, "Clamp"), (30, "Aurora Beam"), (39, "Leer"), (50, "Ice Beam")],
+        &[6, 9, 10, 11, 12, 13, 14, 20, 30, 31, 32, 33, 34, 36, 39, 44, 47, 49, 50], &[3]),
+    learnset("Cloyster", &["Withdraw", "Supersonic", "Clamp", "Aurora Beam"],
+        &[(50, "Spike Cannon")],
+        &[6, 9, 10, 11, 12, 13, 14, 15, 20, 30, 31, 32, 33, 34, 36, 39, 44, 47, 49, 50], &[3]),
+    learnset("Gastly", &["Lick", "Confuse Ray", "Night Shade"],
+        &[(27, "Hypnosis"), (35, "Dream Eater")],
+        &[6, 20, 21, 24, 25, 29, 31, 32, 34, 36, 42, 44, 46, 47, 50], &[]),
+    learnset("Haunter", &["Lick", "Confuse Ray", "Night Shade"],
+        &[(29, "Hypnosis"), (38, "Dream Eater")],
+        &[6, 20, 21, 24, 25, 29, 31, 32, 34, 36, 42, 44, 46, 47, 50], &[]),
+    learnset("Gengar", &["Lick", "Confuse Ray", "Night Shade"],
+        &[(29, "Hypnosis"), (38, "Dream Eater")],
+        &[1, 5, 6, 15, 17, 18, 19, 20, 21, 24, 25, 29, 31, 32, 34, 35, 36, 42, 44, 46, 47, 50], &[4]),
+    learnset("Onix", &["Tackle", "Screech"],
+        &[(15, "Bind"), (19, "Rock Throw"), (25, "Rage"), (33, "Slam"), (43, "Harden")],
+        &[6, 8, 9, 10, 20, 26, 27, 28, 31, 32, 34, 36, 40, 44, 47, 48, 50], &[4]),
+    learnset("Drowzee", &["Pound", "Hypnosis"],
+        &[(12, "Disable"), (17, "Confusion"), (24, "Headbutt"), (29, "Poison Gas"), (32, "Psychic"), (37, "Meditate")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 29, 30, 31, 32, 33, 34, 35, 40, 42, 44, 45, 46, 50], &[5]),
+    learnset("Hypno", &["Pound", "Hypnosis", "Disable", "Confusion"],
+        &[(12, "Disable"), (17, "Confusion"), (24, "Headbutt"), (33, "Poison Gas"), (37, "Psychic"), (43, "Meditate")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 29, 30, 31, 32, 33, 34, 35, 40, 42, 44, 45, 46, 50], &[5]),
+    learnset("Krabby", &["Bubble", "Leer"],
+        &[(20, "Vice Grip"), (25, "Guillotine"), (30, "Stomp"), (35, "Crabhammer"), (40, "Harden")],
+        &[3, 6, 8, 9, 10, 11, 12, 13, 14, 20, 31, 32, 34, 44, 50], &[1, 3, 4]),
+    learnset("Kingler", &["Bubble", "Leer", "Vice Grip"],
+        &[(20, "Vice Grip"), (25, "Guillotine"), (34, "Stomp"), (42, "Crabhammer"), (49, "Harden")],
+        &[3, 6, 8, 9, 10, 11, 12, 13, 14, 15, 20, 31, 32, 34, 44, 50], &[1, 3, 4]),
+    learnset("Voltorb", &["Tackle", "Screech"],
+        &[(17, "Sonic Boom"), (22, "Self-Destruct"), (29, "Light Screen"), (36, "Swift"), (43, "Explosion")],
+        &[6, 9, 20, 24, 25, 31, 32, 33, 34, 36, 39, 44, 45, 47, 50], &[5]),
+    learnset("Electrode", &["Tackle", "Screech", "Sonic Boom"],
+        &[(17, "Sonic Boom"), (22, "Self-Destruct"), (29, "Light Screen"), (40, "Swift"), (50, "Explosion")],
+        &[6, 9, 15, 20, 24, 25, 31, 32, 33, 34, 36, 39, 44, 45, 47, 50], &[5]),
+    learnset("Exeggcute", &["Barrage", "Hypnosis"],
+        &[(25, "Reflect"), (28, "Leech Seed"), (32, "Stun Spore"), (37, "Poison Powder"), (42, "Solar Beam"), (48, "Sleep Powder")],
+        &[6, 9, 10, 20, 29, 30, 31, 32, 33, 34, 36, 37, 44, 46, 47, 50], &[4]),
+    learnset("Exeggutor", &["Barrage", "Hypnosis"],
+        &[(28, "Stomp")],
+        &[6, 9, 10, 15, 20, 29, 30, 31, 32, 33, 34, 36, 37, 44, 46, 47, 50], &[4]),
+    learnset("Cubone", &["Bone Club", "Growl"],
+        &[(25, "Leer"), (31, "Focus Energy"), (38, "Thrash"), (43, "Bonemerang"), (46, "Rage")],
+        &[1, 5, 6, 8, 9, 10, 11, 13, 14, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 38, 40, 44, 50], &[4]),
+    learnset("Marowak", &["Bone Club", "Growl", "Leer", "Focus Energy"],
+        &[(25, "Leer"), (33, "Focus Energy"), (41, "Thrash"), (48, "Bonemerang"), (55, "Rage")],
+        &[1, 5, 6, 8, 9, 10, 11, 13, 14, 15, 17, 18, 19, 20, 26, 27, 28, 31, 32, 34, 38, 40, 44, 50], &[4]),
+    learnset("Hitmonlee", &["Double Kick", "Meditate"],
+        &[(33, "Rolling Kick"), (38, "Jump Kick"), (43, "Focus Energy"), (48, "High Jump Kick"), (53, "Mega Kick")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 31, 32, 34, 35, 39, 40, 44, 50], &[4]),
+    learnset("Hitmonchan", &["Comet Punch", "Agility"],
+        &[(33, "Fire Punch"), (38, "Ice Punch"), (43, "Thunder Punch"), (48, "Mega Punch"), (53, "Counter")],
+        &[1, 5, 6, 8, 9, 10, 17, 18, 19, 20, 31, 32, 34, 35, 39, 40, 44, 50], &[4]),
+    learnset("Lickitung", &["Wrap", "Supersonic"],
+        &[(7, "Stomp"), (15, "Disable"), (23, "Defense Curl"), (31, "Slam"), (39, "Screech")],
+        &[1, 3, 5, 6, 7, 8, 9, 10, 11, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 31, 32, 34, 38, 40, 44, 50], &[1, 3, 4]),
+    learnset("Koffing", &["Tackle", "Smog"],
+        &[(32, "Sludge"), (37, "Smokescreen"), (40, "Self-Destruct"), (45, "Haze"), (48, "Explosion")],
+        &[6, 20, 24, 25, 31, 32, 34, 36, 38, 44, 47, 50], &[]),
+    learnset("Weezing", &["Tackle", "Smog", "Sludge"],
+        &[(32, "Sludge"), (39, "Smokescreen"), (43, "Self-Destruct"), (49, "Haze"), (53, "Explosion")],
+        &[6, 15, 20, 24, 25, 31, 32, 34, 36, 38, 44, 47, 50], &[]),
+    learnset("Rhyhorn", &["Horn Attack"],
+        &[(30, "Stomp"), (35, "Tail Whip"), (40, "Fury Attack"), (45, "Horn Drill"), (50, "Leer"), (55, "Take Down")],
+        &[6, 7, 8, 9, 10, 20, 24, 25, 26, 27, 28, 31, 32, 34, 38, 40, 44, 48, 50], &[4]),
+    learnset("Rhydon", &["Horn Attack", "Stomp", "Tail Whip", "Fury Attack"],
+        &[(30, "Stomp"), (35, "Tail Whip"), (40, "Fury Attack"), (48, "Horn Drill"), (55, "Leer"), (64, "Take Down")],
+        &[1, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 28, 31, 32, 34, 38, 40, 44, 48, 50], &[3, 4]),
+    learnset("Chansey", &["Pound", "Double Slap"],
+        &[(24, "Sing"), (30, "Growl"), (38, "Minimize"), (44, "Defense Curl"), (48, "Light Screen"), (54, "Double-Edge")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 22, 24, 25, 29, 30, 31, 32, 33, 34, 35, 37, 38, 40, 41, 44, 45, 46, 49, 50], &[4, 5]),
+    learnset("Tangela", &["Constrict", "Bind"],
+        &[(29, "Absorb"), (32, "Poison Powder"), (36, "Stun Spore"), (39, "Sleep Powder"), (45, "Slam"), (49, "Growth")],
+        &[3, 6, 8, 9, 10, 15, 20, 21, 22, 31, 32, 34, 40, 44, 50], &[1]),
+    learnset("Kangaskhan", &["Comet Punch", "Rage"],
+        &[(26, "Bite"), (31, "Tail Whip"), (36, "Mega Punch"), (41, "Leer"), (46, "Dizzy Punch")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 31, 32, 34, 38, 40, 44, 48, 50], &[3, 4]),
+    learnset("Horsea", &["Bubble"],
+        &[(19, "Smokescreen"), (24, "Leer"), (30, "Water Gun"), (37, "Agility"), (45, "Hydro Pump")],
+        &[6, 9, 10, 11, 12, 13, 14, 20, 31, 32, 34, 39, 44, 50], &[3]),
+    learnset("Seadra", &["Bubble", "Smokescreen"],
+        &[(19, "Smokescreen"), (24, "Leer"), (30, "Water Gun"), (41, "Agility"), (52, "Hydro Pump")],
+        &[6, 9, 10, 11, 12, 13, 14, 15, 20, 31, 32, 34, 39, 44, 50], &[3]),
+    learnset("Goldeen", &["Peck", "Tail Whip"],
+        &[(19, "Supersonic"), (24, "Horn Attack"), (30, "Fury Attack"), (37, "Waterfall"), (45, "Horn Drill"), (54, "Agility")],
+        &[6, 7, 9, 10, 11, 12, 13, 14, 20, 31, 32, 34, 39, 44, 50], &[3]),
+    learnset("Seaking", &["Peck", "Tail Whip", "Supersonic"],
+        &[(19, "Supersonic"), (24, "Horn Attack"), (30, "Fury Attack"), (39, "Waterfall"), (48, "Horn Drill"), (54, "Agility")],
+        &[6, 7, 9, 10, 11, 12, 13, 14, 15, 20, 31, 32, 34, 39, 44, 50], &[3]),
+    learnset("Staryu", &["Tackle"],
+        &[(17, "Water Gun"), (22, "Harden"), (27, "Recover"), (32, "Swift"), (37, "Minimize"), (42, "Light Screen"), (47, "Hydro Pump")],
+        &[6, 9, 10, 11, 12, 13, 14, 20, 24, 25, 29, 30, 31, 32, 33, 34, 39, 44, 45, 46, 49, 50], &[3, 5]),
+    learnset("Starmie", &["Tackle", "Water Gun", "Harden"], &[],
+        &[6, 9, 10, 11, 12, 13, 14, 15, 20, 24, 25, 29, 30, 31, 32, 33, 34, 39, 44, 45, 46, 49, 50], &[3, 5]),
+    learnset("Mr. Mime", &["Confusion", "Barrier"],
+        &[(15, "Confusion"), (23, "Light Screen"), (31, "Double Slap"), (39, "Meditate"), (47, "Substitute")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 22, 24, 25, 29, 30, 31, 32, 33, 34, 35, 40, 44, 45, 46, 50], &[5]),
+    learnset("Scyther", &["Quick Attack"],
+        &[(17, "Leer"), (20, "Focus Energy"), (24, "Double Team"), (29, "Slash"), (35, "Swords Dance"), (42, "Agility")],
+        &[3, 6, 9, 10, 15, 20, 31, 32, 34, 39, 40, 44, 50], &[1]),
+    learnset("Jynx", &["Pound", "Lovely Kiss"],
+        &[(18, "Lick"), (23, "Double Slap"), (31, "Ice Punch"), (39, "Body Slam"), (47, "Thrash"), (58, "Blizzard")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 29, 30, 31, 32, 33, 34, 35, 40, 44, 46, 50], &[]),
+    learnset("Electabuzz", &["Quick Attack", "Leer"],
+        &[(34, "Thunder Shock"), (37, "Screech"), (42, "Thunder Punch"), (49, "Light Screen"), (54, "Thunder")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 24, 25, 29, 30, 31, 32, 33, 34, 35, 39, 40, 44, 45, 46, 50], &[4, 5]),
+    learnset("Magmar", &["Ember"],
+        &[(36, "Leer"), (39, "Confuse Ray"), (43, "Fire Punch"), (48, "Smokescreen"), (52, "Smog"), (55, "Flamethrower")],
+        &[1, 5, 6, 8, 9, 10, 15, 17, 18, 19, 20, 29, 30, 31, 32, 34, 35, 38, 40, 44, 46, 50], &[4]),
+    learnset("Pinsir", &["Vice Grip"],
+        &[(25, "Seismic Toss"), (30, "Guillotine"), (36, "Focus Energy"), (43, "Harden"), (49, "Slash"), (54, "Swords Dance")],
+        &[3, 6, 8, 9, 10, 15, 17, 19, 20, 31, 32, 34, 40, 44, 50], &[1, 4]),
+    learnset("Tauros", &["Tackle"],
+        &[(21, "Stomp"), (28, "Tail Whip"), (35, "Leer"), (44, "Rage"), (51, "Take Down")],
+        &[6, 7, 8, 9, 10, 11, 13, 14, 15, 20, 24, 25, 26, 27, 31, 32, 34, 38, 40, 44, 50], &[4]),
+    learnset("Magikarp", &["Splash"], &[(15, "Tackle")], &[], &[]),
+    learnset("Gyarados", &["Bite", "Dragon Rage", "Leer", "Hydro Pump"],
+        &[(20, "Bite"), (25, "Dragon Rage"), (32, "Leer"), (41, "Hydro Pump"), (52, "Hyper Beam")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 15, 20, 23, 24, 25, 31, 32, 34, 38, 40, 44, 50], &[3, 4]),
+    learnset("Lapras", &["Water Gun", "Growl"],
+        &[(16, "Sing"), (20, "Mist"), (25, "Body Slam"), (31, "Confuse Ray"), (38, "Ice Beam"), (46, "Hydro Pump")],
+        &[6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 20, 22, 23, 24, 25, 29, 31, 32, 33, 34, 40, 44, 46, 50], &[3, 4]),
+    learnset("Ditto", &["Transform"], &[], &[], &[]),
+    learnset("Eevee", &["Tackle", "Sand Attack"],
+        &[(27, "Quick Attack"), (31, "Tail Whip"), (37, "Bite"), (45, "Take Down")],
+        &[6, 8, 9, 10, 20, 31, 32, 33, 34, 39, 40, 44, 50], &[]),
+    learnset("Vaporeon", &["Tackle", "Sand Attack", "Quick Attack", "Water Gun"],
+        &[(27, "Quick Attack"), (31, "Water Gun"), (37, "Tail Whip"), (40, "Bite"), (42, "Acid Armor"), (44, "Haze"), (48, "Mist"), (54, "Hydro Pump")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 15, 20, 31, 32, 33, 34, 39, 40, 44, 50], &[3]),
+    learnset("Jolteon", &["Tackle", "Sand Attack", "Quick Attack", "Thunder Shock"],
+        &[(27, "Quick Attack"), (31, "Thunder Shock"), (37, "Tail Whip"), (40, "Thunder Wave"), (42, "Double Kick"), (44, "Agility"), (48, "Pin Missile"), (54, "Thunder")],
+        &[6, 8, 9, 10, 15, 20, 24, 25, 31, 32, 33, 34, 39, 40, 44, 45, 50], &[5]),
+    learnset("Flareon", &["Tackle", "Sand Attack", "Quick Attack", "Ember"],
+        &[(27, "Quick Attack"), (31, "Ember"), (37, "Tail Whip"), (40, "Bite"), (42, "Leer"), (44, "Fire Spin"), (48, "Rage"), (54, "Flamethrower")],
+        &[6, 8, 9, 10, 15, 20, 31, 32, 33, 34, 38, 39, 40, 44, 50], &[]),
+    learnset("Porygon", &["Tackle", "Sharpen", "Conversion"],
+        &[(23, "Psybeam"), (28, "Recover"), (35, "Agility"), (42, "Tri Attack")],
+        &[6, 9, 10, 13, 14, 15, 20, 24, 25, 29, 30, 31, 32, 33, 34, 39, 40, 44, 45, 46, 49, 50], &[5]),
+    learnset("Omanyte", &["Water Gun", "Withdraw"],
+        &[(34, "Horn Attack"), (39, "Leer"), (46, "Spike Cannon"), (53, "Hydro Pump")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 31, 32, 34, 40, 44, 50], &[3]),
+    learnset("Omastar", &["Water Gun", "Withdraw", "Horn Attack"],
+        &[(34, "Horn Attack"), (39, "Leer"), (44, "Spike Cannon"), (49, "Hydro Pump")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 19, 20, 31, 32, 34, 40, 44, 50], &[3]),
+    learnset("Kabuto", &["Scratch", "Harden"],
+        &[(34, "Absorb"), (39, "Slash"), (44, "Leer"), (49, "Hydro Pump")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 31, 32, 34, 40, 44, 50], &[3]),
+    learnset("Kabutops", &["Scratch", "Harden", "Absorb"],
+        &[(34, "Absorb"), (39, "Slash"), (46, "Leer"), (53, "Hydro Pump")],
+        &[3, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 19, 20, 31, 32, 34, 40, 44, 50], &[1, 3]),
+    learnset("Aerodactyl", &["Wing Attack", "Agility"],
+        &[(33, "Supersonic"), (38, "Bite"), (45, "Take Down"), (54, "Hyper Beam")],
+        &[2, 4, 6, 9, 10, 15, 20, 23, 31, 32, 33, 34, 38, 39, 40, 43, 44, 50], &[2]),
+    learnset("Snorlax", &["Headbutt", "Amnesia", "Rest"],
+        &[(35, "Body Slam"), (41, "Harden"), (48, "Double-Edge"), (56, "Hyper Beam")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 22, 24, 25, 26, 27, 29, 31, 32, 34, 35, 38, 40, 44, 46, 48, 50], &[3, 4]),
+    learnset("Articuno", &["Peck", "Ice Beam"],
+        &[(51, "Blizzard"), (55, "Agility"), (60, "Mist")],
+        &[2, 4, 6, 9, 10, 13, 14, 15, 20, 31, 32, 33, 34, 39, 43, 44, 50], &[2]),
+    learnset("Zapdos", &["Thunder Shock", "Drill Peck"],
+        &[(51, "Thunder"), (55, "Agility"), (60, "Light Screen")],
+        &[2, 4, 6, 9, 10, 15, 20, 24, 25, 31, 32, 33, 34, 39, 43, 44, 45, 50], &[2, 5]),
+    learnset("Moltres", &["Peck", "Fire Spin"],
+        &[(51, "Leer"), (55, "Agility"), (60, "Sky Attack")],
+        &[2, 4, 6, 9, 10, 15, 20, 31, 32, 33, 34, 38, 39, 43, 44, 50], &[2]),
+    learnset("Dratini", &["Wrap", "Leer"],
+        &[(10, "Thunder Wave"), (20, "Agility"), (30, "Slam"), (40, "Dragon Rage"), (50, "Hyper Beam")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 23, 24, 25, 31, 32, 33, 34, 38, 39, 40, 44, 45, 50], &[3]),
+    learnset("Dragonair", &["Wrap", "Leer", "Thunder Wave"],
+        &[(10, "Thunder Wave"), (20, "Agility"), (35, "Slam"), (45, "Dragon Rage"), (55, "Hyper Beam")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 23, 24, 25, 31, 32, 33, 34, 38, 39, 40, 44, 45, 50], &[3]),
+    learnset("Dragonite", &["Wrap", "Leer", "Thunder Wave", "Agility"],
+        &[(10, "Thunder Wave"), (20, "Agility"), (35, "Slam"), (45, "Dragon Rage"), (60, "Hyper Beam")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 15, 20, 23, 24, 25, 31, 32, 33, 34, 38, 39, 40, 44, 45, 50], &[3, 4]),
+    learnset("Mewtwo", &["Confusion", "Disable", "Swift", "Psychic"],
+        &[(63, "Barrier"), (66, "Psychic"), (70, "Recover"), (75, "Mist"), (81, "Amnesia")],
+        &[1, 5, 6, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 22, 24, 25, 26, 27, 29, 30, 31, 32, 33, 34, 35, 38, 40, 44, 45, 46, 49, 50], &[4, 5]),
+    learnset("Mew", &["Pound"],
+        &[(10, "Transform"), (20, "Mega Punch"), (30, "Metronome"), (40, "Psychic")],
+        &[
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25,
+            26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50,
+        ],
+        &[1, 2, 3, 4, 5]),
+];
+
+/// Yellow's learnsets where they differ from Red and Blue's. Only the
+/// level-up moves changed.
+static YELLOW_LEARNSETS_GEN_1: [LearnsetGen1; 7] = [
+    learnset("Pikachu", &["Thunder Shock", "Growl"],
+        &[(6, "Tail Whip"), (8, "Thunder Wave"), (11, "Quick Attack"), (15, "Double Team"), (20, "Slam"), (26, "Thunderbolt"), (33, "Agility"), (41, "Thunder"), (50, "Light Screen")],
+        &[1, 5, 6, 8, 9, 10, 16, 17, 19, 20, 24, 25, 31, 32, 33, 34, 39, 40, 44, 45, 50], &[5]),
+    learnset("Nidoran♀", &["Growl", "Tackle"],
+        &[(8, "Scratch"), (12, "Double Kick"), (17, "Poison Sting"), (23, "Tail Whip"), (30, "Bite"), (38, "Fury Swipes")],
+        &[6, 8, 9, 10, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidorina", &["Growl", "Tackle", "Scratch"],
+        &[(8, "Scratch"), (12, "Double Kick"), (19, "Poison Sting"), (27, "Tail Whip"), (36, "Bite"), (46, "Fury Swipes")],
+        &[6, 8, 9, 10, 11, 12, 13, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidoqueen", &["Tackle", "Scratch", "Tail Whip", "Body Slam"],
+        &[(8, "Scratch"), (12, "Double Kick"), (14, "Poison Sting"), (23, "Body Slam")],
+        &[1, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 31, 32, 33, 34, 38, 40, 44, 48, 50], &[3, 4]),
+    learnset("Nidoran♂", &["Leer", "Tackle"],
+        &[(8, "Horn Attack"), (12, "Double Kick"), (17, "Poison Sting"), (23, "Focus Energy"), (30, "Fury Attack"), (38, "Horn Drill")],
+        &[6, 7, 8, 9, 10, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidorino", &["Leer", "Tackle", "Horn Attack"],
+        &[(8, "Horn Attack"), (12, "Double Kick"), (19, "Poison Sting"), (27, "Focus Energy"), (36, "Fury Attack"), (46, "Horn Drill")],
+        &[6, 7, 8, 9, 10, 11, 12, 13, 14, 20, 24, 25, 31, 32, 33, 34, 40, 44, 50], &[]),
+    learnset("Nidoking", &["Tackle", "Horn Attack", "Poison Sting", "Thrash"],
+        &[(8, "Horn Attack"), (12, "Double Kick"), (14, "Poison Sting"), (23, "Thrash")],
+        &[1, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 17, 18, 19, 20, 24, 25, 26, 27, 31, 32, 33, 34, 38, 40, 44, 48, 50], &[3, 4]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{SPECIES_GEN_1, find_move_data_gen_1, find_species_gen_1};
+
+    /// Every learnset is in Pokédex order and uses known moves, and every
+    /// Yellow learnset replaces one from Red and Blue.
+    #[test]
+    fn test_data_resolves() {
+        for (species, learnset) in SPECIES_GEN_1.iter().zip(&LEARNSETS_GEN_1) {
+            assert_eq!(species.name, learnset.species);
+        }
+        for learnset in &YELLOW_LEARNSETS_GEN_1 {
+            let species = find_species_gen_1(learnset.species).unwrap();
+            assert_eq!(learnset_gen_1(species).tms, learnset.tms, "{}", learnset.species);
+        }
+        for learnset in LEARNSETS_GEN_1.iter().chain(&YELLOW_LEARNSETS_GEN_1) {
+            assert!(learnset.start.len() <= 4);
+            assert!(learnset.level_up.windows(2).all(|w| w[0].0 <= w[1].0), "{}", learnset.species);
+            let moves = learnset.start.iter().chain(learnset.level_up.iter().map(|(_, m)| m));
+            for name in moves.chain(learnset.machine_moves().iter()) {
+                assert!(find_move_data_gen_1(name).is_some(), "{} {name}", learnset.species);
+            }
+        }
+    }
+
+    /// Moves already known aren't learned twice, and the oldest is forgotten.
+    #[test]
+    fn test_moves_at() {
+        let dragonair = learnset_gen_1(find_species_gen_1("Dragonair").unwrap());
+        assert_eq!(dragonair.moves_at(56), ["Agility", "Slam", "Dragon Rage", "Hyper Beam"]);
+        assert_eq!(dragonair.moves_at(1), ["Wrap", "Leer", "Thunder Wave"]);
+        assert_eq!(dragonair.level_learned("dragon rage"), Some(45));
+
+        let charmander = learnset_gen_1(find_species_gen_1("Charmander").unwrap());
+        assert!(charmander.can_learn("Dragon Rage"));
+        assert!(charmander.can_learn("Strength"));
+        assert!(!charmander.can_learn("Surf"));
+    }
+}
@@ -0,0 +1,189 @@
+use crate::trainer_ai::TrainerClassGen1;
+use crate::trainers::{TrainerGen1, VersionGen1, trainers_gen_1};
+
+/// The eight Kanto badges, in the order the gyms are usually beaten.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BadgeGen1 {
+    Boulder,
+    Cascade,
+    Thunder,
+    Rainbow,
+    Soul,
+    Marsh,
+    Volcano,
+    Earth,
+}
+
+/// How far a run has progressed.
+///
+/// # Fields
+/// - `badges`: Badges earned, one bit per badge with Boulder as bit 0 (the
+///   same layout as the games' save data)
+/// - `elite_four`: Elite Four members beaten on the current attempt (0-4)
+/// - `champion`: Whether the Champion has been beaten
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProgressGen1 {
+    pub badges: u8,
+    pub elite_four: u8,
+    pub champion: bool,
+}
+
+/// The level cap for a split and the boss it comes from.
+///
+/// # Fields
+/// - `level`: The highest level in the boss's party
+/// - `boss`: The next boss (for the Champion, the variant with the highest
+///   level)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelCapGen1 {
+    pub level: u8,
+    pub boss: &'static TrainerGen1,
+}
+
+const ELITE_FOUR: [TrainerClassGen1; 5] = [
+    TrainerClassGen1::Lorelei,
+    TrainerClassGen1::Bruno,
+    TrainerClassGen1::Agatha,
+    TrainerClassGen1::Lance,
+    TrainerClassGen1::Rival3,
+];
+
+impl BadgeGen1 {
+    /// Iterates over every badge in gym order.
+    pub fn iter() -> impl Iterator<Item = BadgeGen1> {
+        [
+            BadgeGen1::Boulder,
+            BadgeGen1::Cascade,
+            BadgeGen1::Thunder,
+            BadgeGen1::Rainbow,
+            BadgeGen1::Soul,
+            BadgeGen1::Marsh,
+            BadgeGen1::Volcano,
+            BadgeGen1::Earth,
+        ].into_iter()
+    }
+
+    /// The badge's in-game name.
+    pub fn name(&self) -> &'static str {
+        match self {
+            BadgeGen1::Boulder => "Boulder Badge",
+            BadgeGen1::Cascade => "Cascade Badge",
+            BadgeGen1::Thunder => "Thunder Badge",
+            BadgeGen1::Rainbow => "Rainbow Badge",
+            BadgeGen1::Soul => "Soul Badge",
+            BadgeGen1::Marsh => "Marsh Badge",
+            BadgeGen1::Volcano => "Volcano Badge",
+            BadgeGen1::Earth => "Earth Badge",
+        }
+    }
+
+    /// The gym leader who gives out the badge.
+    pub fn leader(&self) -> TrainerClassGen1 {
+        match self {
+            BadgeGen1::Boulder => TrainerClassGen1::Brock,
+            BadgeGen1::Cascade => TrainerClassGen1::Misty,
+            BadgeGen1::Thunder => TrainerClassGen1::LtSurge,
+            BadgeGen1::Rainbow => TrainerClassGen1::Erika,
+            BadgeGen1::Soul => TrainerClassGen1::Koga,
+            BadgeGen1::Marsh => TrainerClassGen1::Sabrina,
+            BadgeGen1::Volcano => TrainerClassGen1::Blaine,
+            BadgeGen1::Earth => TrainerClassGen1::Giovanni,
+        }
+    }
+
+    fn bit(&self) -> u8 {
+        1 << *self as u8
+    }
+}
+
+impl ProgressGen1 {
+    /// Progress after earning the first `count` badges in gym order.
+    pub fn with_badges(count: usize) -> Self {
+        let mut progress = Self::default();
+        for badge in BadgeGen1::iter().take(count) {
+            progress.earn(badge);
+        }
+        progress
+    }
+
+    /// Whether a badge has been earned.
+    pub fn has(&self, badge: BadgeGen1) -> bool {
+        self.badges & badge.bit() != 0
+    }
+
+    /// Records earning a badge.
+    pub fn earn(&mut self, badge: BadgeGen1) {
+        self.badges |= badge.bit();
+    }
+
+    /// How many badges have been earned.
+    pub fn badge_count(&self) -> u32 {
+        self.badges.count_ones()
+    }
+}
+
+/// The next boss: the first gym leader in gym order whose badge hasn't been
+/// earned, then the Elite Four and the Champion. `None` once the Champion
+/// has been beaten, or if the boss isn't in the trainer data.
+///
+/// Giovanni's gym battle is used rather than his Team Rocket battles.
+pub fn next_boss_gen_1(version: VersionGen1, progress: &ProgressGen1) -> Option<&'static TrainerGen1> {
+    let class = match BadgeGen1::iter().find(|b| !progress.has(*b)) {
+        Some(badge) => badge.leader(),
+        None if progress.champion => return None,
+        None => *ELITE_FOUR.get(progress.elite_four as usize)?,
+    };
+    trainers_gen_1().iter()
+        .filter(|t| t.class == class && t.version == version)
+        .filter(|t| class != TrainerClassGen1::Giovanni || t.location.contains("Gym"))
+        .max_by_key(|t| ace_level(t))
+}
+
+/// The hardcore Nuzlocke level cap: the level of the next boss's
+/// highest-level Pokémon.
+///
+/// # Example
+/// ```
+/// use engine::{level_cap_gen_1, ProgressGen1, VersionGen1};
+///
+/// // After Brock, the cap is Misty's Starmie
+/// let cap = level_cap_gen_1(VersionGen1::RedBlue, &ProgressGen1::with_badges(1)).unwrap();
+/// assert_eq!(cap.boss.name, "Misty");
+/// assert_eq!(cap.level, 21);
+/// ```
+pub fn level_cap_gen_1(version: VersionGen1, progress: &ProgressGen1) -> Option<LevelCapGen1> {
+    next_boss_gen_1(version, progress).map(|boss| LevelCapGen1 { level: ace_level(boss), boss })
+}
+
+fn ace_level(trainer: &TrainerGen1) -> u8 {
+    trainer.party.iter().map(|p| p.level).max().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Caps rise through the gyms and the Elite Four, and stop after the
+    /// Champion.
+    #[test]
+    fn test_level_caps() {
+        let caps: Vec<u8> = (0..=8)
+            .map(|badges| level_cap_gen_1(VersionGen1::RedBlue, &ProgressGen1::with_badges(badges)).unwrap().level)
+            .collect();
+        assert_eq!(caps, [14, 21, 24, 29, 43, 43, 47, 50, 56]);
+
+        let mut progress = ProgressGen1::with_badges(8);
+        progress.elite_four = 4;
+        let champion = level_cap_gen_1(VersionGen1::RedBlue, &progress).unwrap();
+        assert_eq!((champion.boss.name, champion.level), ("Blue", 65));
+        progress.champion = true;
+        assert!(level_cap_gen_1(VersionGen1::RedBlue, &progress).is_none());
+
+        // Badges earned out of order leave the earlier gym as the next boss
+        let mut progress = ProgressGen1::default();
+        progress.earn(BadgeGen1::Cascade);
+        assert_eq!(next_boss_gen_1(VersionGen1::Yellow, &progress).unwrap().name, "Brock");
+    }
+}
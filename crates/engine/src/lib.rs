@@ -5,13 +5,37 @@
 //! - Move calculations
 //! - Damage formulas
 //! - Type effectiveness
+//! - Turn resolution and exact endgame search
+//! - Gen 1 trainer AI
+//! - Species, move and trainer data for Red, Blue and Yellow
+//! - Matchup matrices and worst-case danger analysis for boss fights
+//! - Level caps from the next boss's ace
+//! - Wild encounter, gift and trade tables
+//! - Evolutions and evolution planning, and dupes clause encounter odds
+//! - Level-up learnsets, TM/HM compatibility and default movesets
+//! - Experience, growth rates and stat experience, with level projection
+//! - DV inference from observed stats
+//! - Reading and writing Red, Blue and Yellow save files
+//! - Reading the battle in progress from a dump of the games' RAM
+//! - Importing and exporting teams in Showdown's text format
+//! - Team files, checked against the species, move and learnset data
 //!
 //! # Example
 //! ```
-//! use poke_engine_gen1::{PokemonGen1, MoveGen1, calc_damage_gen_1, DamageRoll};
+//! use engine::{PokemonGen1, MoveGen1, MoveCategory, TypeGen1, calc_damage_gen_1, DamageRoll};
 //!
-//! let pikachu = PokemonGen1::new("Pikachu", [TypeGen1::Electric, TypeGen1::None]);
-//! let thunderbolt = MoveGen1::new("Thunderbolt", TypeGen1::Electric, 90, MoveCategory::Special);
+//! let pikachu = PokemonGen1 {
+//!     name: "Pikachu".into(),
+//!     types: [TypeGen1::Electric, TypeGen1::None],
+//!     ..Default::default()
+//! };
+//! let thunderbolt = MoveGen1 {
+//!     name: "Thunderbolt".into(),
+//!     typ: TypeGen1::Electric,
+//!     power: 90,
+//!     category: MoveCategory::Special,
+//!     ..Default::default()
+//! };
 //! let damage = calc_damage_gen_1(&pikachu, &pikachu, &thunderbolt, false, DamageRoll::Average);
 //! ```
 
@@ -19,9 +43,78 @@ pub mod pokemon;
 pub mod poke_move;
 pub mod damage;
 pub mod types;
+pub mod battle;
+pub mod search;
+pub mod item;
+pub mod trainer_ai;
+pub mod species;
+pub mod move_data;
+pub mod trainers;
+pub mod matchup;
+pub mod danger;
+pub mod level_cap;
+pub mod encounters;
+pub mod evolution;
+pub mod dupes;
+pub mod learnsets;
+pub mod experience;
+pub mod dvs;
+pub mod save;
+pub mod wram;
+pub mod showdown;
+pub mod team;
 
 /// Re-export core battle types for convenient access.
 pub use pokemon::{PokemonGen1, StatsGen1, StatStagesGen1, StatusGen1};
-pub use poke_move::{MoveGen1, MoveCategory};
-pub use damage::{DamageRoll, calc_damage_gen_1};
+pub use poke_move::{MoveGen1, MoveCategory, MoveEffectGen1, StatGen1};
+pub use damage::{
+    DamageRoll, DAMAGE_ROLL_COUNT, calc_damage_gen_1, calc_damage_rolls_gen_1, damage_is_predictable_gen_1,
+    fixed_damage_gen_1, fixed_damage_rolls_gen_1,
+};
 pub use types::{TypeGen1, type_effectiveness_gen_1, type_effectiveness_gen_1_fast};
+pub use battle::{
+    ActionGen1, BattleStateGen1, BattlerGen1, SideGen1, SideIdGen1,
+    OpponentPolicyGen1, UniformMovePolicyGen1, resolve_turn_gen_1,
+};
+pub use item::ItemGen1;
+pub use trainer_ai::{TrainerAiGen1, TrainerClassGen1};
+pub use save::{
+    BOX_CAPACITY_GEN_1, BOX_COUNT_GEN_1, SAVE_SIZE_GEN_1, SaveErrorGen1, SaveGen1, SavedPokemonGen1, decode_text_gen_1,
+    encode_text_gen_1, parse_save_gen_1, species_from_index_gen_1, species_index_gen_1, write_save_gen_1,
+};
+pub use wram::{
+    BattleSnapshotGen1, SnapshotBattlerGen1, VolatileFlagsGen1, WRAM_SIZE_GEN_1, WramErrorGen1, parse_wram_gen_1,
+};
+pub use showdown::{ShowdownErrorGen1, parse_showdown_gen_1, showdown_gen_1, team_to_showdown_gen_1};
+pub use team::{TEAM_SIZE_GEN_1, TeamErrorGen1, TeamFileGen1, TeamMemberGen1};
+pub use species::{BaseStatsGen1, SpeciesGen1, SPECIES_GEN_1, find_species_gen_1, suggest_species_gen_1};
+pub use move_data::{MoveDataGen1, MOVES_GEN_1, find_move_data_gen_1, find_move_gen_1, suggest_moves_gen_1};
+pub use trainers::{
+    TrainerGen1, TrainerPokemonGen1, VersionGen1, TRAINER_DVS_GEN_1, trainers_gen_1,
+    find_trainers_by_class_gen_1, find_trainers_by_location_gen_1, find_trainers_by_name_gen_1,
+};
+pub use matchup::{MatchupMatrixGen1, MoveMatchupGen1, SpeedMatchupGen1, damage_rolls_gen_1, matchup_matrix_gen_1};
+pub use danger::{DangerLevelGen1, ThreatGen1, danger_analysis_gen_1};
+pub use encounters::{
+    EncounterChanceGen1, EncounterMethodGen1, EncounterTableGen1, GameGen1, WILD_SLOT_WEIGHTS_GEN_1,
+    encounter_tables_gen_1, find_encounters_by_area_gen_1, find_encounters_by_type_gen_1,
+};
+pub use evolution::{
+    EvolutionGen1, EvolutionMethodGen1, EvolutionPlanGen1, StoneGen1, evolution_line_gen_1, evolution_plans_gen_1,
+    evolutions_gen_1, pre_evolution_gen_1, same_evolution_line_gen_1,
+};
+pub use dupes::{DupesClauseGen1, FirstEncounterChanceGen1, first_encounter_odds_gen_1, new_species_chance_gen_1};
+pub use dvs::{DvRangesGen1, StatObservationGen1, hp_dv_gen_1, infer_dvs_gen_1};
+pub use experience::{
+    BattleExpGen1, ExpGainGen1, GrowthRateGen1, LevelingPokemonGen1, PlannedBattleGen1, base_exp_yield_gen_1,
+    battles_within_cap_gen_1, exp_gain_gen_1, growth_rate_gen_1, project_exp_gen_1, stat_exp_gain_gen_1,
+    stat_exp_yield_gen_1,
+};
+pub use learnsets::{
+    HMS_GEN_1, LearnsetGen1, TMS_GEN_1, default_moveset_gen_1, default_moveset_in_version_gen_1, learnset_gen_1,
+    learnset_in_version_gen_1,
+};
+pub use level_cap::{BadgeGen1, LevelCapGen1, ProgressGen1, level_cap_gen_1, next_boss_gen_1};
+pub use search::{
+    OutcomeGen1, SearchObjectiveGen1, SearchOptionsGen1, SearchResultGen1, solve_endgame_gen_1,
+};
@@ -0,0 +1,300 @@
+use crate::battle::{BattlerGen1, crit_chance_gen_1, effective_speed_gen_1, hit_chance_gen_1};
+use crate::{calc_damage_rolls_gen_1, damage_is_predictable_gen_1, fixed_damage_rolls_gen_1};
+
+/// How one move fares against one Pokémon.
+///
+/// # Fields
+/// - `attacker`/`defender`: Party indices of the two Pokémon
+/// - `move_index`: Index of the move in the attacker's moveset
+/// - `move_name`: The move's name
+/// - `min_damage`/`max_damage`: Lowest and highest non-critical rolls
+/// - `crit_max_damage`: Highest critical hit roll
+/// - `defender_hp`/`defender_max_hp`: The defender's current and maximum HP
+/// - `ko_chance`: Chance that a single use KOs from current HP, including
+///   accuracy and critical hits
+/// - `hits_to_ko`: Hits needed to KO on minimum non-critical rolls, if the
+///   move does damage at all
+/// - `predictable`: Whether the damage could be worked out at all; Counter's
+///   depends on the last hit taken, so it's left at 0
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoveMatchupGen1 {
+    pub attacker: usize,
+    pub defender: usize,
+    pub move_index: usize,
+    pub move_name: String,
+    pub min_damage: u16,
+    pub max_damage: u16,
+    pub crit_max_damage: u16,
+    pub defender_hp: u16,
+    pub defender_max_hp: u16,
+    pub ko_chance: f64,
+    pub hits_to_ko: Option<u16>,
+    pub predictable: bool,
+}
+
+/// Which of two Pokémon moves first.
+///
+/// # Fields
+/// - `ally`/`enemy`: Party indices
+/// - `ally_speed`/`enemy_speed`: Speed after stat stages and paralysis
+/// - `ally_first_chance`: Chance the ally moves first with equal priority
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpeedMatchupGen1 {
+    pub ally: usize,
+    pub enemy: usize,
+    pub ally_speed: u32,
+    pub enemy_speed: u32,
+    pub ally_first_chance: f64,
+}
+
+/// Every move of one party against every Pokémon of another, both ways,
+/// plus Speed comparisons for each pairing.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchupMatrixGen1 {
+    pub allies: Vec<String>,
+    pub enemies: Vec<String>,
+    pub ally_attacks: Vec<MoveMatchupGen1>,
+    pub enemy_attacks: Vec<MoveMatchupGen1>,
+    pub speeds: Vec<SpeedMatchupGen1>,
+}
+
+impl MoveMatchupGen1 {
+    /// Minimum damage as a percentage of the defender's max HP.
+    pub fn min_percent(&self) -> f64 {
+        percent(self.min_damage, self.defender_max_hp)
+    }
+
+    /// Maximum damage as a percentage of the defender's max HP.
+    pub fn max_percent(&self) -> f64 {
+        percent(self.max_damage, self.defender_max_hp)
+    }
+}
+
+impl MatchupMatrixGen1 {
+    /// The entry for one ally move against one enemy.
+    pub fn ally_attack(&self, ally: usize, move_index: usize, enemy: usize) -> Option<&MoveMatchupGen1> {
+        find(&self.ally_attacks, ally, move_index, enemy)
+    }
+
+    /// The entry for one enemy move against one ally.
+    pub fn enemy_attack(&self, enemy: usize, move_index: usize, ally: usize) -> Option<&MoveMatchupGen1> {
+        find(&self.enemy_attacks, enemy, move_index, ally)
+    }
+
+    /// The Speed comparison between an ally and an enemy.
+    pub fn speed(&self, ally: usize, enemy: usize) -> Option<&SpeedMatchupGen1> {
+        self.speeds.iter().find(|s| s.ally == ally && s.enemy == enemy)
+    }
+}
+
+/// Builds the matchup matrix between the player's party and an opponent's.
+///
+/// Damage is calculated from each Pokémon's current stats, stages and
+/// status, against the defender's current HP. Moves that skip the damage
+/// formula use [`fixed_damage_rolls_gen_1`] instead.
+///
+/// # Example
+/// ```
+/// use engine::{find_trainers_by_name_gen_1, matchup_matrix_gen_1};
+///
+/// let brock = find_trainers_by_name_gen_1("Brock")[0];
+/// let misty = find_trainers_by_name_gen_1("Misty")[0];
+/// let matrix = matchup_matrix_gen_1(&misty.battlers(), &brock.battlers());
+///
+/// // Starmie's Bubble Beam always KOs Brock's Geodude
+/// let bubble_beam = matrix.ally_attack(1, 3, 0).unwrap();
+/// assert!(bubble_beam.ko_chance > 0.99);
+/// ```
+pub fn matchup_matrix_gen_1(allies: &[BattlerGen1], enemies: &[BattlerGen1]) -> MatchupMatrixGen1 {
+    let mut speeds = Vec::new();
+    for (a, ally) in allies.iter().enumerate() {
+        for (e, enemy) in enemies.iter().enumerate() {
+            let ally_speed = effective_speed_gen_1(&ally.pokemon);
+            let enemy_speed = effective_speed_gen_1(&enemy.pokemon);
+            let ally_first_chance = match ally_speed.cmp(&enemy_speed) {
+                std::cmp::Ordering::Greater => 1.0,
+                std::cmp::Ordering::Less => 0.0,
+                std::cmp::Ordering::Equal => 0.5,
+            };
+            speeds.push(SpeedMatchupGen1 { ally: a, enemy: e, ally_speed, enemy_speed, ally_first_chance });
+        }
+    }
+
+    MatchupMatrixGen1 {
+        allies: allies.iter().map(|b| b.pokemon.name.clone()).collect(),
+        enemies: enemies.iter().map(|b| b.pokemon.name.clone()).collect(),
+        ally_attacks: attacks(allies, enemies),
+        enemy_attacks: attacks(enemies, allies),
+        speeds,
+    }
+}
+
+/// Every move of every attacker against every defender.
+fn attacks(attackers: &[BattlerGen1], defenders: &[BattlerGen1]) -> Vec<MoveMatchupGen1> {
+    let mut matchups = Vec::new();
+    for (a, attacker) in attackers.iter().enumerate() {
+        for m in 0..attacker.moves.len() {
+            for (d, defender) in defenders.iter().enumerate() {
+                matchups.push(move_matchup(attacker, defender, m, (a, d)));
+            }
+        }
+    }
+    matchups
+}
+
+fn move_matchup(
+    attacker: &BattlerGen1,
+    defender: &BattlerGen1,
+    move_index: usize,
+    (a, d): (usize, usize),
+) -> MoveMatchupGen1 {
+    let mov = &attacker.moves[move_index];
+    let normal = damage_rolls_gen_1(attacker, defender, move_index, false);
+    let critical = damage_rolls_gen_1(attacker, defender, move_index, true);
+
+    let ko_fraction = |rolls: &[u16]| {
+        if rolls.is_empty() {
+            0.0
+        } else {
+            rolls.iter().filter(|&&r| r >= defender.hp).count() as f64 / rolls.len() as f64
+        }
+    };
+    let crit = crit_chance_gen_1(attacker.base_speed, mov);
+    let ko_chance = hit_chance_gen_1(mov)
+        * ((1.0 - crit) * ko_fraction(&normal) + crit * ko_fraction(&critical));
+
+    let min_damage = normal.first().copied().unwrap_or(0);
+    MoveMatchupGen1 {
+        attacker: a,
+        defender: d,
+        move_index,
+        move_name: mov.name.clone(),
+        min_damage,
+        max_damage: normal.last().copied().unwrap_or(0),
+        crit_max_damage: critical.last().copied().unwrap_or(0),
+        defender_hp: defender.hp,
+        defender_max_hp: defender.pokemon.stats.hp,
+        ko_chance,
+        hits_to_ko: (min_damage > 0).then(|| defender.hp.div_ceil(min_damage)),
+        predictable: damage_is_predictable_gen_1(mov),
+    }
+}
+
+/// Every equally likely damage roll for one of the attacker's moves, in
+/// ascending order. Moves that skip the damage formula get the rolls of
+/// [`fixed_damage_rolls_gen_1`], and moves that don't do damage have none.
+///
+/// # Example
+/// ```
+/// use engine::{DAMAGE_ROLL_COUNT, damage_rolls_gen_1, find_trainers_by_name_gen_1};
+///
+/// let misty = find_trainers_by_name_gen_1("Misty")[0].battlers();
+/// let brock = find_trainers_by_name_gen_1("Brock")[0].battlers();
+/// // Staryu's Tackle, then its Water Gun
+/// let tackle = damage_rolls_gen_1(&misty[0], &brock[0], 0, false);
+/// let water_gun = damage_rolls_gen_1(&misty[0], &brock[0], 1, false);
+/// assert_eq!(tackle.len(), DAMAGE_ROLL_COUNT);
+/// assert_eq!(water_gun.len(), DAMAGE_ROLL_COUNT);
+/// // Super effective twice over on Geodude
+/// assert!(water_gun[0] > tackle[DAMAGE_ROLL_COUNT - 1]);
+/// ```
+pub fn damage_rolls_gen_1(attacker: &BattlerGen1, defender: &BattlerGen1, move_index: usize, is_critical: bool) -> Vec<u16> {
+    let mov = &attacker.moves[move_index];
+    match fixed_damage_rolls_gen_1(&attacker.pokemon, &defender.pokemon, defender.hp, mov) {
+        Some(rolls) => rolls,
+        None if mov.power == 0 => Vec::new(),
+        None => calc_damage_rolls_gen_1(&attacker.pokemon, &defender.pokemon, mov, is_critical),
+    }
+}
+
+fn find(matchups: &[MoveMatchupGen1], attacker: usize, move_index: usize, defender: usize) -> Option<&MoveMatchupGen1> {
+    matchups.iter().find(|m| m.attacker == attacker && m.move_index == move_index && m.defender == defender)
+}
+
+fn percent(damage: u16, max_hp: u16) -> f64 {
+    if max_hp == 0 {
+        0.0
+    } else {
+        damage as f64 * 100.0 / max_hp as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::find_trainers_by_name_gen_1;
+
+    /// The matrix covers every move against every Pokémon in both directions.
+    #[test]
+    fn test_matrix_shape() {
+        let surge = find_trainers_by_name_gen_1("Lt. Surge")[0].battlers();
+        let erika = find_trainers_by_name_gen_1("Erika")[0].battlers();
+        let matrix = matchup_matrix_gen_1(&surge, &erika);
+
+        let ally_moves: usize = surge.iter().map(|b| b.moves.len()).sum();
+        let enemy_moves: usize = erika.iter().map(|b| b.moves.len()).sum();
+        assert_eq!(matrix.ally_attacks.len(), ally_moves * erika.len());
+        assert_eq!(matrix.enemy_attacks.len(), enemy_moves * surge.len());
+        assert_eq!(matrix.speeds.len(), surge.len() * erika.len());
+
+        // Sonic Boom always does 20, status moves nothing
+        let sonic_boom = matrix.ally_attack(0, 2, 1).unwrap();
+        assert_eq!((sonic_boom.min_damage, sonic_boom.max_damage), (20, 20));
+        let screech = matrix.ally_attack(0, 1, 1).unwrap();
+        assert_eq!(screech.max_damage, 0);
+        assert_eq!(screech.hits_to_ko, None);
+        assert_eq!(screech.ko_chance, 0.0);
+    }
+
+    fn battler(species: &str, level: u8, moves: &[&str]) -> BattlerGen1 {
+        let species = crate::find_species_gen_1(species).unwrap();
+        let pokemon = species.pokemon(level, crate::pokemon::IVsGen1::default());
+        let moves = moves.iter().map(|m| crate::find_move_gen_1(m).unwrap()).collect();
+        BattlerGen1::new(pokemon, moves, species.base.speed)
+    }
+
+    /// One-hit KO moves, Psywave and Counter skip the damage formula.
+    #[test]
+    fn test_formula_free_moves() {
+        let machamp = battler("Machamp", 50, &["Fissure", "Psywave", "Counter"]);
+        let onix = battler("Onix", 14, &[]);
+        assert_eq!(damage_rolls_gen_1(&machamp, &onix, 0, false), [onix.hp]);
+        assert_eq!(damage_rolls_gen_1(&machamp, &onix, 0, true), [onix.hp]);
+
+        // Immune, faster or higher level defenders can't be hit
+        let pidgeot = battler("Pidgeot", 20, &[]);
+        assert_eq!(damage_rolls_gen_1(&machamp, &pidgeot, 0, false), [0]);
+        let electrode = battler("Electrode", 50, &[]);
+        assert_eq!(damage_rolls_gen_1(&machamp, &electrode, 0, false), [0]);
+        let low_machamp = battler("Machamp", 10, &["Fissure"]);
+        assert_eq!(damage_rolls_gen_1(&low_machamp, &onix, 0, false), [0]);
+
+        // Psywave is anything from 1 to just under 1.5× the level
+        let psywave = damage_rolls_gen_1(&machamp, &onix, 1, false);
+        assert_eq!(psywave, (1..75).collect::<Vec<u16>>());
+
+        let matrix = matchup_matrix_gen_1(&[machamp], &[onix]);
+        let fissure = matrix.ally_attack(0, 0, 0).unwrap();
+        assert_eq!((fissure.min_damage, fissure.hits_to_ko), (fissure.defender_hp, Some(1)));
+        assert!((fissure.ko_chance - 0.3).abs() < 0.01);
+        let counter = matrix.ally_attack(0, 2, 0).unwrap();
+        assert!(!counter.predictable);
+        assert_eq!((counter.max_damage, counter.ko_chance), (0, 0.0));
+        assert!(matrix.ally_attack(0, 1, 0).unwrap().predictable);
+    }
+
+    /// A matrix survives a trip through JSON unchanged.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let brock = find_trainers_by_name_gen_1("Brock")[0].battlers();
+        let misty = find_trainers_by_name_gen_1("Misty")[0].battlers();
+        let matrix = matchup_matrix_gen_1(&brock, &misty);
+        let json = serde_json::to_string(&matrix).unwrap();
+        assert!(json.contains(r#""move_name":"Water Gun""#));
+        assert_eq!(serde_json::from_str::<MatchupMatrixGen1>(&json).unwrap(), matrix);
+    }
+}
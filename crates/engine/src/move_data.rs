@@ -0,0 +1,266 @@
+use crate::{MoveCategory, MoveEffectGen1, MoveGen1, StatGen1, TypeGen1};
+use crate::species::{closest_names, name_key};
+
+/// Static data for a Generation 1 move.
+///
+/// Moves whose damage doesn't come from the damage formula (Seismic Toss,
+/// Dragon Rage, one-hit KO moves, ...) have a power of 1, as in the games'
+/// own data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MoveDataGen1 {
+    pub name: &'static str,
+    pub typ: TypeGen1,
+    pub power: u8,
+    pub accuracy: u8,
+    pub effect: MoveEffectGen1,
+}
+
+impl MoveDataGen1 {
+    /// The move's category. In Gen 1 it's decided by type alone: Fire,
+    /// Water, Electric, Grass, Ice, Psychic and Dragon moves are special.
+    pub fn category(&self) -> MoveCategory {
+        match self.typ {
+            _ if self.power == 0 => MoveCategory::Status,
+            TypeGen1::Fire | TypeGen1::Water | TypeGen1::Electric | TypeGen1::Grass
+            | TypeGen1::Ice | TypeGen1::Psychic | TypeGen1::Dragon => MoveCategory::Special,
+            _ => MoveCategory::Physical,
+        }
+    }
+
+    /// Builds a [`MoveGen1`] from the data.
+    pub fn to_move(&self) -> MoveGen1 {
+        MoveGen1 {
+            name: self.name.into(),
+            typ: self.typ,
+            power: self.power,
+            category: self.category(),
+            accuracy: self.accuracy,
+            effect: self.effect,
+        }
+    }
+}
+
+/// Looks up a move by name, ignoring case, spaces and punctuation.
+///
+/// # Example
+/// ```
+/// use engine::{find_move_gen_1, MoveCategory, TypeGen1};
+///
+/// let surf = find_move_gen_1("surf").unwrap();
+/// assert_eq!(surf.power, 95);
+/// assert_eq!(surf.category, MoveCategory::Special);
+/// assert_eq!(find_move_gen_1("Thunder Punch"), find_move_gen_1("thunderpunch"));
+/// ```
+pub fn find_move_gen_1(name: &str) -> Option<MoveGen1> {
+    find_move_data_gen_1(name).map(MoveDataGen1::to_move)
+}
+
+/// Looks up a move's static data by name; see [`find_move_gen_1`].
+pub fn find_move_data_gen_1(name: &str) -> Option<&'static MoveDataGen1> {
+    let key = name_key(name);
+    MOVES_GEN_1.iter().find(|m| name_key(m.name) == key)
+}
+
+/// Move names closest to a misspelt one; see
+/// [`closest_names`](crate::species::closest_names).
+pub fn suggest_moves_gen_1(name: &str) -> Vec<&'static str> {
+    closest_names(name, MOVES_GEN_1.iter().map(|m| m.name))
+}
+
+const fn data(name: &'static str, typ: TypeGen1, power: u8, accuracy: u8, effect: MoveEffectGen1) -> MoveDataGen1 {
+    MoveDataGen1 { name, typ, power, accuracy, effect }
+}
+
+/// Every Generation 1 move, in the games' internal order (Pound is move 1).
+pub static MOVES_GEN_1: [MoveDataGen1; 165] = [
+    data("Pound", TypeGen1::Normal, 40, 100, MoveEffectGen1::None),
+    data("Karate Chop", TypeGen1::Normal, 50, 100, MoveEffectGen1::None),
+    data("Double Slap", TypeGen1::Normal, 15, 85, MoveEffectGen1::MultiHit),
+    data("Comet Punch", TypeGen1::Normal, 18, 85, MoveEffectGen1::MultiHit),
+    data("Mega Punch", TypeGen1::Normal, 80, 85, MoveEffectGen1::None),
+    data("Pay Day", TypeGen1::Normal, 40, 100, MoveEffectGen1::PayDay),
+    data("Fire Punch", TypeGen1::Fire, 75, 100, MoveEffectGen1::BurnChance(10)),
+    data("Ice Punch", TypeGen1::Ice, 75, 100, MoveEffectGen1::FreezeChance(10)),
+    data("Thunder Punch", TypeGen1::Electric, 75, 100, MoveEffectGen1::ParalyzeChance(10)),
+    data("Scratch", TypeGen1::Normal, 40, 100, MoveEffectGen1::None),
+    data("Vice Grip", TypeGen1::Normal, 55, 100, MoveEffectGen1::None),
+    data("Guillotine", TypeGen1::Normal, 1, 30, MoveEffectGen1::OneHitKo),
+    data("Razor Wind", TypeGen1::Normal, 80, 75, MoveEffectGen1::Charge),
+    data("Swords Dance", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Attack, 2)),
+    data("Cut", TypeGen1::Normal, 50, 95, MoveEffectGen1::None),
+    data("Gust", TypeGen1::Normal, 40, 100, MoveEffectGen1::None),
+    data("Wing Attack", TypeGen1::Flying, 35, 100, MoveEffectGen1::None),
+    data("Whirlwind", TypeGen1::Normal, 0, 85, MoveEffectGen1::Escape),
+    data("Fly", TypeGen1::Flying, 70, 95, MoveEffectGen1::Fly),
+    data("Bind", TypeGen1::Normal, 15, 75, MoveEffectGen1::Trap),
+    data("Slam", TypeGen1::Normal, 80, 75, MoveEffectGen1::None),
+    data("Vine Whip", TypeGen1::Grass, 35, 100, MoveEffectGen1::None),
+    data("Stomp", TypeGen1::Normal, 65, 100, MoveEffectGen1::FlinchChance(30)),
+    data("Double Kick", TypeGen1::Fighting, 30, 100, MoveEffectGen1::TwoHit),
+    data("Mega Kick", TypeGen1::Normal, 120, 75, MoveEffectGen1::None),
+    data("Jump Kick", TypeGen1::Fighting, 70, 95, MoveEffectGen1::JumpKick),
+    data("Rolling Kick", TypeGen1::Fighting, 60, 85, MoveEffectGen1::FlinchChance(30)),
+    data("Sand Attack", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatDown(StatGen1::Accuracy, 1)),
+    data("Headbutt", TypeGen1::Normal, 70, 100, MoveEffectGen1::FlinchChance(30)),
+    data("Horn Attack", TypeGen1::Normal, 65, 100, MoveEffectGen1::None),
+    data("Fury Attack", TypeGen1::Normal, 15, 85, MoveEffectGen1::MultiHit),
+    data("Horn Drill", TypeGen1::Normal, 1, 30, MoveEffectGen1::OneHitKo),
+    data("Tackle", TypeGen1::Normal, 35, 95, MoveEffectGen1::None),
+    data("Body Slam", TypeGen1::Normal, 85, 100, MoveEffectGen1::ParalyzeChance(30)),
+    data("Wrap", TypeGen1::Normal, 15, 85, MoveEffectGen1::Trap),
+    data("Take Down", TypeGen1::Normal, 90, 85, MoveEffectGen1::Recoil),
+    data("Thrash", TypeGen1::Normal, 90, 100, MoveEffectGen1::Thrash),
+    data("Double-Edge", TypeGen1::Normal, 100, 100, MoveEffectGen1::Recoil),
+    data("Tail Whip", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatDown(StatGen1::Defense, 1)),
+    data("Poison Sting", TypeGen1::Poison, 15, 100, MoveEffectGen1::PoisonChance(20)),
+    data("Twineedle", TypeGen1::Bug, 25, 100, MoveEffectGen1::Twineedle),
+    data("Pin Missile", TypeGen1::Bug, 14, 85, MoveEffectGen1::MultiHit),
+    data("Leer", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatDown(StatGen1::Defense, 1)),
+    data("Bite", TypeGen1::Normal, 60, 100, MoveEffectGen1::FlinchChance(10)),
+    data("Growl", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatDown(StatGen1::Attack, 1)),
+    data("Roar", TypeGen1::Normal, 0, 100, MoveEffectGen1::Escape),
+    data("Sing", TypeGen1::Normal, 0, 55, MoveEffectGen1::Sleep),
+    data("Supersonic", TypeGen1::Normal, 0, 55, MoveEffectGen1::Confuse),
+    data("Sonic Boom", TypeGen1::Normal, 1, 90, MoveEffectGen1::FixedDamage(20)),
+    data("Disable", TypeGen1::Normal, 0, 55, MoveEffectGen1::Disable),
+    data("Acid", TypeGen1::Poison, 40, 100, MoveEffectGen1::StatDownChance(StatGen1::Defense, 33)),
+    data("Ember", TypeGen1::Fire, 40, 100, MoveEffectGen1::BurnChance(10)),
+    data("Flamethrower", TypeGen1::Fire, 95, 100, MoveEffectGen1::BurnChance(10)),
+    data("Mist", TypeGen1::Ice, 0, 100, MoveEffectGen1::Mist),
+    data("Water Gun", TypeGen1::Water, 40, 100, MoveEffectGen1::None),
+    data("Hydro Pump", TypeGen1::Water, 120, 80, MoveEffectGen1::None),
+    data("Surf", TypeGen1::Water, 95, 100, MoveEffectGen1::None),
+    data("Ice Beam", TypeGen1::Ice, 95, 100, MoveEffectGen1::FreezeChance(10)),
+    data("Blizzard", TypeGen1::Ice, 120, 90, MoveEffectGen1::FreezeChance(10)),
+    data("Psybeam", TypeGen1::Psychic, 65, 100, MoveEffectGen1::ConfuseChance(10)),
+    data("Bubble Beam", TypeGen1::Water, 65, 100, MoveEffectGen1::StatDownChance(StatGen1::Speed, 33)),
+    data("Aurora Beam", TypeGen1::Ice, 65, 100, MoveEffectGen1::StatDownChance(StatGen1::Attack, 33)),
+    data("Hyper Beam", TypeGen1::Normal, 150, 90, MoveEffectGen1::Recharge),
+    data("Peck", TypeGen1::Flying, 35, 100, MoveEffectGen1::None),
+    data("Drill Peck", TypeGen1::Flying, 80, 100, MoveEffectGen1::None),
+    data("Submission", TypeGen1::Fighting, 80, 80, MoveEffectGen1::Recoil),
+    data("Low Kick", TypeGen1::Fighting, 50, 90, MoveEffectGen1::FlinchChance(30)),
+    data("Counter", TypeGen1::Fighting, 1, 100, MoveEffectGen1::Counter),
+    data("Seismic Toss", TypeGen1::Fighting, 1, 100, MoveEffectGen1::LevelDamage),
+    data("Strength", TypeGen1::Normal, 80, 100, MoveEffectGen1::None),
+    data("Absorb", TypeGen1::Grass, 20, 100, MoveEffectGen1::Drain),
+    data("Mega Drain", TypeGen1::Grass, 40, 100, MoveEffectGen1::Drain),
+    data("Leech Seed", TypeGen1::Grass, 0, 90, MoveEffectGen1::LeechSeed),
+    data("Growth", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Special, 1)),
+    data("Razor Leaf", TypeGen1::Grass, 55, 95, MoveEffectGen1::None),
+    data("Solar Beam", TypeGen1::Grass, 120, 100, MoveEffectGen1::Charge),
+    data("Poison Powder", TypeGen1::Poison, 0, 75, MoveEffectGen1::Poison),
+    data("Stun Spore", TypeGen1::Grass, 0, 75, MoveEffectGen1::Paralyze),
+    data("Sleep Powder", TypeGen1::Grass, 0, 75, MoveEffectGen1::Sleep),
+    data("Petal Dance", TypeGen1::Grass, 70, 100, MoveEffectGen1::Thrash),
+    data("String Shot", TypeGen1::Bug, 0, 95, MoveEffectGen1::StatDown(StatGen1::Speed, 1)),
+    data("Dragon Rage", TypeGen1::Dragon, 1, 100, MoveEffectGen1::FixedDamage(40)),
+    data("Fire Spin", TypeGen1::Fire, 15, 70, MoveEffectGen1::Trap),
+    data("Thunder Shock", TypeGen1::Electric, 40, 100, MoveEffectGen1::ParalyzeChance(10)),
+    data("Thunderbolt", TypeGen1::Electric, 95, 100, MoveEffectGen1::ParalyzeChance(10)),
+    data("Thunder Wave", TypeGen1::Electric, 0, 100, MoveEffectGen1::Paralyze),
+    data("Thunder", TypeGen1::Electric, 120, 70, MoveEffectGen1::ParalyzeChance(10)),
+    data("Rock Throw", TypeGen1::Rock, 50, 65, MoveEffectGen1::None),
+    data("Earthquake", TypeGen1::Ground, 100, 100, MoveEffectGen1::None),
+    data("Fissure", TypeGen1::Ground, 1, 30, MoveEffectGen1::OneHitKo),
+    data("Dig", TypeGen1::Ground, 100, 100, MoveEffectGen1::Fly),
+    data("Toxic", TypeGen1::Poison, 0, 85, MoveEffectGen1::Poison),
+    data("Confusion", TypeGen1::Psychic, 50, 100, MoveEffectGen1::ConfuseChance(10)),
+    data("Psychic", TypeGen1::Psychic, 90, 100, MoveEffectGen1::StatDownChance(StatGen1::Special, 33)),
+    data("Hypnosis", TypeGen1::Psychic, 0, 60, MoveEffectGen1::Sleep),
+    data("Meditate", TypeGen1::Psychic, 0, 100, MoveEffectGen1::StatUp(StatGen1::Attack, 1)),
+    data("Agility", TypeGen1::Psychic, 0, 100, MoveEffectGen1::StatUp(StatGen1::Speed, 2)),
+    data("Quick Attack", TypeGen1::Normal, 40, 100, MoveEffectGen1::None),
+    data("Rage", TypeGen1::Normal, 20, 100, MoveEffectGen1::Rage),
+    data("Teleport", TypeGen1::Psychic, 0, 100, MoveEffectGen1::Escape),
+    data("Night Shade", TypeGen1::Ghost, 1, 100, MoveEffectGen1::LevelDamage),
+    data("Mimic", TypeGen1::Normal, 0, 100, MoveEffectGen1::Mimic),
+    data("Screech", TypeGen1::Normal, 0, 85, MoveEffectGen1::StatDown(StatGen1::Defense, 2)),
+    data("Double Team", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Evasion, 1)),
+    data("Recover", TypeGen1::Normal, 0, 100, MoveEffectGen1::Heal),
+    data("Harden", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Defense, 1)),
+    data("Minimize", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Evasion, 1)),
+    data("Smokescreen", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatDown(StatGen1::Accuracy, 1)),
+    data("Confuse Ray", TypeGen1::Ghost, 0, 100, MoveEffectGen1::Confuse),
+    data("Withdraw", TypeGen1::Water, 0, 100, MoveEffectGen1::StatUp(StatGen1::Defense, 1)),
+    data("Defense Curl", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Defense, 1)),
+    data("Barrier", TypeGen1::Psychic, 0, 100, MoveEffectGen1::StatUp(StatGen1::Defense, 2)),
+    data("Light Screen", TypeGen1::Psychic, 0, 100, MoveEffectGen1::LightScreen),
+    data("Haze", TypeGen1::Ice, 0, 100, MoveEffectGen1::Haze),
+    data("Reflect", TypeGen1::Psychic, 0, 100, MoveEffectGen1::Reflect),
+    data("Focus Energy", TypeGen1::Normal, 0, 100, MoveEffectGen1::FocusEnergy),
+    data("Bide", TypeGen1::Normal, 0, 100, MoveEffectGen1::Bide),
+    data("Metronome", TypeGen1::Normal, 0, 100, MoveEffectGen1::Metronome),
+    data("Mirror Move", TypeGen1::Flying, 0, 100, MoveEffectGen1::MirrorMove),
+    data("Self-Destruct", TypeGen1::Normal, 130, 100, MoveEffectGen1::Explode),
+    data("Egg Bomb", TypeGen1::Normal, 100, 75, MoveEffectGen1::None),
+    data("Lick", TypeGen1::Ghost, 20, 100, MoveEffectGen1::ParalyzeChance(30)),
+    data("Smog", TypeGen1::Poison, 20, 70, MoveEffectGen1::PoisonChance(40)),
+    data("Sludge", TypeGen1::Poison, 65, 100, MoveEffectGen1::PoisonChance(40)),
+    data("Bone Club", TypeGen1::Ground, 65, 85, MoveEffectGen1::FlinchChance(10)),
+    data("Fire Blast", TypeGen1::Fire, 120, 85, MoveEffectGen1::BurnChance(30)),
+    data("Waterfall", TypeGen1::Water, 80, 100, MoveEffectGen1::None),
+    data("Clamp", TypeGen1::Water, 35, 75, MoveEffectGen1::Trap),
+    data("Swift", TypeGen1::Normal, 60, 100, MoveEffectGen1::Swift),
+    data("Skull Bash", TypeGen1::Normal, 100, 100, MoveEffectGen1::Charge),
+    data("Spike Cannon", TypeGen1::Normal, 20, 100, MoveEffectGen1::MultiHit),
+    data("Constrict", TypeGen1::Normal, 10, 100, MoveEffectGen1::StatDownChance(StatGen1::Speed, 10)),
+    data("Amnesia", TypeGen1::Psychic, 0, 100, MoveEffectGen1::StatUp(StatGen1::Special, 2)),
+    data("Kinesis", TypeGen1::Psychic, 0, 80, MoveEffectGen1::StatDown(StatGen1::Accuracy, 1)),
+    data("Soft-Boiled", TypeGen1::Normal, 0, 100, MoveEffectGen1::Heal),
+    data("High Jump Kick", TypeGen1::Fighting, 85, 90, MoveEffectGen1::JumpKick),
+    data("Glare", TypeGen1::Normal, 0, 75, MoveEffectGen1::Paralyze),
+    data("Dream Eater", TypeGen1::Psychic, 100, 100, MoveEffectGen1::DreamEater),
+    data("Poison Gas", TypeGen1::Poison, 0, 55, MoveEffectGen1::Poison),
+    data("Barrage", TypeGen1::Normal, 15, 85, MoveEffectGen1::MultiHit),
+    data("Leech Life", TypeGen1::Bug, 20, 100, MoveEffectGen1::Drain),
+    data("Lovely Kiss", TypeGen1::Normal, 0, 75, MoveEffectGen1::Sleep),
+    data("Sky Attack", TypeGen1::Flying, 140, 90, MoveEffectGen1::Charge),
+    data("Transform", TypeGen1::Normal, 0, 100, MoveEffectGen1::Transform),
+    data("Bubble", TypeGen1::Water, 20, 100, MoveEffectGen1::StatDownChance(StatGen1::Speed, 10)),
+    data("Dizzy Punch", TypeGen1::Normal, 70, 100, MoveEffectGen1::None),
+    data("Spore", TypeGen1::Grass, 0, 100, MoveEffectGen1::Sleep),
+    data("Flash", TypeGen1::Normal, 0, 70, MoveEffectGen1::StatDown(StatGen1::Accuracy, 1)),
+    data("Psywave", TypeGen1::Psychic, 1, 80, MoveEffectGen1::Psywave),
+    data("Splash", TypeGen1::Normal, 0, 100, MoveEffectGen1::Splash),
+    data("Acid Armor", TypeGen1::Poison, 0, 100, MoveEffectGen1::StatUp(StatGen1::Defense, 2)),
+    data("Crabhammer", TypeGen1::Water, 90, 85, MoveEffectGen1::None),
+    data("Explosion", TypeGen1::Normal, 170, 100, MoveEffectGen1::Explode),
+    data("Fury Swipes", TypeGen1::Normal, 18, 80, MoveEffectGen1::MultiHit),
+    data("Bonemerang", TypeGen1::Ground, 50, 90, MoveEffectGen1::TwoHit),
+    data("Rest", TypeGen1::Psychic, 0, 100, MoveEffectGen1::Heal),
+    data("Rock Slide", TypeGen1::Rock, 75, 90, MoveEffectGen1::None),
+    data("Hyper Fang", TypeGen1::Normal, 80, 90, MoveEffectGen1::FlinchChance(10)),
+    data("Sharpen", TypeGen1::Normal, 0, 100, MoveEffectGen1::StatUp(StatGen1::Attack, 1)),
+    data("Conversion", TypeGen1::Normal, 0, 100, MoveEffectGen1::Conversion),
+    data("Tri Attack", TypeGen1::Normal, 80, 100, MoveEffectGen1::None),
+    data("Super Fang", TypeGen1::Normal, 1, 90, MoveEffectGen1::SuperFang),
+    data("Slash", TypeGen1::Normal, 70, 100, MoveEffectGen1::None),
+    data("Substitute", TypeGen1::Normal, 0, 100, MoveEffectGen1::Substitute),
+    data("Struggle", TypeGen1::Normal, 50, 100, MoveEffectGen1::Recoil),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Categories follow type, and status moves have no power.
+    #[test]
+    fn test_move_categories() {
+        assert_eq!(find_move_gen_1("Karate Chop").unwrap().category, MoveCategory::Physical);
+        assert_eq!(find_move_gen_1("Dragon Rage").unwrap().category, MoveCategory::Special);
+        assert_eq!(find_move_gen_1("Thunder Wave").unwrap().category, MoveCategory::Status);
+        assert!(find_move_gen_1("Shadow Ball").is_none());
+    }
+
+    /// Misspelt names get the moves and species they were probably meant
+    /// to be.
+    #[test]
+    fn test_suggestions() {
+        assert_eq!(suggest_moves_gen_1("Thunderblot"), ["Thunderbolt"]);
+        assert_eq!(suggest_moves_gen_1("hyperbeem")[0], "Hyper Beam");
+        assert_eq!(crate::suggest_species_gen_1("Charizzard"), ["Charizard"]);
+        assert_eq!(crate::suggest_species_gen_1("nidoran"), ["Nidoran♀", "Nidoran♂", "Nidorina"]);
+        assert!(suggest_moves_gen_1("Moonblast").is_empty());
+    }
+}
@@ -3,7 +3,8 @@ use crate::TypeGen1;
 /// Generation 1 Move representation.
 /// 
 /// This struct defines a move in Generation 1 of the game, including its name, type, power, and category.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MoveGen1 {
     /// The name of the move (e.g., "Tackle", "Fire Blast").
     pub name: String,
@@ -16,10 +17,17 @@ pub struct MoveGen1 {
     
     /// The category of the move (Physical, Special, or Status).
     pub category: MoveCategory,
+
+    /// The accuracy of the move as a percentage (e.g., 100 for Tackle, 70 for Thunder).
+    pub accuracy: u8,
+
+    /// The move's additional effect (e.g., Thunder Wave paralyses, Growl lowers Attack).
+    pub effect: MoveEffectGen1,
 }
 
 /// Represents the category of a move in Generation 1.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MoveCategory {
     /// Physical moves (affected by Attack and Defense stats).
     Physical,
@@ -31,18 +39,115 @@ pub enum MoveCategory {
     Status,
 }
 
+/// Stats that moves can raise or lower in Generation 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StatGen1 {
+    Attack,
+    Defense,
+    Speed,
+    Special,
+    Accuracy,
+    Evasion,
+}
+
+/// Additional effects of Generation 1 moves.
+///
+/// Grouped the way the games group them internally, so that logic such as
+/// the trainer AI can check for a whole family of effects at once.
+///
+/// # Variants
+/// - `None`: Plain damage, no additional effect
+/// - `Sleep`/`Poison`/`Paralyze`/`Confuse`: Status moves that only inflict the condition
+/// - `*Chance(percent)`: Damaging moves with a chance of a side effect
+/// - `StatUp(stat, stages)`: Raises the user's stat (e.g., Swords Dance)
+/// - `StatDown(stat, stages)`: Lowers the target's stat (e.g., Growl)
+/// - `Heal`: Restores the user's HP (Recover, Softboiled, Rest)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoveEffectGen1 {
+    #[default]
+    None,
+
+    // Status moves
+    Sleep,
+    Poison,
+    Paralyze,
+    Confuse,
+
+    // Side effects of damaging moves
+    PoisonChance(u8),
+    BurnChance(u8),
+    FreezeChance(u8),
+    ParalyzeChance(u8),
+    ConfuseChance(u8),
+    FlinchChance(u8),
+    StatDownChance(StatGen1, u8),
+
+    // Stat changes
+    StatUp(StatGen1, u8),
+    StatDown(StatGen1, u8),
+
+    // Damage variations
+    Drain,
+    DreamEater,
+    Recoil,
+    Explode,
+    MultiHit,
+    TwoHit,
+    Twineedle,
+    OneHitKo,
+    FixedDamage(u8),
+    LevelDamage,
+    Psywave,
+    SuperFang,
+    Swift,
+    PayDay,
+    Charge,
+    Fly,
+    Recharge,
+    Trap,
+    Thrash,
+    Rage,
+    JumpKick,
+    Counter,
+    Bide,
+
+    // Field and utility effects
+    Heal,
+    Haze,
+    Reflect,
+    LightScreen,
+    Mist,
+    FocusEnergy,
+    Substitute,
+    LeechSeed,
+    Disable,
+    Mimic,
+    Metronome,
+    MirrorMove,
+    Transform,
+    Conversion,
+    Escape,
+    Splash,
+}
+
 impl Default for MoveGen1 {
     /// Creates a default `MoveGen1` with:
     /// - Empty name
     /// - Type: `Normal`
     /// - Power: `50`
     /// - Category: `Physical`
+    /// - Accuracy: `100`
+    /// - Effect: `None`
     fn default() -> Self {
         Self { 
             name: String::new(), 
             typ: TypeGen1::Normal, 
             power: 50, 
-            category: MoveCategory::Physical
+            category: MoveCategory::Physical,
+            accuracy: 100,
+            effect: MoveEffectGen1::None,
         }
     }
 }
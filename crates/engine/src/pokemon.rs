@@ -1,4 +1,4 @@
-use crate::TypeGen1;
+use crate::{MoveGen1, TypeGen1};
 
 /// Represents a Pokémon in Generation 1, including its stats, types, and battle state.
 ///
@@ -10,9 +10,12 @@ use crate::TypeGen1;
 /// - `evs`: Effort Values (0-65535 for each stat)
 /// - `stat_stages`: Current stat modifiers (-6 to +6)
 /// - `status`: Current status condition (Burned, Paralyzed, etc.)
+/// - `moves`: The moves it knows (up to 4)
 ///
 /// # Examples
 /// ```
+/// use engine::{PokemonGen1, StatsGen1, TypeGen1};
+///
 /// let mut pikachu = PokemonGen1 {
 ///     name: String::from("Pikachu"),
 ///     types: [TypeGen1::Electric, TypeGen1::None],
@@ -22,6 +25,7 @@ use crate::TypeGen1;
 /// pikachu.recalculate_stats();
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default))]
 pub struct PokemonGen1 {
     pub name: String,
     pub types: [TypeGen1; 2], // e.g., [Type::Electric, Type::None]
@@ -30,6 +34,7 @@ pub struct PokemonGen1 {
     pub evs: EVsGen1,
     pub stat_stages: StatStagesGen1,
     pub status: StatusGen1,
+    pub moves: Vec<MoveGen1>,
 }
 
 /// Individual Values (IVs) for a Generation 1 Pokémon.
@@ -39,6 +44,7 @@ pub struct PokemonGen1 {
 /// - Determine stat variation at level up
 /// - Hidden values set when Pokémon is obtained
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IVsGen1 {  // values are 0-15
     pub hp: u8,
     pub attack: u8,
@@ -52,8 +58,10 @@ pub struct IVsGen1 {  // values are 0-15
 /// In Gen 1:
 /// - Range: 0-65535 for each stat
 /// - Use square root in stat calculation (unlike later gens)
-/// - Gained by defeating Pokémon
+/// - Gained by defeating Pokémon: the defeated species' base stats are
+///   added to every stat (see [`EVsGen1::gain`])
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EVsGen1 {
     pub hp: u16,
     pub attack: u16,
@@ -67,14 +75,17 @@ pub struct EVsGen1 {
 /// # Notes
 /// - `special` handles both Special Attack and Defense (Gen 1 mechanic)
 /// - `hp` is calculated separately from other stats
+/// - Stats go up to 999, so late-game stats (e.g., Chansey's HP) don't fit
+///   in a byte
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatsGen1 {
     pub lvl: u8,
-    pub hp: u8,
-    pub attack: u8,
-    pub defense: u8,
-    pub special: u8,  // Gen 1 uses one special stat
-    pub speed: u8,
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub special: u16,  // Gen 1 uses one special stat
+    pub speed: u16,
 }
 
 /// Active stat stage modifiers during battle.
@@ -83,7 +94,8 @@ pub struct StatsGen1 {
 /// - Range: -6 to +6 for each stat
 /// - Affects damage calculation and speed order
 /// - Modified by moves like Growl (+1 Defense) or Swords Dance (+2 Attack)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatStagesGen1 { // Apply this in damage.rs?
     pub attack: i8, // -6 to +6
     pub defense: i8,
@@ -104,7 +116,8 @@ pub struct StatStagesGen1 { // Apply this in damage.rs?
 /// # Gen 1 Quirks
 /// - No "badly poisoned" (toxic) in Gen 1
 /// - Frozen can only be thawed by Fire moves or Haze
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatusGen1 {
     Healthy,
     Burned,
@@ -130,6 +143,7 @@ impl Default for PokemonGen1 {
     /// - Level 100
     /// - All stats/IVs/EVs at 0
     /// - No status conditions
+    /// - No moves
     fn default() -> Self {
         Self {
             name: String::new(),
@@ -139,6 +153,7 @@ impl Default for PokemonGen1 {
             evs: Default::default(),
             stat_stages: Default::default(),
             status: Default::default(),
+            moves: Vec::new(),
         }
     }
 }
@@ -210,30 +225,33 @@ impl PokemonGen1 {
     /// Calculates an individual stat using Gen 1 formulas.
     ///
     /// # Formula
-    /// ```
-    /// stat = ((base + IV) * 2 + (EV.sqrt())) * level / 100 + 5
-    /// HP = ((base + IV) * 2 + (EV.sqrt())) * level / 100 + 10
+    /// ```text
+    /// stat = ((base + IV) * 2 + min(ceil(EV.sqrt()), 255) / 4) * level / 100 + 5
+    /// HP = ((base + IV) * 2 + min(ceil(EV.sqrt()), 255) / 4) * level / 100 + level + 10
     /// ```
     ///
     /// # Arguments
     /// - `base_stat`: The species' base stat (e.g., Pikachu's base Speed is 90)
     /// - `iv`: Individual Value (0-15)
     /// - `ev`: Effort Value (0-65535)
-    /// - `is_hp`: Whether to calculate HP (uses +10 instead of +5)
+    /// - `is_hp`: Whether to calculate HP (adds level + 10 instead of 5)
     ///
     /// # Returns
-    /// The calculated stat value (capped at 255).
+    /// The calculated stat value (capped at 999, as in the games).
     ///
     /// # Example
     /// ```
+    /// use engine::PokemonGen1;
+    ///
     /// let pikachu = PokemonGen1::default();
     /// let speed = pikachu.calculate_stat(90, 15, 65535, false);
-    /// assert_eq!(speed, 207); // Pikachu's max Speed at level 100
+    /// assert_eq!(speed, 278); // Pikachu's max Speed at level 100
     /// ```
-    pub fn calculate_stat(&self, base_stat: u8, iv: u8, ev: u16, is_hp: bool) -> u8 {
-        // Gen 1 uses √EV in the calculation, not EV/4
-        let ev_factor = (ev as f64).sqrt().floor() as u16;
-        (((base_stat as u16 + iv as u16) * 2 + ev_factor) * self.stats.lvl as u16 / 100 + if is_hp { 10 } else { 5 }).min(255) as u8
+    pub fn calculate_stat(&self, base_stat: u8, iv: u8, ev: u16, is_hp: bool) -> u16 {
+        // Gen 1 uses √EV in the calculation, not EV/4, capped at 255 so
+        // maxed stat exp is worth 63 points before scaling by level
+        let ev_factor = ((ev as f64).sqrt().ceil() as u16).min(255) / 4;
+        (((base_stat as u16 + iv as u16) * 2 + ev_factor) * self.stats.lvl as u16 / 100 + if is_hp { self.stats.lvl as u16 + 10 } else { 5 }).min(999)
     }
 
     /// Recalculates all stats based on current IVs/EVs/level.
@@ -242,29 +260,57 @@ impl PokemonGen1 {
     ///
     /// # Example
     /// ```
+    /// use engine::PokemonGen1;
+    ///
     /// let mut pikachu = PokemonGen1::default();
     /// pikachu.stats.lvl = 50;
     /// pikachu.recalculate_stats();
     /// ```
     pub fn recalculate_stats(&mut self) {
+        // The current stats stand in for base stats, which fit in a byte
+        let base = |stat: u16| u8::try_from(stat).unwrap_or(u8::MAX);
         self.stats.hp = self.calculate_stat(
-            self.stats.hp, self.ivs.hp, self.evs.hp, true
+            base(self.stats.hp), self.ivs.hp, self.evs.hp, true
         );
 
         self.stats.attack = self.calculate_stat(
-            self.stats.attack, self.ivs.attack, self.evs.attack, false
+            base(self.stats.attack), self.ivs.attack, self.evs.attack, false
         );
 
         self.stats.defense = self.calculate_stat(
-            self.stats.defense, self.ivs.defense, self.evs.defense, false
+            base(self.stats.defense), self.ivs.defense, self.evs.defense, false
         );
 
         self.stats.special = self.calculate_stat(
-            self.stats.special, self.ivs.special, self.evs.special, false
+            base(self.stats.special), self.ivs.special, self.evs.special, false
         );
 
         self.stats.speed = self.calculate_stat(
-            self.stats.speed, self.ivs.speed, self.evs.speed, false
+            base(self.stats.speed), self.ivs.speed, self.evs.speed, false
         );
     }
-}
\ No newline at end of file
+}
+
+// ================= Stat Experience =================
+
+impl EVsGen1 {
+    /// Adds stat experience from a defeated Pokémon, capping each stat at
+    /// 65535.
+    ///
+    /// # Example
+    /// ```
+    /// use engine::pokemon::EVsGen1;
+    /// use engine::{find_species_gen_1, stat_exp_yield_gen_1};
+    ///
+    /// let mut evs = EVsGen1 { speed: 65_500, ..Default::default() };
+    /// evs.gain(&stat_exp_yield_gen_1(find_species_gen_1("Onix").unwrap()));
+    /// assert_eq!((evs.defense, evs.speed), (160, 65_535));
+    /// ```
+    pub fn gain(&mut self, gain: &EVsGen1) {
+        self.hp = self.hp.saturating_add(gain.hp);
+        self.attack = self.attack.saturating_add(gain.attack);
+        self.defense = self.defense.saturating_add(gain.defense);
+        self.special = self.special.saturating_add(gain.special);
+        self.speed = self.speed.saturating_add(gain.speed);
+    }
+}
@@ -0,0 +1,670 @@
+use std::fmt;
+
+use crate::{MOVES_GEN_1, PokemonGen1, SPECIES_GEN_1, SpeciesGen1, StatsGen1, StatusGen1, TypeGen1};
+use crate::dvs::hp_dv_gen_1;
+use crate::level_cap::ProgressGen1;
+use crate::pokemon::{EVsGen1, IVsGen1};
+
+/// Size of a Red, Blue or Yellow save: the cartridge's 32KB of SRAM.
+pub const SAVE_SIZE_GEN_1: usize = 0x8000;
+
+/// How many PC boxes there are.
+pub const BOX_COUNT_GEN_1: usize = 12;
+
+/// How many Pokémon a PC box holds.
+pub const BOX_CAPACITY_GEN_1: usize = 20;
+
+// Offsets into the save, from pokered's SRAM layout (international
+// versions; Japanese saves are laid out differently)
+const PLAYER_NAME: usize = 0x2598;
+const MONEY: usize = 0x25F3;
+const RIVAL_NAME: usize = 0x25F6;
+const BADGES: usize = 0x2602;
+const PLAYER_ID: usize = 0x2605;
+const CURRENT_BOX: usize = 0x284C;
+const PARTY: usize = 0x2F2C;
+const CURRENT_BOX_DATA: usize = 0x30C0;
+const MAIN_CHECKSUM: usize = 0x3523;
+const BOX_BANKS: [usize; 2] = [0x4000, 0x6000];
+const BOX_BANK_CHECKSUM: usize = 0x1A4C;
+const BOXES_PER_BANK: usize = 6;
+const BOX_SIZE: usize = 0x462;
+const NAME_LENGTH: usize = 11;
+const PARTY_MON_SIZE: usize = 44;
+const BOX_MON_SIZE: usize = 33;
+const TEXT_END: u8 = 0x50;
+
+/// A Pokémon stored in a save.
+///
+/// # Fields
+/// - `species`: Its species
+/// - `pokemon`: The Pokémon, ready for the damage calc: its level, DVs,
+///   stat experience, moves and status. Party Pokémon keep the stats stored
+///   in the save; boxed Pokémon have theirs calculated, as the games do
+///   when they're withdrawn
+/// - `nickname`: Its nickname
+/// - `ot_name`/`ot_id`: Its original trainer
+/// - `exp`: Total experience
+/// - `hp`: Current HP
+/// - `pp`: Current PP of each move
+/// - `pp_ups`: PP Ups used on each move (0-3)
+/// - `catch_rate`: The byte the games keep the species' catch rate in,
+///   which Gen 2 reads as a held item
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedPokemonGen1 {
+    pub species: &'static SpeciesGen1,
+    pub pokemon: PokemonGen1,
+    pub nickname: String,
+    pub ot_name: String,
+    pub ot_id: u16,
+    pub exp: u32,
+    pub hp: u16,
+    pub pp: Vec<u8>,
+    pub pp_ups: Vec<u8>,
+    pub catch_rate: u8,
+}
+
+/// What a Red, Blue or Yellow save holds about the player and their
+/// Pokémon.
+///
+/// # Fields
+/// - `player_name`/`rival_name`: The names chosen at the start
+/// - `player_id`: The trainer ID
+/// - `money`: Money in Pokédollars
+/// - `progress`: Badges earned (Elite Four progress isn't saved)
+/// - `party`: The party, in order
+/// - `boxes`: Every PC box, [`BOX_COUNT_GEN_1`] of them
+/// - `current_box`: Index of the box the PC deposits into
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SaveGen1 {
+    pub player_name: String,
+    pub rival_name: String,
+    pub player_id: u16,
+    pub money: u32,
+    pub progress: ProgressGen1,
+    pub party: Vec<SavedPokemonGen1>,
+    pub boxes: Vec<Vec<SavedPokemonGen1>>,
+    pub current_box: usize,
+}
+
+/// Why a save couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveErrorGen1 {
+    /// The file isn't [`SAVE_SIZE_GEN_1`] bytes long
+    WrongSize(usize),
+    /// The main data's checksum doesn't match, so the save is corrupt or
+    /// isn't a Gen 1 save
+    BadChecksum,
+    /// A party or box claims to hold more Pokémon than it can
+    BadCount(u8),
+    /// A species index that isn't a Pokémon (e.g., MissingNo.)
+    UnknownSpecies(u8),
+    /// A move index that isn't a move
+    UnknownMove(u8),
+    /// A name that's too long, or has characters the games can't show, or
+    /// a move that isn't a Gen 1 move
+    CantEncode(String),
+}
+
+impl fmt::Display for SaveErrorGen1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongSize(size) => write!(f, "a Gen 1 save is {SAVE_SIZE_GEN_1} bytes, not {size}"),
+            Self::BadChecksum => write!(f, "the save's checksum doesn't match"),
+            Self::BadCount(count) => write!(f, "a list of Pokémon claims to hold {count}"),
+            Self::UnknownSpecies(index) => write!(f, "unknown species index {index:#04x}"),
+            Self::UnknownMove(index) => write!(f, "unknown move index {index:#04x}"),
+            Self::CantEncode(text) => write!(f, "'{text}' can't be stored in a save"),
+        }
+    }
+}
+
+impl std::error::Error for SaveErrorGen1 {}
+
+/// Reads a Red, Blue or Yellow save (the `.sav` file emulators write).
+///
+/// The main data's checksum must match. A bank of boxes whose checksum
+/// doesn't match has never been written to, which happens until the
+/// player first changes box, so its boxes are read as empty.
+///
+/// # Errors
+/// If the save is the wrong size, its checksum doesn't match, or it holds
+/// something that isn't a Pokémon or a move.
+pub fn parse_save_gen_1(data: &[u8]) -> Result<SaveGen1, SaveErrorGen1> {
+    if data.len() != SAVE_SIZE_GEN_1 {
+        return Err(SaveErrorGen1::WrongSize(data.len()));
+    }
+    if checksum(&data[PLAYER_NAME..MAIN_CHECKSUM]) != data[MAIN_CHECKSUM] {
+        return Err(SaveErrorGen1::BadChecksum);
+    }
+
+    let current_box = (data[CURRENT_BOX] & 0x7F) as usize % BOX_COUNT_GEN_1;
+    let boxes = (0..BOX_COUNT_GEN_1)
+        .map(|i| {
+            if i == current_box {
+                return read_list(data, CURRENT_BOX_DATA, BOX_CAPACITY_GEN_1, BOX_MON_SIZE);
+            }
+            let bank = BOX_BANKS[i / BOXES_PER_BANK];
+            if checksum(&data[bank..bank + BOX_BANK_CHECKSUM]) != data[bank + BOX_BANK_CHECKSUM] {
+                return Ok(Vec::new());
+            }
+            read_list(data, bank + i % BOXES_PER_BANK * BOX_SIZE, BOX_CAPACITY_GEN_1, BOX_MON_SIZE)
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(SaveGen1 {
+        player_name: decode_text_gen_1(&data[PLAYER_NAME..PLAYER_NAME + NAME_LENGTH]),
+        rival_name: decode_text_gen_1(&data[RIVAL_NAME..RIVAL_NAME + NAME_LENGTH]),
+        player_id: u16::from_be_bytes([data[PLAYER_ID], data[PLAYER_ID + 1]]),
+        money: data[MONEY..MONEY + 3].iter().fold(0, |money, &b| money * 100 + (b >> 4) as u32 * 10 + (b & 0x0F) as u32),
+        progress: ProgressGen1 { badges: data[BADGES], ..Default::default() },
+        party: read_list(data, PARTY, 6, PARTY_MON_SIZE)?,
+        boxes,
+        current_box,
+    })
+}
+
+/// Writes a save's player, party and boxes over the save it was read from,
+/// with fresh checksums, ready for an emulator to load. Anything else in
+/// the original save, such as items and the map, is left as it was.
+///
+/// Party Pokémon keep the stats in `pokemon.stats`; the HP DV is always
+/// worked out from the other four.
+///
+/// # Example
+/// ```no_run
+/// use engine::{find_species_gen_1, parse_save_gen_1, write_save_gen_1};
+///
+/// let original = std::fs::read("red.sav").unwrap();
+/// let mut save = parse_save_gen_1(&original).unwrap();
+/// // Practise Misty with a level 40 lead
+/// let lead = &mut save.party[0];
+/// lead.pokemon = lead.species.pokemon(40, lead.pokemon.ivs.clone());
+/// lead.hp = lead.pokemon.stats.hp;
+/// std::fs::write("red.sav", write_save_gen_1(&save, &original).unwrap()).unwrap();
+/// ```
+///
+/// # Errors
+/// If the original save is the wrong size, the party, a box or the list of
+/// boxes is too long, or a name or move can't be stored.
+pub fn write_save_gen_1(save: &SaveGen1, original: &[u8]) -> Result<Vec<u8>, SaveErrorGen1> {
+    if original.len() != SAVE_SIZE_GEN_1 {
+        return Err(SaveErrorGen1::WrongSize(original.len()));
+    }
+    if save.boxes.len() > BOX_COUNT_GEN_1 {
+        return Err(SaveErrorGen1::BadCount(save.boxes.len() as u8));
+    }
+    let mut data = original.to_vec();
+    data[PLAYER_NAME..PLAYER_NAME + NAME_LENGTH].copy_from_slice(&encode_text_gen_1(&save.player_name)?);
+    data[RIVAL_NAME..RIVAL_NAME + NAME_LENGTH].copy_from_slice(&encode_text_gen_1(&save.rival_name)?);
+    data[PLAYER_ID..PLAYER_ID + 2].copy_from_slice(&save.player_id.to_be_bytes());
+    let money = save.money.min(999_999);
+    for (i, digits) in [money / 10_000, money / 100 % 100, money % 100].into_iter().enumerate() {
+        data[MONEY + i] = (((digits / 10) << 4) | (digits % 10)) as u8;
+    }
+    data[BADGES] = save.progress.badges;
+    data[CURRENT_BOX] = (data[CURRENT_BOX] & 0x80) | save.current_box as u8;
+    write_list(&mut data, PARTY, 6, PARTY_MON_SIZE, &save.party)?;
+
+    let empty = Vec::new();
+    for i in 0..BOX_COUNT_GEN_1 {
+        let pokemon = save.boxes.get(i).unwrap_or(&empty);
+        let bank = BOX_BANKS[i / BOXES_PER_BANK];
+        write_list(&mut data, bank + i % BOXES_PER_BANK * BOX_SIZE, BOX_CAPACITY_GEN_1, BOX_MON_SIZE, pokemon)?;
+        if i == save.current_box {
+            write_list(&mut data, CURRENT_BOX_DATA, BOX_CAPACITY_GEN_1, BOX_MON_SIZE, pokemon)?;
+        }
+    }
+    for bank in BOX_BANKS {
+        // The bank's checksum, then one for each box in it
+        data[bank + BOX_BANK_CHECKSUM] = checksum(&data[bank..bank + BOX_BANK_CHECKSUM]);
+        for i in 0..BOXES_PER_BANK {
+            let start = bank + i * BOX_SIZE;
+            data[bank + BOX_BANK_CHECKSUM + 1 + i] = checksum(&data[start..start + BOX_SIZE]);
+        }
+    }
+    data[MAIN_CHECKSUM] = checksum(&data[PLAYER_NAME..MAIN_CHECKSUM]);
+    Ok(data)
+}
+
+/// The species with a given index in the games' internal order, which
+/// isn't Pokédex order (Rhydon is 1). `None` for MissingNo. and indices past
+/// the end.
+///
+/// # Example
+/// ```
+/// use engine::species_from_index_gen_1;
+///
+/// assert_eq!(species_from_index_gen_1(0x54).unwrap().name, "Pikachu");
+/// assert!(species_from_index_gen_1(0x1F).is_none());
+/// ```
+pub fn species_from_index_gen_1(index: u8) -> Option<&'static SpeciesGen1> {
+    let dex = *SPECIES_DEX_GEN_1.get((index as usize).checked_sub(1)?)?;
+    (dex != 0).then(|| &SPECIES_GEN_1[dex as usize - 1])
+}
+
+/// A species' index in the games' internal order.
+pub fn species_index_gen_1(species: &SpeciesGen1) -> u8 {
+    SPECIES_DEX_GEN_1.iter().position(|&dex| dex == species.dex).map_or(0, |i| i as u8 + 1)
+}
+
+/// Decodes text in the games' character encoding, up to the first
+/// terminator. Characters with no Unicode equivalent become `�`.
+pub fn decode_text_gen_1(bytes: &[u8]) -> String {
+    bytes.iter()
+        .take_while(|&&b| b != TEXT_END)
+        .map(|&b| match b {
+            0x7F => " ".into(),
+            0x80..=0x99 => ((b - 0x80 + b'A') as char).to_string(),
+            0x9A => "(".into(),
+            0x9B => ")".into(),
+            0x9C => ":".into(),
+            0x9D => ";".into(),
+            0x9E => "[".into(),
+            0x9F => "]".into(),
+            0xA0..=0xB9 => ((b - 0xA0 + b'a') as char).to_string(),
+            0xBA => "é".into(),
+            0xE0 => "'".into(),
+            0xE1 => "PK".into(),
+            0xE2 => "MN".into(),
+            0xE3 => "-".into(),
+            0xE6 => "?".into(),
+            0xE7 => "!".into(),
+            0xE8 => ".".into(),
+            0xEF => "♂".into(),
+            0xF1 => "×".into(),
+            0xF3 => "/".into(),
+            0xF4 => ",".into(),
+            0xF5 => "♀".into(),
+            0xF6..=0xFF => ((b - 0xF6 + b'0') as char).to_string(),
+            _ => char::REPLACEMENT_CHARACTER.to_string(),
+        })
+        .collect()
+}
+
+/// Encodes text in the games' character encoding as an 11-byte name,
+/// padded with terminators.
+///
+/// # Errors
+/// If the text is longer than 10 characters or has a character the games
+/// can't show.
+pub fn encode_text_gen_1(text: &str) -> Result<[u8; NAME_LENGTH], SaveErrorGen1> {
+    let error = || SaveErrorGen1::CantEncode(text.into());
+    let mut bytes = [TEXT_END; NAME_LENGTH];
+    for (len, c) in text.chars().enumerate() {
+        let byte = match c {
+            ' ' => 0x7F,
+            'A'..='Z' => 0x80 + (c as u8 - b'A'),
+            '(' => 0x9A,
+            ')' => 0x9B,
+            ':' => 0x9C,
+            ';' => 0x9D,
+            '[' => 0x9E,
+            ']' => 0x9F,
+            'a'..='z' => 0xA0 + (c as u8 - b'a'),
+            'é' => 0xBA,
+            '\'' => 0xE0,
+            '-' => 0xE3,
+            '?' => 0xE6,
+            '!' => 0xE7,
+            '.' => 0xE8,
+            '♂' => 0xEF,
+            '×' => 0xF1,
+            '/' => 0xF3,
+            ',' => 0xF4,
+            '♀' => 0xF5,
+            '0'..='9' => 0xF6 + (c as u8 - b'0'),
+            _ => return Err(error()),
+        };
+        if len == NAME_LENGTH - 1 {
+            return Err(error());
+        }
+        bytes[len] = byte;
+    }
+    Ok(bytes)
+}
+
+/// The games' checksum: the complement of the sum of the bytes.
+fn checksum(bytes: &[u8]) -> u8 {
+    !bytes.iter().fold(0u8, |sum, &b| sum.wrapping_add(b))
+}
+
+/// Reads a party or box: a count, a species list, the Pokémon, then their
+/// original trainers' names and their nicknames.
+fn read_list(data: &[u8], offset: usize, capacity: usize, mon_size: usize) -> Result<Vec<SavedPokemonGen1>, SaveErrorGen1> {
+    let count = data[offset];
+    if count as usize > capacity {
+        return Err(SaveErrorGen1::BadCount(count));
+    }
+    let mons = offset + capacity + 2;
+    let ot_names = mons + capacity * mon_size;
+    let nicknames = ot_names + capacity * NAME_LENGTH;
+    (0..count as usize)
+        .map(|i| {
+            let name = |start: usize| decode_text_gen_1(&data[start + i * NAME_LENGTH..start + (i + 1) * NAME_LENGTH]);
+            read_pokemon(&data[mons + i * mon_size..mons + (i + 1) * mon_size], name(ot_names), name(nicknames))
+        })
+        .collect()
+}
+
+/// Writes a party or box in the layout [`read_list`] reads.
+fn write_list(
+    data: &mut [u8],
+    offset: usize,
+    capacity: usize,
+    mon_size: usize,
+    pokemon: &[SavedPokemonGen1],
+) -> Result<(), SaveErrorGen1> {
+    if pokemon.len() > capacity {
+        return Err(SaveErrorGen1::BadCount(pokemon.len() as u8));
+    }
+    let mons = offset + capacity + 2;
+    let ot_names = mons + capacity * mon_size;
+    let nicknames = ot_names + capacity * NAME_LENGTH;
+    data[offset] = pokemon.len() as u8;
+    for (i, saved) in pokemon.iter().enumerate() {
+        data[offset + 1 + i] = species_index_gen_1(saved.species);
+        data[mons + i * mon_size..mons + (i + 1) * mon_size].copy_from_slice(&write_pokemon(saved, mon_size)?);
+        data[ot_names + i * NAME_LENGTH..ot_names + (i + 1) * NAME_LENGTH].copy_from_slice(&encode_text_gen_1(&saved.ot_name)?);
+        data[nicknames + i * NAME_LENGTH..nicknames + (i + 1) * NAME_LENGTH].copy_from_slice(&encode_text_gen_1(&saved.nickname)?);
+    }
+    data[offset + 1 + pokemon.len()] = 0xFF;
+    Ok(())
+}
+
+/// Writes one Pokémon's data in the layout [`read_pokemon`] reads.
+fn write_pokemon(saved: &SavedPokemonGen1, mon_size: usize) -> Result<Vec<u8>, SaveErrorGen1> {
+    let pokemon = &saved.pokemon;
+    let mut mon = vec![0; mon_size];
+    let mut word = |i: usize, value: u16| mon[i..i + 2].copy_from_slice(&value.to_be_bytes());
+    word(0x01, saved.hp);
+    word(0x0C, saved.ot_id);
+    word(0x11, pokemon.evs.hp);
+    word(0x13, pokemon.evs.attack);
+    word(0x15, pokemon.evs.defense);
+    word(0x17, pokemon.evs.speed);
+    word(0x19, pokemon.evs.special);
+    if mon_size == PARTY_MON_SIZE {
+        let stats = &pokemon.stats;
+        for (i, stat) in [stats.hp, stats.attack, stats.defense, stats.speed, stats.special].into_iter().enumerate() {
+            word(0x22 + 2 * i, stat);
+        }
+        mon[0x21] = stats.lvl;
+    }
+
+    mon[0x00] = species_index_gen_1(saved.species);
+    mon[0x03] = pokemon.stats.lvl;
+    mon[0x04] = match pokemon.status {
+        StatusGen1::Healthy => 0,
+        StatusGen1::Asleep(turns) => turns.clamp(1, 7),
+        StatusGen1::Poisoned => 0x08,
+        StatusGen1::Burned => 0x10,
+        StatusGen1::Frozen => 0x20,
+        StatusGen1::Paralyzed => 0x40,
+    };
+    let [first, second] = saved.species.types.map(type_index);
+    mon[0x05] = first;
+    mon[0x06] = if saved.species.types[1] == TypeGen1::None { first } else { second };
+    mon[0x07] = saved.catch_rate;
+    for (slot, m) in pokemon.moves.iter().take(4).enumerate() {
+        let index = MOVES_GEN_1.iter().position(|data| data.name == m.name).ok_or(SaveErrorGen1::CantEncode(m.name.clone()))?;
+        mon[0x08 + slot] = index as u8 + 1;
+        let pp = saved.pp.get(slot).copied().unwrap_or(0);
+        let pp_ups = saved.pp_ups.get(slot).copied().unwrap_or(0);
+        mon[0x1D + slot] = (pp_ups.min(3) << 6) | pp.min(0x3F);
+    }
+    mon[0x0E..0x11].copy_from_slice(&saved.exp.min(0xFF_FFFF).to_be_bytes()[1..]);
+    let ivs = &pokemon.ivs;
+    mon[0x1B] = ((ivs.attack & 0x0F) << 4) | (ivs.defense & 0x0F);
+    mon[0x1C] = ((ivs.speed & 0x0F) << 4) | (ivs.special & 0x0F);
+    Ok(mon)
+}
+
+/// Decodes a status byte: a sleep counter in the low three bits, then one
+/// bit each for poison, burn, freeze and paralysis.
+pub(crate) fn status_from_byte(byte: u8) -> StatusGen1 {
+    match byte {
+        s if s & 0x07 != 0 => StatusGen1::Asleep(s & 0x07),
+        s if s & 0x08 != 0 => StatusGen1::Poisoned,
+        s if s & 0x10 != 0 => StatusGen1::Burned,
+        s if s & 0x20 != 0 => StatusGen1::Frozen,
+        s if s & 0x40 != 0 => StatusGen1::Paralyzed,
+        _ => StatusGen1::Healthy,
+    }
+}
+
+/// A type's index in the games' internal order.
+fn type_index(typ: TypeGen1) -> u8 {
+    match typ {
+        TypeGen1::Normal | TypeGen1::None => 0x00,
+        TypeGen1::Fighting => 0x01,
+        TypeGen1::Flying => 0x02,
+        TypeGen1::Poison => 0x03,
+        TypeGen1::Ground => 0x04,
+        TypeGen1::Rock => 0x05,
+        TypeGen1::Bug => 0x07,
+        TypeGen1::Ghost => 0x08,
+        TypeGen1::Fire => 0x14,
+        TypeGen1::Water => 0x15,
+        TypeGen1::Grass => 0x16,
+        TypeGen1::Electric => 0x17,
+        TypeGen1::Psychic => 0x18,
+        TypeGen1::Ice => 0x19,
+        TypeGen1::Dragon => 0x1A,
+    }
+}
+
+/// Reads one Pokémon's data: the 33 bytes every Pokémon has, plus, in the
+/// party, its level and stats.
+fn read_pokemon(mon: &[u8], ot_name: String, nickname: String) -> Result<SavedPokemonGen1, SaveErrorGen1> {
+    let word = |i: usize| u16::from_be_bytes([mon[i], mon[i + 1]]);
+    let species = species_from_index_gen_1(mon[0x00]).ok_or(SaveErrorGen1::UnknownSpecies(mon[0x00]))?;
+
+    let (attack, defense, speed, special) = (mon[0x1B] >> 4, mon[0x1B] & 0x0F, mon[0x1C] >> 4, mon[0x1C] & 0x0F);
+    let ivs = IVsGen1 { hp: hp_dv_gen_1(attack, defense, special, speed), attack, defense, special, speed };
+    let level = if mon.len() == PARTY_MON_SIZE { mon[0x21] } else { mon[0x03] };
+    let mut pokemon = species.pokemon(level, ivs);
+    pokemon.evs = EVsGen1 { hp: word(0x11), attack: word(0x13), defense: word(0x15), speed: word(0x17), special: word(0x19) };
+    species.calculate_stats(&mut pokemon);
+    if mon.len() == PARTY_MON_SIZE {
+        pokemon.stats = StatsGen1 { lvl: level, hp: word(0x22), attack: word(0x24), defense: word(0x26), speed: word(0x28), special: word(0x2A) };
+    }
+    pokemon.status = status_from_byte(mon[0x04]);
+
+    let mut pp = Vec::new();
+    let mut pp_ups = Vec::new();
+    pokemon.moves.clear();
+    for slot in 0..4 {
+        let index = mon[0x08 + slot];
+        if index == 0 {
+            continue;
+        }
+        let data = MOVES_GEN_1.get(index as usize - 1).ok_or(SaveErrorGen1::UnknownMove(index))?;
+        pokemon.moves.push(data.to_move());
+        pp.push(mon[0x1D + slot] & 0x3F);
+        pp_ups.push(mon[0x1D + slot] >> 6);
+    }
+
+    Ok(SavedPokemonGen1 {
+        species,
+        pokemon,
+        nickname,
+        ot_name,
+        ot_id: word(0x0C),
+        exp: u32::from_be_bytes([0, mon[0x0E], mon[0x0F], mon[0x10]]),
+        hp: word(0x01),
+        pp,
+        pp_ups,
+        catch_rate: mon[0x07],
+    })
+}
+
+/// The Pokédex number of each species index, starting from index 1; 0 for
+/// MissingNo.
+static SPECIES_DEX_GEN_1: [u8; 190] = [
+    112, 115, 32, 35, 21, 100, 34, 80, 2, 103,
+    108, 102, 88, 94, 29, 31, 104, 111, 131, 59,
+    151, 130, 90, 72, 92, 123, 120, 9, 127, 114,
+    0, 0, 58, 95, 22, 16, 79, 64, 75, 113,
+    67, 122, 106, 107, 24, 47, 54, 96, 76, 0,
+    126, 0, 125, 82, 109, 0, 56, 86, 50, 128,
+    0, 0, 0, 83, 48, 149, 0, 0, 0, 84,
+    60, 124, 146, 144, 145, 132, 52, 98, 0, 0,
+    0, 37, 38, 25, 26, 0, 0, 147, 148, 140,
+    141, 116, 117, 0, 0, 27, 28, 138, 139, 39,
+    40, 133, 136, 135, 134, 66, 41, 23, 46, 61,
+    62, 13, 14, 15, 0, 85, 57, 51, 49, 87,
+    0, 0, 10, 11, 12, 68, 0, 55, 97, 42,
+    150, 143, 129, 0, 0, 89, 0, 99, 91, 0,
+    101, 36, 110, 53, 105, 0, 93, 63, 65, 17,
+    18, 121, 1, 3, 73, 0, 118, 119, 0, 0,
+    0, 0, 77, 78, 19, 20, 33, 30, 74, 137,
+    142, 0, 81, 0, 0, 4, 7, 5, 8, 6,
+    0, 0, 0, 0, 43, 44, 45, 69, 70, 71,
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds a save holding one party Pokémon: RED's level 25 Pikachu
+    /// named SPARKY with Thunderbolt, paralysed, at 40 HP.
+    fn save() -> Vec<u8> {
+        let red = [0x80 + 17, 0x80 + 4, 0x80 + 3, TEXT_END];
+        let mut data = vec![0; SAVE_SIZE_GEN_1];
+        data[PLAYER_NAME..PLAYER_NAME + 4].copy_from_slice(&red);
+        data[RIVAL_NAME..RIVAL_NAME + 4].copy_from_slice(&[0x81, 0x8B, 0x94, TEXT_END]);
+        data[MONEY..MONEY + 3].copy_from_slice(&[0x01, 0x23, 0x45]);
+        data[BADGES] = 0b11;
+        data[PARTY] = 1;
+        data[PARTY + 1] = 0x54;
+        data[PARTY + 2] = 0xFF;
+
+        let mon = PARTY + 8;
+        data[mon] = 0x54;
+        data[mon + 0x02] = 40;
+        data[mon + 0x04] = 0x40;
+        data[mon + 0x08] = 85;
+        data[mon + 0x0C..mon + 0x0E].copy_from_slice(&[0x30, 0x39]);
+        data[mon + 0x10] = 0x64;
+        data[mon + 0x18] = 200;
+        data[mon + 0x1B] = 0xF3;
+        data[mon + 0x1C] = 0xA8;
+        data[mon + 0x1D] = 0x40 | 14;
+        data[mon + 0x21] = 25;
+        data[mon + 0x23] = 55;
+        data[mon + 0x29] = 60;
+
+        let ot_name = PARTY + 8 + 6 * PARTY_MON_SIZE;
+        data[ot_name..ot_name + 4].copy_from_slice(&red);
+        let nickname = ot_name + 6 * NAME_LENGTH;
+        data[nickname..nickname + 7].copy_from_slice(&[0x92, 0x8F, 0x80, 0x91, 0x8A, 0x98, TEXT_END]);
+        data[MAIN_CHECKSUM] = checksum(&data[PLAYER_NAME..MAIN_CHECKSUM]);
+        data
+    }
+
+    /// The party, player and progress all come through.
+    #[test]
+    fn test_parse_party() {
+        let save = parse_save_gen_1(&save()).unwrap();
+        assert_eq!((save.player_name.as_str(), save.rival_name.as_str()), ("RED", "BLU"));
+        assert_eq!(save.money, 12_345);
+        assert_eq!(save.progress.badges, 0b11);
+        assert_eq!(save.boxes.len(), BOX_COUNT_GEN_1);
+        assert!(save.boxes.iter().all(Vec::is_empty));
+
+        let [sparky] = save.party.as_slice() else { panic!("expected one Pokémon") };
+        assert_eq!((sparky.species.name, sparky.nickname.as_str()), ("Pikachu", "SPARKY"));
+        assert_eq!((sparky.hp, sparky.exp, sparky.ot_id), (40, 100, 12_345));
+        assert_eq!((sparky.pp.as_slice(), sparky.pp_ups.as_slice()), ([14].as_slice(), [1].as_slice()));
+        let pokemon = &sparky.pokemon;
+        assert_eq!(pokemon.moves.iter().map(|m| m.name.as_str()).collect::<Vec<_>>(), ["Thunderbolt"]);
+        assert_eq!((pokemon.stats.lvl, pokemon.stats.hp, pokemon.stats.speed), (25, 55, 60));
+        assert_eq!((pokemon.ivs.attack, pokemon.ivs.defense, pokemon.ivs.speed, pokemon.ivs.special), (15, 3, 10, 8));
+        assert_eq!(pokemon.ivs.hp, 0b1100);
+        assert_eq!(pokemon.evs.speed, 200);
+        assert_eq!(pokemon.status, StatusGen1::Paralyzed);
+    }
+
+    /// Corrupt or truncated saves are refused, and species indices map
+    /// both ways.
+    #[test]
+    fn test_errors_and_indices() {
+        let mut data = save();
+        data[MONEY] = 0x99;
+        assert_eq!(parse_save_gen_1(&data).unwrap_err(), SaveErrorGen1::BadChecksum);
+        assert_eq!(parse_save_gen_1(&data[..0x2000]).unwrap_err(), SaveErrorGen1::WrongSize(0x2000));
+
+        for species in &SPECIES_GEN_1 {
+            assert_eq!(species_from_index_gen_1(species_index_gen_1(species)), Some(species));
+        }
+        assert_eq!(species_index_gen_1(&SPECIES_GEN_1[0]), 0x99);
+    }
+
+    /// Parse, modify, write and parse again gives back the modified save,
+    /// boxes included.
+    #[test]
+    fn test_round_trip() {
+        let original = save();
+        let mut save = parse_save_gen_1(&original).unwrap();
+        assert_eq!(format!("{:?}", parse_save_gen_1(&write_save_gen_1(&save, &original).unwrap()).unwrap()), format!("{save:?}"));
+
+        let mut lapras = save.party[0].clone();
+        lapras.species = crate::find_species_gen_1("Lapras").unwrap();
+        lapras.pokemon = lapras.species.pokemon(30, lapras.pokemon.ivs.clone());
+        lapras.pokemon.status = StatusGen1::Asleep(3);
+        lapras.nickname = "NESSIE".into();
+        lapras.pp = vec![35; lapras.pokemon.moves.len()];
+        lapras.pp_ups = vec![0; lapras.pokemon.moves.len()];
+        save.party[0].pokemon.stats.lvl = 50;
+        save.party[0].exp = 125_000;
+        save.boxes[3].push(lapras.clone());
+        save.boxes[8].push(lapras.clone());
+        save.player_name = "Ash".into();
+        save.money = 999_999;
+
+        let written = write_save_gen_1(&save, &original).unwrap();
+        let reread = parse_save_gen_1(&written).unwrap();
+        // Boxed Pokémon get their stats recalculated, and the HP DV follows
+        // from the rest
+        save.boxes[3][0].pokemon.ivs.hp = 0b1100;
+        save.boxes[8][0].pokemon.ivs.hp = 0b1100;
+        lapras.species.calculate_stats(&mut save.boxes[3][0].pokemon);
+        lapras.species.calculate_stats(&mut save.boxes[8][0].pokemon);
+        assert_eq!(format!("{reread:?}"), format!("{save:?}"));
+        assert_eq!(reread.boxes[8][0].nickname, "NESSIE");
+
+        save.party[0].nickname = "SPARKYSPARKY".into();
+        assert_eq!(write_save_gen_1(&save, &original).unwrap_err(), SaveErrorGen1::CantEncode("SPARKYSPARKY".into()));
+    }
+
+    /// A parsed save survives a trip through JSON unchanged, with the
+    /// species stored by name.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let save = parse_save_gen_1(&save()).unwrap();
+        let json = serde_json::to_string(&save).unwrap();
+        assert!(json.contains(r#""species":"Pikachu""#));
+        let read: SaveGen1 = serde_json::from_str(&json).unwrap();
+        assert_eq!(format!("{read:?}"), format!("{save:?}"));
+    }
+
+    /// Party stats over 255 are written and read back as full words.
+    #[test]
+    fn test_round_trip_big_stats() {
+        let original = save();
+        let mut save = parse_save_gen_1(&original).unwrap();
+        let chansey = &mut save.party[0];
+        chansey.species = crate::find_species_gen_1("Chansey").unwrap();
+        chansey.pokemon = chansey.species.pokemon(100, chansey.pokemon.ivs.clone());
+        chansey.hp = chansey.pokemon.stats.hp;
+        assert!(chansey.hp > 255);
+
+        let reread = parse_save_gen_1(&write_save_gen_1(&save, &original).unwrap()).unwrap();
+        assert_eq!(reread.party[0].pokemon.stats.hp, save.party[0].pokemon.stats.hp);
+        assert_eq!(reread.party[0].hp, save.party[0].hp);
+    }
+}
@@ -0,0 +1,372 @@
+use crate::battle::{
+    ActionGen1, BattleStateGen1, OpponentPolicyGen1, Position, PositionMap, SideIdGen1, TurnResolver,
+};
+
+/// What the endgame search should optimise for.
+///
+/// # Variants
+/// - `MaximiseWin`: Highest chance of winning, ties broken by fewest deaths
+/// - `MinimiseDeaths`: Lowest chance of losing a Pokémon, ties broken by win chance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SearchObjectiveGen1 {
+    #[default]
+    MaximiseWin,
+    MinimiseDeaths,
+}
+
+/// Settings for [`solve_endgame_gen_1`].
+///
+/// # Fields
+/// - `objective`: What the player is trying to achieve
+/// - `max_turns`: Turns to look ahead before a line counts as unresolved
+#[derive(Debug, Clone, Copy)]
+pub struct SearchOptionsGen1 {
+    pub objective: SearchObjectiveGen1,
+    pub max_turns: u8,
+}
+
+/// Exact probabilities of how a battle ends under a given line of play.
+///
+/// `win`, `loss` and `unresolved` always sum to 1. `death` is the chance
+/// that at least one of the player's Pokémon faints, whatever the result.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct OutcomeGen1 {
+    pub win: f64,
+    pub loss: f64,
+    pub unresolved: f64,
+    pub death: f64,
+}
+
+/// Result of an endgame search.
+///
+/// # Fields
+/// - `best_action`: The optimal action now (`None` if the battle is already over)
+/// - `outcome`: Probabilities when playing optimally from here
+/// - `actions`: Every legal action with the outcome of choosing it now
+/// - `line`: The player's optimal actions along the most likely course of the battle
+/// - `states_evaluated`: Distinct positions stored in the transposition table
+#[derive(Debug, Clone)]
+pub struct SearchResultGen1 {
+    pub best_action: Option<ActionGen1>,
+    pub outcome: OutcomeGen1,
+    pub actions: Vec<(ActionGen1, OutcomeGen1)>,
+    pub line: Vec<ActionGen1>,
+    pub states_evaluated: usize,
+}
+
+impl Default for SearchOptionsGen1 {
+    /// Maximises win chance, looking 10 turns ahead.
+    fn default() -> Self {
+        Self {
+            objective: SearchObjectiveGen1::MaximiseWin,
+            max_turns: 10,
+        }
+    }
+}
+
+impl OutcomeGen1 {
+    /// Adds `other` scaled by `chance` to this outcome.
+    fn add_scaled(&mut self, other: &OutcomeGen1, chance: f64) {
+        self.win += other.win * chance;
+        self.loss += other.loss * chance;
+        self.unresolved += other.unresolved * chance;
+        self.death += other.death * chance;
+    }
+
+    /// Whether this outcome is strictly better than `other` for `objective`.
+    fn is_better_than(&self, other: &OutcomeGen1, objective: SearchObjectiveGen1) -> bool {
+        const EPSILON: f64 = 1e-12;
+        let (primary, secondary) = match objective {
+            SearchObjectiveGen1::MaximiseWin => (self.win - other.win, other.death - self.death),
+            SearchObjectiveGen1::MinimiseDeaths => (other.death - self.death, self.win - other.win),
+        };
+        primary > EPSILON || (primary.abs() <= EPSILON && secondary > EPSILON)
+    }
+}
+
+/// Finds the optimal play in a small endgame by exact expectiminimax search.
+///
+/// Every chance event handled by [`resolve_turn_gen_1`](crate::resolve_turn_gen_1) becomes a chance
+/// node, the opponent's choices are weighted by `policy`, and the player
+/// picks whichever action is best for `options.objective`. Positions are
+/// memoised in a transposition table, so 1v1 and 2v2 endgames solve quickly;
+/// larger battles work but grow exponentially.
+///
+/// Lines still undecided after `options.max_turns` turns count towards
+/// `unresolved` rather than being guessed at.
+///
+/// # Examples
+/// ```
+/// use engine::{BattleStateGen1, BattlerGen1, MoveGen1, PokemonGen1, StatsGen1};
+/// use engine::{SearchOptionsGen1, UniformMovePolicyGen1, solve_endgame_gen_1};
+///
+/// let stats = StatsGen1 { lvl: 30, hp: 80, attack: 60, defense: 50, special: 50, speed: 60 };
+/// let rattata = PokemonGen1 { stats, ..Default::default() };
+/// let tackle = MoveGen1 { name: "Tackle".into(), power: 35, accuracy: 95, ..Default::default() };
+///
+/// let state = BattleStateGen1::new(
+///     vec![BattlerGen1::new(rattata.clone(), vec![tackle.clone()], 72)],
+///     vec![BattlerGen1::new(rattata, vec![tackle], 72)],
+/// );
+/// let options = SearchOptionsGen1 { max_turns: 3, ..Default::default() };
+/// let result = solve_endgame_gen_1(&state, &UniformMovePolicyGen1, options);
+///
+/// let total = result.outcome.win + result.outcome.loss + result.outcome.unresolved;
+/// assert!((total - 1.0).abs() < 1e-9);
+/// ```
+pub fn solve_endgame_gen_1<P: OpponentPolicyGen1>(
+    state: &BattleStateGen1,
+    policy: &P,
+    options: SearchOptionsGen1,
+) -> SearchResultGen1 {
+    let mut search = Expectiminimax {
+        policy,
+        objective: options.objective,
+        resolver: TurnResolver::new(state),
+        table: PositionMap::default(),
+        complete: PositionMap::default(),
+    };
+    let root = Position::of(state);
+
+    let actions: Vec<(ActionGen1, OutcomeGen1)> = search.resolver.player_actions(&root)
+        .into_iter()
+        .map(|action| (action, search.action_value(&root, action, options.max_turns).0))
+        .collect();
+
+    let (best_action, outcome) = match search.best(&root, options.max_turns) {
+        Some((action, outcome)) => (Some(action), outcome),
+        None => (None, search.value(&root, options.max_turns).0),
+    };
+    let line = search.principal_line(&root, options.max_turns);
+
+    SearchResultGen1 {
+        best_action,
+        outcome,
+        actions,
+        line,
+        states_evaluated: search.table.len() + search.complete.len(),
+    }
+}
+
+/// Search state shared across the recursion.
+///
+/// `table` holds values for a position at a given number of remaining
+/// turns. `complete` holds positions where every line ends within the
+/// stored number of turns whatever the player chooses; their value can't
+/// change with a deeper search, so it's reused for any larger turn count.
+/// This keeps the same position from being solved again at every turn count.
+struct Expectiminimax<'a, P> {
+    policy: &'a P,
+    objective: SearchObjectiveGen1,
+    resolver: TurnResolver<'a>,
+    table: PositionMap<(Position, u8), OutcomeGen1>,
+    complete: PositionMap<Position, (u8, OutcomeGen1)>,
+}
+
+impl<P: OpponentPolicyGen1> Expectiminimax<'_, P> {
+    /// Value of a position when the player plays optimally, and whether it is complete.
+    fn value(&mut self, position: &Position, turns: u8) -> (OutcomeGen1, bool) {
+        let party_size = self.resolver.template().player.party.len();
+        let death = if position.fainted_count(SideIdGen1::Player, party_size) > 0 { 1.0 } else { 0.0 };
+        if position.is_defeated(SideIdGen1::Opponent) {
+            return (OutcomeGen1 { win: 1.0, death, ..Default::default() }, true);
+        }
+        if position.is_defeated(SideIdGen1::Player) {
+            return (OutcomeGen1 { loss: 1.0, death, ..Default::default() }, true);
+        }
+        if turns == 0 {
+            return (OutcomeGen1 { unresolved: 1.0, death, ..Default::default() }, false);
+        }
+
+        if let Some((complete_turns, outcome)) = self.complete.get(position)
+            && *complete_turns <= turns
+        {
+            return (*outcome, true);
+        }
+        if let Some(outcome) = self.table.get(&(*position, turns)) {
+            return (*outcome, false);
+        }
+
+        let (outcome, complete) = match self.evaluate(position, turns) {
+            Some((_, outcome, complete)) => (outcome, complete),
+            None => (OutcomeGen1 { unresolved: 1.0, death, ..Default::default() }, false),
+        };
+        if complete {
+            self.complete.insert(*position, (turns, outcome));
+        } else {
+            self.table.insert((*position, turns), outcome);
+        }
+        (outcome, complete)
+    }
+
+    /// The best action in a position and its value.
+    fn best(&mut self, position: &Position, turns: u8) -> Option<(ActionGen1, OutcomeGen1)> {
+        self.evaluate(position, turns).map(|(action, outcome, _)| (action, outcome))
+    }
+
+    /// Evaluates every action in a position, returning the best one, its
+    /// value, and whether the position is complete.
+    fn evaluate(&mut self, position: &Position, turns: u8) -> Option<(ActionGen1, OutcomeGen1, bool)> {
+        if position.is_defeated(SideIdGen1::Opponent) || position.is_defeated(SideIdGen1::Player) || turns == 0 {
+            return None;
+        }
+
+        let mut best: Option<(ActionGen1, OutcomeGen1)> = None;
+        let mut complete = true;
+        for action in self.resolver.player_actions(position) {
+            let (outcome, action_complete) = self.action_value(position, action, turns);
+            complete &= action_complete;
+            if best.is_none_or(|(_, b)| outcome.is_better_than(&b, self.objective)) {
+                best = Some((action, outcome));
+            }
+        }
+        best.map(|(action, outcome)| (action, outcome, complete))
+    }
+
+    /// Value of choosing `action` now and playing optimally afterwards.
+    fn action_value(&mut self, position: &Position, action: ActionGen1, turns: u8) -> (OutcomeGen1, bool) {
+        let mut outcome = OutcomeGen1::default();
+        let mut complete = true;
+        for (chance, next, spent) in self.successors(position, action) {
+            let (value, next_complete) = self.value(&next, turns - spent);
+            outcome.add_scaled(&value, chance);
+            complete &= next_complete;
+        }
+        (outcome, complete)
+    }
+
+    /// Every position reachable by choosing `action`, with its probability
+    /// and the number of turns it uses up.
+    ///
+    /// Replacing a fainted Pokémon is free; anything else is a full turn
+    /// against the opponent's policy.
+    fn successors(&mut self, position: &Position, action: ActionGen1) -> Vec<(f64, Position, u8)> {
+        if position.is_active_fainted(SideIdGen1::Player) {
+            return self.resolver.resolve(position, Some(action), None)
+                .into_iter()
+                .map(|(chance, next)| (chance, next, 0))
+                .collect();
+        }
+
+        let state = position.to_state(self.resolver.template());
+        let mut opponent_actions: Vec<(Option<ActionGen1>, f64)> = self.policy
+            .action_probabilities(&state)
+            .into_iter()
+            .map(|(a, chance)| (Some(a), chance))
+            .collect();
+        if opponent_actions.is_empty() {
+            opponent_actions.push((None, 1.0));
+        }
+
+        let mut successors = Vec::new();
+        for (opponent_action, opponent_chance) in opponent_actions {
+            for (chance, next) in self.resolver.resolve(position, Some(action), opponent_action) {
+                successors.push((opponent_chance * chance, next, 1));
+            }
+        }
+        successors
+    }
+
+    /// Follows the optimal action and the most likely reply until the battle ends.
+    fn principal_line(&mut self, position: &Position, mut turns: u8) -> Vec<ActionGen1> {
+        let mut line = Vec::new();
+        let mut position = *position;
+        while let Some((action, _)) = self.best(&position, turns) {
+            line.push(action);
+            let Some((_, next, spent)) = self.successors(&position, action)
+                .into_iter()
+                .max_by(|a, b| a.0.total_cmp(&b.0))
+            else {
+                break;
+            };
+            position = next;
+            turns -= spent;
+        }
+        line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::battle::{BattlerGen1, UniformMovePolicyGen1};
+    use crate::{MoveCategory, MoveGen1, PokemonGen1, StatsGen1, TypeGen1};
+
+    fn battler(types: [TypeGen1; 2], hp: u16, speed: u8, moves: Vec<MoveGen1>) -> BattlerGen1 {
+        let pokemon = PokemonGen1 {
+            types,
+            stats: StatsGen1 { lvl: 40, hp, attack: 90, defense: 70, special: 90, speed: speed.into() },
+            ..Default::default()
+        };
+        BattlerGen1::new(pokemon, moves, speed)
+    }
+
+    fn special(name: &str, typ: TypeGen1, power: u8) -> MoveGen1 {
+        MoveGen1 { name: name.into(), typ, power, category: MoveCategory::Special, ..Default::default() }
+    }
+
+    /// A faster Pokémon with a guaranteed OHKO only loses to a 1/256 miss.
+    #[test]
+    fn test_guaranteed_ohko() {
+        let thunderbolt = special("Thunderbolt", TypeGen1::Electric, 95);
+        let splash = MoveGen1 { name: "Splash".into(), power: 0, category: MoveCategory::Status, ..Default::default() };
+        let state = BattleStateGen1::new(
+            vec![battler([TypeGen1::Electric, TypeGen1::None], 100, 120, vec![thunderbolt])],
+            vec![battler([TypeGen1::Water, TypeGen1::None], 20, 80, vec![splash])],
+        );
+
+        let options = SearchOptionsGen1 { max_turns: 1, ..Default::default() };
+        let result = solve_endgame_gen_1(&state, &UniformMovePolicyGen1, options);
+
+        assert!((result.outcome.win - 255.0 / 256.0).abs() < 1e-9);
+        assert!((result.outcome.unresolved - 1.0 / 256.0).abs() < 1e-9);
+        assert_eq!(result.outcome.death, 0.0);
+        assert_eq!(result.line, vec![ActionGen1::Move(0)]);
+    }
+
+    /// The search prefers a super-effective move over a resisted one.
+    #[test]
+    fn test_prefers_super_effective_move() {
+        let ember = special("Ember", TypeGen1::Fire, 40);
+        let vine_whip = special("Vine Whip", TypeGen1::Grass, 35);
+        let water_gun = special("Water Gun", TypeGen1::Water, 40);
+        let state = BattleStateGen1::new(
+            vec![battler([TypeGen1::Grass, TypeGen1::None], 90, 60, vec![ember, vine_whip])],
+            vec![battler([TypeGen1::Water, TypeGen1::Ground], 90, 50, vec![water_gun])],
+        );
+
+        let options = SearchOptionsGen1 { max_turns: 4, ..Default::default() };
+        let result = solve_endgame_gen_1(&state, &UniformMovePolicyGen1, options);
+
+        assert_eq!(result.best_action, Some(ActionGen1::Move(1)));
+        let total = result.outcome.win + result.outcome.loss + result.outcome.unresolved;
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    /// In a 2v2, the search switches to the Pokémon that walls the opponent.
+    #[test]
+    fn test_switches_to_resist() {
+        let thunderbolt = special("Thunderbolt", TypeGen1::Electric, 95);
+        let earthquake = MoveGen1 {
+            name: "Earthquake".into(),
+            typ: TypeGen1::Ground,
+            power: 100,
+            category: MoveCategory::Physical,
+            ..Default::default()
+        };
+        let tackle = MoveGen1 { name: "Tackle".into(), power: 35, accuracy: 95, ..Default::default() };
+        let state = BattleStateGen1::new(
+            vec![
+                battler([TypeGen1::Water, TypeGen1::None], 60, 50, vec![tackle.clone()]),
+                battler([TypeGen1::Ground, TypeGen1::None], 120, 40, vec![earthquake]),
+            ],
+            vec![battler([TypeGen1::Electric, TypeGen1::None], 80, 110, vec![thunderbolt])],
+        );
+
+        let options = SearchOptionsGen1 { objective: SearchObjectiveGen1::MinimiseDeaths, max_turns: 6 };
+        let result = solve_endgame_gen_1(&state, &UniformMovePolicyGen1, options);
+
+        assert_eq!(result.best_action, Some(ActionGen1::Switch(1)));
+        assert!(result.outcome.death < 0.01);
+    }
+}
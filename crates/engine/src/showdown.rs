@@ -0,0 +1,292 @@
+use std::fmt;
+
+use crate::{PokemonGen1, find_move_gen_1, find_species_gen_1};
+use crate::pokemon::{EVsGen1, IVsGen1};
+use crate::dvs::hp_dv_gen_1;
+
+/// The EVs Showdown gives Gen 1 sets that don't list any: 252 in every
+/// stat, which is 63504 stat experience.
+const DEFAULT_EV: u16 = 252;
+
+/// Why a Showdown team couldn't be read.
+///
+/// # Fields
+/// - `line`: The line the problem is on, counting from 1
+/// - `field`: What was being read (e.g., "species", "Level", "EVs")
+/// - `message`: What's wrong with it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShowdownErrorGen1 {
+    pub line: usize,
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for ShowdownErrorGen1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}: {}", self.line, self.field, self.message)
+    }
+}
+
+impl std::error::Error for ShowdownErrorGen1 {}
+
+/// Reads a team in Showdown's text format, one set per paragraph:
+///
+/// ```text
+/// Sparky (Pikachu) @ Leftovers
+/// Level: 50
+/// EVs: 252 HP / 252 Atk / 252 Def / 252 SpA / 252 SpD / 252 Spe
+/// IVs: 28 Atk
+/// - Thunderbolt
+/// - Thunder Wave
+/// ```
+///
+/// As on Showdown's Gen 1 format, sets default to level 100 with 252 EVs
+/// and 30 IVs in every stat, and any stat left out of an `EVs:` or `IVs:`
+/// line keeps its default. EVs become stat experience (EV²) and IVs become
+/// DVs (half the IV). A `DVs:` line gives DVs directly. SpA and SpD are
+/// both the Special stat, so they must agree, and the HP DV always follows
+/// from the other four. Nicknames, genders, items and abilities don't
+/// exist in Gen 1 and are skipped.
+///
+/// # Errors
+/// The line and field of the first thing that can't be read, such as an
+/// unknown species or move, a level out of range, or a fifth move.
+///
+/// # Example
+/// ```
+/// use engine::parse_showdown_gen_1;
+///
+/// let team = parse_showdown_gen_1("Pikachu\nLevel: 50\n- Thunderbolt\n\nMr. Mime\n- Psychic").unwrap();
+/// assert_eq!(team[0].stats.lvl, 50);
+/// assert_eq!(team[0].moves[0].name, "Thunderbolt");
+/// assert_eq!(team[1].evs.special, 63_504);
+///
+/// let error = parse_showdown_gen_1("Pikachu\nLevel: 500").unwrap_err();
+/// assert_eq!(error.to_string(), "line 2: Level: '500' isn't a level from 1 to 100");
+/// ```
+pub fn parse_showdown_gen_1(text: &str) -> Result<Vec<PokemonGen1>, ShowdownErrorGen1> {
+    let mut team = Vec::new();
+    let mut set: Vec<(usize, &str)> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !set.is_empty() {
+                team.push(parse_set(&set)?);
+                set.clear();
+            }
+        } else {
+            set.push((i + 1, line));
+        }
+    }
+    if !set.is_empty() {
+        team.push(parse_set(&set)?);
+    }
+    Ok(team)
+}
+
+/// Writes one Pokémon as a Showdown set; see [`parse_showdown_gen_1`] for
+/// the format. Reading the set back gives the same Pokémon, apart from any
+/// stat experience that falls between two EVs.
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, showdown_gen_1};
+/// use engine::pokemon::IVsGen1;
+///
+/// let starmie = find_species_gen_1("Starmie").unwrap().pokemon(47, IVsGen1::default());
+/// let set = showdown_gen_1(&starmie);
+/// assert!(set.starts_with("Starmie\nLevel: 47\nEVs: 0 HP / 0 Atk / 0 Def / 0 SpA / 0 SpD / 0 Spe\n"));
+/// ```
+pub fn showdown_gen_1(pokemon: &PokemonGen1) -> String {
+    let mut out = format!("{}\n", showdown_name(&pokemon.name));
+    if pokemon.stats.lvl != 100 {
+        out += &format!("Level: {}\n", pokemon.stats.lvl);
+    }
+
+    let evs = &pokemon.evs;
+    let evs = [evs.hp, evs.attack, evs.defense, evs.special, evs.special, evs.speed]
+        .map(|stat_exp| ((stat_exp as f64).sqrt().ceil() as u16).min(255));
+    if evs.iter().any(|&ev| ev != DEFAULT_EV) {
+        out += &format!("EVs: {}\n", stat_line(evs));
+    }
+    let ivs = &pokemon.ivs;
+    let ivs = [hp_dv_gen_1(ivs.attack, ivs.defense, ivs.special, ivs.speed), ivs.attack, ivs.defense, ivs.special, ivs.special, ivs.speed]
+        .map(|dv| dv as u16 * 2);
+    if ivs.iter().any(|&iv| iv != 30) {
+        out += &format!("IVs: {}\n", stat_line(ivs));
+    }
+
+    for m in &pokemon.moves {
+        out += &format!("- {}\n", m.name);
+    }
+    out
+}
+
+/// Writes a team as Showdown sets, separated by blank lines.
+pub fn team_to_showdown_gen_1(team: &[PokemonGen1]) -> String {
+    team.iter().map(showdown_gen_1).collect::<Vec<_>>().join("\n")
+}
+
+const STAT_NAMES: [&str; 6] = ["HP", "Atk", "Def", "SpA", "SpD", "Spe"];
+
+/// `value Stat / value Stat / ...` for all six of Showdown's stats.
+fn stat_line(values: [u16; 6]) -> String {
+    values.iter()
+        .zip(STAT_NAMES)
+        .map(|(value, stat)| format!("{value} {stat}"))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Showdown's spelling of a species name.
+fn showdown_name(name: &str) -> String {
+    match name {
+        "Nidoran♀" => "Nidoran-F".into(),
+        "Nidoran♂" => "Nidoran-M".into(),
+        _ => name.into(),
+    }
+}
+
+/// Reads one set's lines, each with its line number.
+fn parse_set(lines: &[(usize, &str)]) -> Result<PokemonGen1, ShowdownErrorGen1> {
+    let error = |line: usize, field: &str, message: String| ShowdownErrorGen1 { line, field: field.into(), message };
+
+    let (first_line, first) = lines[0];
+    let name = first.split(" @ ").next().unwrap_or_default().trim();
+    let name = name.strip_suffix(" (M)").or_else(|| name.strip_suffix(" (F)")).unwrap_or(name);
+    // "Nickname (Species)", or just "Species"
+    let species_name = match (name.rfind(" ("), name.strip_suffix(')')) {
+        (Some(open), Some(_)) => &name[open + 2..name.len() - 1],
+        _ => name,
+    };
+    let species = find_species_gen_1(species_name)
+        .ok_or_else(|| error(first_line, "species", format!("unknown species '{species_name}'")))?;
+
+    let mut level = 100;
+    let mut evs = [DEFAULT_EV; 6];
+    let mut dvs = [15u8; 6];
+    let mut moves = Vec::new();
+    for &(line, text) in &lines[1..] {
+        if let Some(move_name) = text.strip_prefix('-') {
+            let move_name = move_name.trim();
+            let m = find_move_gen_1(move_name).ok_or_else(|| error(line, "move", format!("unknown move '{move_name}'")))?;
+            if moves.len() == 4 {
+                return Err(error(line, "move", format!("'{move_name}' would be a fifth move")));
+            }
+            moves.push(m);
+            continue;
+        }
+
+        let Some((field, value)) = text.split_once(':') else {
+            return Err(error(line, "line", format!("can't read '{text}'")));
+        };
+        let value = value.trim();
+        match field.trim() {
+            "Level" => {
+                level = value.parse().ok()
+                    .filter(|l| (1..=100).contains(l))
+                    .ok_or_else(|| error(line, "Level", format!("'{value}' isn't a level from 1 to 100")))?;
+            }
+            "EVs" => parse_stats(value, 255, &mut evs).map_err(|message| error(line, "EVs", message))?,
+            "IVs" => {
+                let mut ivs = dvs.map(|dv| dv as u16 * 2);
+                parse_stats(value, 31, &mut ivs).map_err(|message| error(line, "IVs", message))?;
+                dvs = ivs.map(|iv| (iv / 2) as u8);
+            }
+            "DVs" => {
+                let mut values = dvs.map(u16::from);
+                parse_stats(value, 15, &mut values).map_err(|message| error(line, "DVs", message))?;
+                dvs = values.map(|dv| dv as u8);
+            }
+            // Gen 1 has none of these
+            "Ability" | "Shiny" | "Happiness" | "Nature" | "Tera Type" | "Hidden Power" | "Gigantamax" | "Dynamax Level" => {}
+            other => return Err(error(line, other, "isn't part of a Showdown set".into())),
+        }
+    }
+    for (field, values) in [("EVs", evs), ("IVs", dvs.map(u16::from))] {
+        if values[3] != values[4] {
+            let line = lines.iter().find(|(_, text)| text.starts_with(field) || text.starts_with("DVs")).map_or(first_line, |l| l.0);
+            return Err(error(line, field, "SpA and SpD are both Special, so they must match".into()));
+        }
+    }
+
+    let [_, attack, defense, special, _, speed] = dvs;
+    let ivs = IVsGen1 { hp: hp_dv_gen_1(attack, defense, special, speed), attack, defense, special, speed };
+    let mut pokemon = species.pokemon(level, ivs);
+    let stat_exp = |ev: u16| (ev as u32 * ev as u32).min(u16::MAX as u32) as u16;
+    pokemon.evs = EVsGen1 {
+        hp: stat_exp(evs[0]),
+        attack: stat_exp(evs[1]),
+        defense: stat_exp(evs[2]),
+        special: stat_exp(evs[3]),
+        speed: stat_exp(evs[5]),
+    };
+    species.calculate_stats(&mut pokemon);
+    if !moves.is_empty() {
+        pokemon.moves = moves;
+    }
+    Ok(pokemon)
+}
+
+/// Reads `value Stat / value Stat / ...` into `values`, in Showdown's stat
+/// order, leaving stats that aren't listed alone.
+fn parse_stats(text: &str, max: u16, values: &mut [u16; 6]) -> Result<(), String> {
+    for part in text.split('/') {
+        let part = part.trim();
+        let (value, stat) = part.split_once(' ').ok_or(format!("can't read '{part}'"))?;
+        let index = STAT_NAMES.iter()
+            .position(|s| s.eq_ignore_ascii_case(stat.trim()))
+            .ok_or(format!("unknown stat '{}'", stat.trim()))?;
+        values[index] = value.parse().ok()
+            .filter(|&v| v <= max)
+            .ok_or(format!("'{value}' isn't a value from 0 to {max}"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A full set comes in as Showdown describes it, and goes back out the
+    /// same way.
+    #[test]
+    fn test_round_trip() {
+        let text = "Sparky (Pikachu) (M) @ Light Ball
+Ability: No Ability
+Level: 24
+EVs: 100 HP / 0 Atk / 252 Def / 40 SpA / 40 SpD / 255 Spe
+IVs: 30 HP / 20 Atk / 2 Def / 31 SpA / 31 SpD / 12 Spe
+- Thunder Shock
+- Growl
+
+Nidoran-F
+- Double Kick";
+        let team = parse_showdown_gen_1(text).unwrap();
+        let pikachu = &team[0];
+        assert_eq!(pikachu.name, "Pikachu");
+        assert_eq!((pikachu.evs.hp, pikachu.evs.special, pikachu.evs.speed), (10_000, 1_600, 65_025));
+        assert_eq!([pikachu.ivs.attack, pikachu.ivs.defense, pikachu.ivs.special, pikachu.ivs.speed], [10, 1, 15, 6]);
+        assert_eq!(pikachu.ivs.hp, 0b0101);
+        assert_eq!(pikachu.moves.len(), 2);
+        assert_eq!(team[1].name, "Nidoran♀");
+
+        let exported = team_to_showdown_gen_1(&team);
+        assert!(exported.contains("IVs: 10 HP / 20 Atk / 2 Def / 30 SpA / 30 SpD / 12 Spe\n"));
+        assert!(exported.contains("\nNidoran-F\n- Double Kick\n"));
+        let reimported = parse_showdown_gen_1(&exported).unwrap();
+        assert_eq!(format!("{reimported:?}"), format!("{team:?}"));
+    }
+
+    /// Errors point at the line and field that's wrong.
+    #[test]
+    fn test_errors() {
+        let error = |text| parse_showdown_gen_1(text).unwrap_err();
+        assert_eq!(error("Pikachu\n\nPikablu").to_string(), "line 3: species: unknown species 'Pikablu'");
+        assert_eq!(error("Pikachu\n- Surf\n- Fly\n- Cut\n- Strength\n- Flash").line, 6);
+        assert_eq!(error("Pikachu\nEVs: 300 Atk").to_string(), "line 2: EVs: '300' isn't a value from 0 to 255");
+        assert_eq!(error("Pikachu\nIVs: 30 SpA / 10 SpD").field, "IVs");
+        assert_eq!(error("Pikachu\nEVs: 4 Sp").message, "unknown stat 'Sp'");
+        assert_eq!(error("Pikachu\n- Thunderblot").message, "unknown move 'Thunderblot'");
+    }
+}
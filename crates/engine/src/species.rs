@@ -0,0 +1,382 @@
+use crate::{PokemonGen1, StatsGen1, TypeGen1};
+use crate::pokemon::IVsGen1;
+use crate::learnsets::default_moveset_gen_1;
+
+/// Base stats of a Generation 1 species.
+///
+/// Gen 1 has a single Special stat, used for both attacking and defending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BaseStatsGen1 {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+/// A Pokémon species from Red, Blue and Yellow.
+///
+/// # Fields
+/// - `dex`: National Pokédex number (1-151)
+/// - `name`: Species name (e.g., "Pikachu")
+/// - `types`: Primary and secondary types (`TypeGen1::None` if single-typed)
+/// - `base`: Base stats
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeciesGen1 {
+    pub dex: u8,
+    pub name: &'static str,
+    pub types: [TypeGen1; 2],
+    pub base: BaseStatsGen1,
+}
+
+impl SpeciesGen1 {
+    /// Builds a Pokémon of this species with stats for the given level and
+    /// DVs, no stat experience, and the moves it would know in the wild at
+    /// that level.
+    ///
+    /// # Example
+    /// ```
+    /// use engine::find_species_gen_1;
+    /// use engine::pokemon::IVsGen1;
+    ///
+    /// let pikachu = find_species_gen_1("pikachu").unwrap();
+    /// let ivs = IVsGen1 { hp: 15, attack: 15, defense: 15, special: 15, speed: 15 };
+    /// let pokemon = pikachu.pokemon(100, ivs);
+    /// assert_eq!(pokemon.stats.speed, 215);
+    /// ```
+    pub fn pokemon(&self, level: u8, ivs: IVsGen1) -> PokemonGen1 {
+        let mut pokemon = PokemonGen1 {
+            name: self.name.into(),
+            types: self.types,
+            stats: StatsGen1 { lvl: level, ..Default::default() },
+            ivs,
+            moves: default_moveset_gen_1(self, level),
+            ..Default::default()
+        };
+        self.calculate_stats(&mut pokemon);
+        pokemon
+    }
+
+    /// Recalculates a Pokémon's stats from this species' base stats and the
+    /// Pokémon's level, DVs and stat experience.
+    pub fn calculate_stats(&self, pokemon: &mut PokemonGen1) {
+        let base = &self.base;
+        pokemon.stats.hp = pokemon.calculate_stat(base.hp, pokemon.ivs.hp, pokemon.evs.hp, true);
+        pokemon.stats.attack = pokemon.calculate_stat(base.attack, pokemon.ivs.attack, pokemon.evs.attack, false);
+        pokemon.stats.defense = pokemon.calculate_stat(base.defense, pokemon.ivs.defense, pokemon.evs.defense, false);
+        pokemon.stats.special = pokemon.calculate_stat(base.special, pokemon.ivs.special, pokemon.evs.special, false);
+        pokemon.stats.speed = pokemon.calculate_stat(base.speed, pokemon.ivs.speed, pokemon.evs.speed, false);
+    }
+}
+
+/// Looks up a species by name, ignoring case, spaces and punctuation
+/// (so "mr mime", "Mr. Mime" and "MR.MIME" all match). The Nidoran can be
+/// written with their symbols or as "Nidoran F"/"Nidoran M".
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, TypeGen1};
+///
+/// let onix = find_species_gen_1("Onix").unwrap();
+/// assert_eq!(onix.types, [TypeGen1::Rock, TypeGen1::Ground]);
+/// assert_eq!(find_species_gen_1("nidoran m").unwrap().dex, 32);
+/// ```
+pub fn find_species_gen_1(name: &str) -> Option<&'static SpeciesGen1> {
+    let key = name_key(name);
+    SPECIES_GEN_1.iter().find(|s| name_key(s.name) == key)
+}
+
+/// Normalises a name for lookups: lowercase letters and digits only, with
+/// gender symbols spelled out.
+pub fn name_key(name: &str) -> String {
+    name.chars()
+        .flat_map(|c| match c {
+            '♀' => Some('f'),
+            '♂' => Some('m'),
+            c if c.is_ascii_alphanumeric() => Some(c.to_ascii_lowercase()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Up to three of `names` closest to a misspelt `name`, best first, for
+/// "did you mean" hints. Names are compared with [`name_key`], by edit
+/// distance, and anything starting with what was typed counts as close.
+/// Nothing is suggested for a name that's empty once punctuation is dropped.
+///
+/// # Example
+/// ```
+/// use engine::species::closest_names;
+///
+/// let names = ["Pikachu", "Raichu", "Pidgey"];
+/// assert_eq!(closest_names("pickachu", names), ["Pikachu"]);
+/// assert_eq!(closest_names("pi", names), ["Pikachu", "Pidgey"]);
+/// assert!(closest_names("Mewthree", names).is_empty());
+/// ```
+pub fn closest_names<'a>(name: &str, names: impl IntoIterator<Item = &'a str>) -> Vec<&'a str> {
+    let key = name_key(name);
+    if key.is_empty() {
+        return Vec::new();
+    }
+    let max_distance = (key.len() / 3).max(1);
+    let mut close: Vec<(usize, &str)> = names.into_iter()
+        .filter_map(|candidate| {
+            let candidate_key = name_key(candidate);
+            let distance = if candidate_key.starts_with(&key) { 0 } else { edit_distance(&key, &candidate_key) };
+            (distance <= max_distance).then_some((distance, candidate))
+        })
+        .collect();
+    close.sort_by_key(|&(distance, _)| distance);
+    close.into_iter().take(3).map(|(_, candidate)| candidate).collect()
+}
+
+/// Species names closest to a misspelt one; see [`closest_names`].
+pub fn suggest_species_gen_1(name: &str) -> Vec<&'static str> {
+    closest_names(name, SPECIES_GEN_1.iter().map(|s| s.name))
+}
+
+/// Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitute = previous[j] + usize::from(ca != cb);
+            current.push(substitute.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// ================= Serde =================
+
+/// Species are written as their name, since they all live in
+/// [`SPECIES_GEN_1`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for SpeciesGen1 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name)
+    }
+}
+
+/// Species are read from their name, spelled any way
+/// [`find_species_gen_1`] accepts.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for &'static SpeciesGen1 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        find_species_gen_1(&name).ok_or_else(|| serde::de::Error::custom(format!("unknown species '{name}'")))
+    }
+}
+
+/// Table helper; base stats are in the games' order: HP, Attack, Defense,
+/// Speed, Special.
+const fn species(dex: u8, name: &'static str, types: [TypeGen1; 2], base: [u8; 5]) -> SpeciesGen1 {
+    let [hp, attack, defense, speed, special] = base;
+    SpeciesGen1 { dex, name, types, base: BaseStatsGen1 { hp, attack, defense, speed, special } }
+}
+
+/// Every Generation 1 species, in Pokédex order.
+pub static SPECIES_GEN_1: [SpeciesGen1; 151] = [
+    species(  1, "Bulbasaur", [TypeGen1::Grass, TypeGen1::Poison], [45, 49, 49, 45, 65]),
+    species(  2, "Ivysaur", [TypeGen1::Grass, TypeGen1::Poison], [60, 62, 63, 60, 80]),
+    species(  3, "Venusaur", [TypeGen1::Grass, TypeGen1::Poison], [80, 82, 83, 80, 100]),
+    species(  4, "Charmander", [TypeGen1::Fire, TypeGen1::None], [39, 52, 43, 65, 50]),
+    species(  5, "Charmeleon", [TypeGen1::Fire, TypeGen1::None], [58, 64, 58, 80, 65]),
+    species(  6, "Charizard", [TypeGen1::Fire, TypeGen1::Flying], [78, 84, 78, 100, 85]),
+    species(  7, "Squirtle", [TypeGen1::Water, TypeGen1::None], [44, 48, 65, 43, 50]),
+    species(  8, "Wartortle", [TypeGen1::Water, TypeGen1::None], [59, 63, 80, 58, 65]),
+    species(  9, "Blastoise", [TypeGen1::Water, TypeGen1::None], [79, 83, 100, 78, 85]),
+    species( 10, "Caterpie", [TypeGen1::Bug, TypeGen1::None], [45, 30, 35, 45, 20]),
+    species( 11, "Metapod", [TypeGen1::Bug, TypeGen1::None], [50, 20, 55, 30, 25]),
+    species( 12, "Butterfree", [TypeGen1::Bug, TypeGen1::Flying], [60, 45, 50, 70, 80]),
+    species( 13, "Weedle", [TypeGen1::Bug, TypeGen1::Poison], [40, 35, 30, 50, 20]),
+    species( 14, "Kakuna", [TypeGen1::Bug, TypeGen1::Poison], [45, 25, 50, 35, 25]),
+    species( 15, "Beedrill", [TypeGen1::Bug, TypeGen1::Poison], [65, 80, 40, 75, 45]),
+    species( 16, "Pidgey", [TypeGen1::Normal, TypeGen1::Flying], [40, 45, 40, 56, 35]),
+    species( 17, "Pidgeotto", [TypeGen1::Normal, TypeGen1::Flying], [63, 60, 55, 71, 50]),
+    species( 18, "Pidgeot", [TypeGen1::Normal, TypeGen1::Flying], [83, 80, 75, 91, 70]),
+    species( 19, "Rattata", [TypeGen1::Normal, TypeGen1::None], [30, 56, 35, 72, 25]),
+    species( 20, "Raticate", [TypeGen1::Normal, TypeGen1::None], [55, 81, 60, 97, 50]),
+    species( 21, "Spearow", [TypeGen1::Normal, TypeGen1::Flying], [40, 60, 30, 70, 31]),
+    species( 22, "Fearow", [TypeGen1::Normal, TypeGen1::Flying], [65, 90, 65, 100, 61]),
+    species( 23, "Ekans", [TypeGen1::Poison, TypeGen1::None], [35, 60, 44, 55, 40]),
+    species( 24, "Arbok", [TypeGen1::Poison, TypeGen1::None], [60, 85, 69, 80, 65]),
+    species( 25, "Pikachu", [TypeGen1::Electric, TypeGen1::None], [35, 55, 30, 90, 50]),
+    species( 26, "Raichu", [TypeGen1::Electric, TypeGen1::None], [60, 90, 55, 100, 90]),
+    species( 27, "Sandshrew", [TypeGen1::Ground, TypeGen1::None], [50, 75, 85, 40, 30]),
+    species( 28, "Sandslash", [TypeGen1::Ground, TypeGen1::None], [75, 100, 110, 65, 55]),
+    species( 29, "Nidoran♀", [TypeGen1::Poison, TypeGen1::None], [55, 47, 52, 41, 40]),
+    species( 30, "Nidorina", [TypeGen1::Poison, TypeGen1::None], [70, 62, 67, 56, 55]),
+    species( 31, "Nidoqueen", [TypeGen1::Poison, TypeGen1::Ground], [90, 82, 87, 76, 75]),
+    species( 32, "Nidoran♂", [TypeGen1::Poison, TypeGen1::None], [46, 57, 40, 50, 40]),
+    species( 33, "Nidorino", [TypeGen1::Poison, TypeGen1::None], [61, 72, 57, 65, 55]),
+    species( 34, "Nidoking", [TypeGen1::Poison, TypeGen1::Ground], [81, 92, 77, 85, 75]),
+    species( 35, "Clefairy", [TypeGen1::Normal, TypeGen1::None], [70, 45, 48, 35, 60]),
+    species( 36, "Clefable", [TypeGen1::Normal, TypeGen1::None], [95, 70, 73, 60, 85]),
+    species( 37, "Vulpix", [TypeGen1::Fire, TypeGen1::None], [38, 41, 40, 65, 65]),
+    species( 38, "Ninetales", [TypeGen1::Fire, TypeGen1::None], [73, 76, 75, 100, 100]),
+    species( 39, "Jigglypuff", [TypeGen1::Normal, TypeGen1::None], [115, 45, 20, 20, 25]),
+    species( 40, "Wigglytuff", [TypeGen1::Normal, TypeGen1::None], [140, 70, 45, 45, 50]),
+    species( 41, "Zubat", [TypeGen1::Poison, TypeGen1::Flying], [40, 45, 35, 55, 40]),
+    species( 42, "Golbat", [TypeGen1::Poison, TypeGen1::Flying], [75, 80, 70, 90, 75]),
+    species( 43, "Oddish", [TypeGen1::Grass, TypeGen1::Poison], [45, 50, 55, 30, 75]),
+    species( 44, "Gloom", [TypeGen1::Grass, TypeGen1::Poison], [60, 65, 70, 40, 85]),
+    species( 45, "Vileplume", [TypeGen1::Grass, TypeGen1::Poison], [75, 80, 85, 50, 100]),
+    species( 46, "Paras", [TypeGen1::Bug, TypeGen1::Grass], [35, 70, 55, 25, 55]),
+    species( 47, "Parasect", [TypeGen1::Bug, TypeGen1::Grass], [60, 95, 80, 30, 80]),
+    species( 48, "Venonat", [TypeGen1::Bug, TypeGen1::Poison], [60, 55, 50, 45, 40]),
+    species( 49, "Venomoth", [TypeGen1::Bug, TypeGen1::Poison], [70, 65, 60, 90, 90]),
+    species( 50, "Diglett", [TypeGen1::Ground, TypeGen1::None], [10, 55, 25, 95, 45]),
+    species( 51, "Dugtrio", [TypeGen1::Ground, TypeGen1::None], [35, 80, 50, 120, 70]),
+    species( 52, "Meowth", [TypeGen1::Normal, TypeGen1::None], [40, 45, 35, 90, 40]),
+    species( 53, "Persian", [TypeGen1::Normal, TypeGen1::None], [65, 70, 60, 115, 65]),
+    species( 54, "Psyduck", [TypeGen1::Water, TypeGen1::None], [50, 52, 48, 55, 50]),
+    species( 55, "Golduck", [TypeGen1::Water, TypeGen1::None], [80, 82, 78, 85, 80]),
+    species( 56, "Mankey", [TypeGen1::Fighting, TypeGen1::None], [40, 80, 35, 70, 35]),
+    species( 57, "Primeape", [TypeGen1::Fighting, TypeGen1::None], [65, 105, 60, 95, 60]),
+    species( 58, "Growlithe", [TypeGen1::Fire, TypeGen1::None], [55, 70, 45, 60, 50]),
+    species( 59, "Arcanine", [TypeGen1::Fire, TypeGen1::None], [90, 110, 80, 95, 80]),
+    species( 60, "Poliwag", [TypeGen1::Water, TypeGen1::None], [40, 50, 40, 90, 40]),
+    species( 61, "Poliwhirl", [TypeGen1::Water, TypeGen1::None], [65, 65, 65, 90, 50]),
+    species( 62, "Poliwrath", [TypeGen1::Water, TypeGen1::Fighting], [90, 85, 95, 70, 70]),
+    species( 63, "Abra", [TypeGen1::Psychic, TypeGen1::None], [25, 20, 15, 90, 105]),
+    species( 64, "Kadabra", [TypeGen1::Psychic, TypeGen1::None], [40, 35, 30, 105, 120]),
+    species( 65, "Alakazam", [TypeGen1::Psychic, TypeGen1::None], [55, 50, 45, 120, 135]),
+    species( 66, "Machop", [TypeGen1::Fighting, TypeGen1::None], [70, 80, 50, 35, 35]),
+    species( 67, "Machoke", [TypeGen1::Fighting, TypeGen1::None], [80, 100, 70, 45, 50]),
+    species( 68, "Machamp", [TypeGen1::Fighting, TypeGen1::None], [90, 130, 80, 55, 65]),
+    species( 69, "Bellsprout", [TypeGen1::Grass, TypeGen1::Poison], [50, 75, 35, 40, 70]),
+    species( 70, "Weepinbell", [TypeGen1::Grass, TypeGen1::Poison], [65, 90, 50, 55, 85]),
+    species( 71, "Victreebel", [TypeGen1::Grass, TypeGen1::Poison], [80, 105, 65, 70, 100]),
+    species( 72, "Tentacool", [TypeGen1::Water, TypeGen1::Poison], [40, 40, 35, 70, 100]),
+    species( 73, "Tentacruel", [TypeGen1::Water, TypeGen1::Poison], [80, 70, 65, 100, 120]),
+    species( 74, "Geodude", [TypeGen1::Rock, TypeGen1::Ground], [40, 80, 100, 20, 30]),
+    species( 75, "Graveler", [TypeGen1::Rock, TypeGen1::Ground], [55, 95, 115, 35, 45]),
+    species( 76, "Golem", [TypeGen1::Rock, TypeGen1::Ground], [80, 110, 130, 45, 55]),
+    species( 77, "Ponyta", [TypeGen1::Fire, TypeGen1::None], [50, 85, 55, 90, 65]),
+    species( 78, "Rapidash", [TypeGen1::Fire, TypeGen1::None], [65, 100, 70, 105, 80]),
+    species( 79, "Slowpoke", [TypeGen1::Water, TypeGen1::Psychic], [90, 65, 65, 15, 40]),
+    species( 80, "Slowbro", [TypeGen1::Water, TypeGen1::Psychic], [95, 75, 110, 30, 80]),
+    species( 81, "Magnemite", [TypeGen1::Electric, TypeGen1::None], [25, 35, 70, 45, 95]),
+    species( 82, "Magneton", [TypeGen1::Electric, TypeGen1::None], [50, 60, 95, 70, 120]),
+    species( 83, "Farfetch'd", [TypeGen1::Normal, TypeGen1::Flying], [52, 65, 55, 60, 58]),
+    species( 84, "Doduo", [TypeGen1::Normal, TypeGen1::Flying], [35, 85, 45, 75, 35]),
+    species( 85, "Dodrio", [TypeGen1::Normal, TypeGen1::Flying], [60, 110, 70, 100, 60]),
+    species( 86, "Seel", [TypeGen1::Water, TypeGen1::None], [65, 45, 55, 45, 70]),
+    species( 87, "Dewgong", [TypeGen1::Water, TypeGen1::Ice], [90, 70, 80, 70, 95]),
+    species( 88, "Grimer", [TypeGen1::Poison, TypeGen1::None], [80, 80, 50, 25, 40]),
+    species( 89, "Muk", [TypeGen1::Poison, TypeGen1::None], [105, 105, 75, 50, 65]),
+    species( 90, "Shellder", [TypeGen1::Water, TypeGen1::None], [30, 65, 100, 40, 45]),
+    species( 91, "Cloyster", [TypeGen1::Water, TypeGen1::Ice], [50, 95, 180, 70, 85]),
+    species( 92, "Gastly", [TypeGen1::Ghost, TypeGen1::Poison], [30, 35, 30, 80, 100]),
+    species( 93, "Haunter", [TypeGen1::Ghost, TypeGen1::Poison], [45, 50, 45, 95, 115]),
+    species( 94, "Gengar", [TypeGen1::Ghost, TypeGen1::Poison], [60, 65, 60, 110, 130]),
+    species( 95, "Onix", [TypeGen1::Rock, TypeGen1::Ground], [35, 45, 160, 70, 30]),
+    species( 96, "Drowzee", [TypeGen1::Psychic, TypeGen1::None], [60, 48, 45, 42, 90]),
+    species( 97, "Hypno", [TypeGen1::Psychic, TypeGen1::None], [85, 73, 70, 67, 115]),
+    species( 98, "Krabby", [TypeGen1::Water, TypeGen1::None], [30, 105, 90, 50, 25]),
+    species( 99, "Kingler", [TypeGen1::Water, TypeGen1::None], [55, 130, 115, 75, 50]),
+    species(100, "Voltorb", [TypeGen1::Electric, TypeGen1::None], [40, 30, 50, 100, 55]),
+    species(101, "Electrode", [TypeGen1::Electric, TypeGen1::None], [60, 50, 70, 140, 80]),
+    species(102, "Exeggcute", [TypeGen1::Grass, TypeGen1::Psychic], [60, 40, 80, 40, 60]),
+    species(103, "Exeggutor", [TypeGen1::Grass, TypeGen1::Psychic], [95, 95, 85, 55, 125]),
+    species(104, "Cubone", [TypeGen1::Ground, TypeGen1::None], [50, 50, 95, 35, 40]),
+    species(105, "Marowak", [TypeGen1::Ground, TypeGen1::None], [60, 80, 110, 45, 50]),
+    species(106, "Hitmonlee", [TypeGen1::Fighting, TypeGen1::None], [50, 120, 53, 87, 35]),
+    species(107, "Hitmonchan", [TypeGen1::Fighting, TypeGen1::None], [50, 105, 79, 76, 35]),
+    species(108, "Lickitung", [TypeGen1::Normal, TypeGen1::None], [90, 55, 75, 30, 60]),
+    species(109, "Koffing", [TypeGen1::Poison, TypeGen1::None], [40, 65, 95, 35, 60]),
+    species(110, "Weezing", [TypeGen1::Poison, TypeGen1::None], [65, 90, 120, 60, 85]),
+    species(111, "Rhyhorn", [TypeGen1::Ground, TypeGen1::Rock], [80, 85, 95, 25, 30]),
+    species(112, "Rhydon", [TypeGen1::Ground, TypeGen1::Rock], [105, 130, 120, 40, 45]),
+    species(113, "Chansey", [TypeGen1::Normal, TypeGen1::None], [250, 5, 5, 50, 105]),
+    species(114, "Tangela", [TypeGen1::Grass, TypeGen1::None], [65, 55, 115, 60, 100]),
+    species(115, "Kangaskhan", [TypeGen1::Normal, TypeGen1::None], [105, 95, 80, 90, 40]),
+    species(116, "Horsea", [TypeGen1::Water, TypeGen1::None], [30, 40, 70, 60, 70]),
+    species(117, "Seadra", [TypeGen1::Water, TypeGen1::None], [55, 65, 95, 85, 95]),
+    species(118, "Goldeen", [TypeGen1::Water, TypeGen1::None], [45, 67, 60, 63, 50]),
+    species(119, "Seaking", [TypeGen1::Water, TypeGen1::None], [80, 92, 65, 68, 80]),
+    species(120, "Staryu", [TypeGen1::Water, TypeGen1::None], [30, 45, 55, 85, 70]),
+    species(121, "Starmie", [TypeGen1::Water, TypeGen1::Psychic], [60, 75, 85, 115, 100]),
+    species(122, "Mr. Mime", [TypeGen1::Psychic, TypeGen1::None], [40, 45, 65, 90, 100]),
+    species(123, "Scyther", [TypeGen1::Bug, TypeGen1::Flying], [70, 110, 80, 105, 55]),
+    species(124, "Jynx", [TypeGen1::Ice, TypeGen1::Psychic], [65, 50, 35, 95, 95]),
+    species(125, "Electabuzz", [TypeGen1::Electric, TypeGen1::None], [65, 83, 57, 105, 85]),
+    species(126, "Magmar", [TypeGen1::Fire, TypeGen1::None], [65, 95, 57, 93, 85]),
+    species(127, "Pinsir", [TypeGen1::Bug, TypeGen1::None], [65, 125, 100, 85, 55]),
+    species(128, "Tauros", [TypeGen1::Normal, TypeGen1::None], [75, 100, 95, 110, 70]),
+    species(129, "Magikarp", [TypeGen1::Water, TypeGen1::None], [20, 10, 55, 80, 20]),
+    species(130, "Gyarados", [TypeGen1::Water, TypeGen1::Flying], [95, 125, 79, 81, 100]),
+    species(131, "Lapras", [TypeGen1::Water, TypeGen1::Ice], [130, 85, 80, 60, 95]),
+    species(132, "Ditto", [TypeGen1::Normal, TypeGen1::None], [48, 48, 48, 48, 48]),
+    species(133, "Eevee", [TypeGen1::Normal, TypeGen1::None], [55, 55, 50, 55, 65]),
+    species(134, "Vaporeon", [TypeGen1::Water, TypeGen1::None], [130, 65, 60, 65, 110]),
+    species(135, "Jolteon", [TypeGen1::Electric, TypeGen1::None], [65, 65, 60, 130, 110]),
+    species(136, "Flareon", [TypeGen1::Fire, TypeGen1::None], [65, 130, 60, 65, 110]),
+    species(137, "Porygon", [TypeGen1::Normal, TypeGen1::None], [65, 60, 70, 40, 75]),
+    species(138, "Omanyte", [TypeGen1::Rock, TypeGen1::Water], [35, 40, 100, 35, 90]),
+    species(139, "Omastar", [TypeGen1::Rock, TypeGen1::Water], [70, 60, 125, 55, 115]),
+    species(140, "Kabuto", [TypeGen1::Rock, TypeGen1::Water], [30, 80, 90, 55, 45]),
+    species(141, "Kabutops", [TypeGen1::Rock, TypeGen1::Water], [60, 115, 105, 80, 70]),
+    species(142, "Aerodactyl", [TypeGen1::Rock, TypeGen1::Flying], [80, 105, 65, 130, 60]),
+    species(143, "Snorlax", [TypeGen1::Normal, TypeGen1::None], [160, 110, 65, 30, 65]),
+    species(144, "Articuno", [TypeGen1::Ice, TypeGen1::Flying], [90, 85, 100, 85, 125]),
+    species(145, "Zapdos", [TypeGen1::Electric, TypeGen1::Flying], [90, 90, 85, 100, 125]),
+    species(146, "Moltres", [TypeGen1::Fire, TypeGen1::Flying], [90, 100, 90, 90, 125]),
+    species(147, "Dratini", [TypeGen1::Dragon, TypeGen1::None], [41, 64, 45, 50, 50]),
+    species(148, "Dragonair", [TypeGen1::Dragon, TypeGen1::None], [61, 84, 65, 70, 70]),
+    species(149, "Dragonite", [TypeGen1::Dragon, TypeGen1::Flying], [91, 134, 95, 80, 100]),
+    species(150, "Mewtwo", [TypeGen1::Psychic, TypeGen1::None], [106, 110, 90, 130, 154]),
+    species(151, "Mew", [TypeGen1::Psychic, TypeGen1::None], [100, 100, 100, 100, 100]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pokédex numbers line up with table positions.
+    #[test]
+    fn test_dex_order() {
+        for (i, species) in SPECIES_GEN_1.iter().enumerate() {
+            assert_eq!(species.dex as usize, i + 1, "{}", species.name);
+        }
+    }
+
+    /// Known stats for a level 50 Starmie with trainer DVs.
+    #[test]
+    fn test_species_pokemon() {
+        let starmie = find_species_gen_1("STARMIE").unwrap();
+        let ivs = IVsGen1 { hp: 8, attack: 9, defense: 8, special: 8, speed: 8 };
+        let pokemon = starmie.pokemon(50, ivs);
+        assert_eq!(pokemon.stats.hp, 128);
+        assert_eq!(pokemon.stats.speed, 128);
+        assert_eq!(pokemon.types, [TypeGen1::Water, TypeGen1::Psychic]);
+        assert_eq!(find_species_gen_1("Farfetchd").unwrap().dex, 83);
+    }
+
+    /// Stat experience adds ceil(√EV) / 4, at most 63, before scaling by level.
+    #[test]
+    fn test_stat_exp() {
+        let slowpoke = find_species_gen_1("Slowpoke").unwrap();
+        let mut pokemon = slowpoke.pokemon(100, IVsGen1::default());
+        let speed = |ev| pokemon.calculate_stat(slowpoke.base.speed, 0, ev, false);
+        assert_eq!([0, 9, 10, 65_025, 65_535].map(speed), [35, 35, 36, 98, 98]);
+
+        pokemon.evs.speed = 65_535;
+        slowpoke.calculate_stats(&mut pokemon);
+        assert_eq!(pokemon.stats.speed, 98);
+    }
+
+    /// Names with nothing to compare get no suggestions.
+    #[test]
+    fn test_closest_names_empty() {
+        assert!(suggest_species_gen_1("").is_empty());
+        assert!(suggest_species_gen_1("?!").is_empty());
+        assert!(suggest_species_gen_1("  .  ").is_empty());
+        assert_eq!(suggest_species_gen_1("Pikachuu"), ["Pikachu"]);
+    }
+}
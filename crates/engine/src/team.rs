@@ -0,0 +1,255 @@
+use std::fmt;
+
+use crate::{MoveGen1, PokemonGen1, SpeciesGen1, StatusGen1, find_move_gen_1, find_species_gen_1};
+use crate::pokemon::{EVsGen1, IVsGen1};
+use crate::dvs::hp_dv_gen_1;
+use crate::evolution::pre_evolution_gen_1;
+use crate::VersionGen1;
+use crate::learnsets::learnset_in_version_gen_1;
+
+/// The most Pokémon a team can have.
+pub const TEAM_SIZE_GEN_1: usize = 6;
+
+/// A team as written in a team file. With the `serde` feature it reads
+/// from TOML (or JSON, etc.) like this:
+///
+/// ```toml
+/// [[pokemon]]
+/// species = "Pikachu"
+/// level = 25
+/// moves = ["Thunder Shock", "Quick Attack", "Thunder Wave"]
+/// dvs = { hp = 15, attack = 15, defense = 15, special = 15, speed = 15 }
+/// stat_exp = { hp = 2500, attack = 2500, defense = 2500, special = 2500, speed = 2500 }
+/// status = "Paralyzed"
+///
+/// [[pokemon]]
+/// species = "Snorlax"
+/// level = 30
+/// status = { Asleep = 3 }
+/// ```
+///
+/// # Fields
+/// - `pokemon`: The team's members, lead first
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(deny_unknown_fields))]
+pub struct TeamFileGen1 {
+    pub pokemon: Vec<TeamMemberGen1>,
+}
+
+/// One team member as written in a team file, before it's checked against
+/// the species and move data.
+///
+/// # Fields
+/// - `species`: Species name, spelled any way [`find_species_gen_1`] accepts
+/// - `level`: 1-100
+/// - `dvs`: DVs (0-15), all 15 if left out; the HP DV must be the one the
+///   other four give (see [`hp_dv_gen_1`])
+/// - `stat_exp`: Stat experience, all 0 if left out
+/// - `moves`: Up to four moves it can legally know at its level; the moves
+///   it would know in the wild if left empty
+/// - `status`: Healthy if left out
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(default, deny_unknown_fields))]
+pub struct TeamMemberGen1 {
+    pub species: String,
+    pub level: u8,
+    pub dvs: Option<IVsGen1>,
+    pub stat_exp: Option<EVsGen1>,
+    pub moves: Vec<String>,
+    pub status: StatusGen1,
+}
+
+/// Why a team file doesn't describe a legal team.
+///
+/// # Fields
+/// - `member`: Which Pokémon the problem is with, counting from 1, or 0 for
+///   the team as a whole
+/// - `field`: The field that's wrong (e.g., "species", "moves")
+/// - `message`: What's wrong with it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeamErrorGen1 {
+    pub member: usize,
+    pub field: String,
+    pub message: String,
+}
+
+impl fmt::Display for TeamErrorGen1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.member == 0 {
+            write!(f, "{}: {}", self.field, self.message)
+        } else {
+            write!(f, "pokemon {}: {}: {}", self.member, self.field, self.message)
+        }
+    }
+}
+
+impl std::error::Error for TeamErrorGen1 {}
+
+impl TeamFileGen1 {
+    /// Checks every member and builds the team.
+    ///
+    /// # Errors
+    /// The first problem found: more than six members, or any member's
+    /// problem (see [`TeamMemberGen1::pokemon`]).
+    pub fn pokemon(&self) -> Result<Vec<PokemonGen1>, TeamErrorGen1> {
+        if self.pokemon.is_empty() || self.pokemon.len() > TEAM_SIZE_GEN_1 {
+            return Err(TeamErrorGen1 {
+                member: 0,
+                field: "pokemon".into(),
+                message: format!("a team has 1 to {TEAM_SIZE_GEN_1} Pokémon, not {}", self.pokemon.len()),
+            });
+        }
+        self.pokemon.iter()
+            .enumerate()
+            .map(|(i, member)| member.pokemon().map_err(|error| TeamErrorGen1 { member: i + 1, ..error }))
+            .collect()
+    }
+}
+
+impl TeamMemberGen1 {
+    /// Checks the member against the species and move data and builds it,
+    /// with stats for its level, DVs and stat experience.
+    ///
+    /// # Errors
+    /// An unknown species or move, a level, DV or sleep counter out of
+    /// range, an HP DV the other DVs don't give, more than four moves, a
+    /// move listed twice, or a move it can't know at its level. A move
+    /// counts as legal if the species, or anything it evolves from, learns
+    /// it by that level in Red, Blue or Yellow, or from a TM or HM. The
+    /// error's `member` is 0; the team fills it in.
+    ///
+    /// # Example
+    /// ```
+    /// use engine::TeamMemberGen1;
+    ///
+    /// let pikachu = TeamMemberGen1 {
+    ///     species: "Pikachu".into(),
+    ///     level: 25,
+    ///     moves: vec!["Thunder Shock".into(), "Surf".into()],
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(pikachu.pokemon().unwrap_err().to_string(), "moves: Pikachu can't learn Surf");
+    /// ```
+    pub fn pokemon(&self) -> Result<PokemonGen1, TeamErrorGen1> {
+        let error = |field: &str, message: String| TeamErrorGen1 { member: 0, field: field.into(), message };
+
+        let species = find_species_gen_1(&self.species)
+            .ok_or_else(|| error("species", format!("unknown species '{}'", self.species)))?;
+        if !(1..=100).contains(&self.level) {
+            return Err(error("level", format!("{} isn't a level from 1 to 100", self.level)));
+        }
+
+        let ivs = self.dvs.clone().unwrap_or(IVsGen1 { hp: 15, attack: 15, defense: 15, special: 15, speed: 15 });
+        if let Some(dv) = [ivs.hp, ivs.attack, ivs.defense, ivs.special, ivs.speed].into_iter().find(|&dv| dv > 15) {
+            return Err(error("dvs", format!("{dv} isn't a DV from 0 to 15")));
+        }
+        let hp = hp_dv_gen_1(ivs.attack, ivs.defense, ivs.special, ivs.speed);
+        if ivs.hp != hp {
+            return Err(error("dvs", format!("the HP DV is {} but the other DVs make it {hp}", ivs.hp)));
+        }
+
+        if let StatusGen1::Asleep(turns) = self.status
+            && !(1..=7).contains(&turns)
+        {
+            return Err(error("status", format!("sleep lasts 1 to 7 turns, not {turns}")));
+        }
+
+        if self.moves.len() > 4 {
+            return Err(error("moves", format!("{} moves is more than 4", self.moves.len())));
+        }
+        let mut moves = Vec::new();
+        for name in &self.moves {
+            let m = find_move_gen_1(name).ok_or_else(|| error("moves", format!("unknown move '{name}'")))?;
+            if moves.iter().any(|known: &MoveGen1| known.name == m.name) {
+                return Err(error("moves", format!("{} is listed twice", m.name)));
+            }
+            if !can_know(species, &m.name, self.level) {
+                return Err(error("moves", format!("{} can't learn {}", species.name, m.name)));
+            }
+            moves.push(m);
+        }
+
+        let mut pokemon = species.pokemon(self.level, ivs);
+        pokemon.evs = self.stat_exp.clone().unwrap_or_default();
+        species.calculate_stats(&mut pokemon);
+        if !moves.is_empty() {
+            pokemon.moves = moves;
+        }
+        pokemon.status = self.status;
+        Ok(pokemon)
+    }
+}
+
+/// Whether a Pokémon of `species` at `level` can know a move, from its own
+/// learnset in either version or anything it evolved from.
+fn can_know(species: &SpeciesGen1, move_name: &str, level: u8) -> bool {
+    let mut current = Some(species);
+    while let Some(species) = current {
+        for version in [VersionGen1::RedBlue, VersionGen1::Yellow] {
+            let learnset = learnset_in_version_gen_1(species, version);
+            if learnset.level_learned(move_name).is_some_and(|learned| learned <= level)
+                || learnset.machine_moves().contains(&move_name)
+            {
+                return true;
+            }
+        }
+        current = pre_evolution_gen_1(species).map(|evolution| evolution.from_species());
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(species: &str, level: u8, moves: &[&str]) -> TeamMemberGen1 {
+        TeamMemberGen1 {
+            species: species.into(),
+            level,
+            moves: moves.iter().map(|&m| m.into()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// A legal member gets the stats, moves and status it was given.
+    #[test]
+    fn test_member() {
+        let mut jolteon = member("jolteon", 40, &["Thunderbolt", "Thunder Wave", "Tackle"]);
+        jolteon.stat_exp = Some(EVsGen1 { speed: 65_535, ..Default::default() });
+        jolteon.status = StatusGen1::Asleep(2);
+        let pokemon = jolteon.pokemon().unwrap();
+        let species = find_species_gen_1("Jolteon").unwrap();
+        let mut expected = species.pokemon(40, IVsGen1 { hp: 15, attack: 15, defense: 15, special: 15, speed: 15 });
+        assert!(pokemon.stats.speed > expected.stats.speed);
+        expected.evs.speed = 65_535;
+        species.calculate_stats(&mut expected);
+        assert_eq!(pokemon.stats.speed, expected.stats.speed);
+        assert_eq!(pokemon.moves.len(), 3);
+        assert_eq!(pokemon.status, StatusGen1::Asleep(2));
+
+        // No moves means its wild moveset
+        assert_eq!(member("Jolteon", 40, &[]).pokemon().unwrap().moves, expected.moves);
+    }
+
+    /// Each kind of problem names the member and the field.
+    #[test]
+    fn test_validation() {
+        let message = |member: TeamMemberGen1| member.pokemon().unwrap_err().to_string();
+        assert_eq!(message(member("Pikablu", 5, &[])), "species: unknown species 'Pikablu'");
+        assert_eq!(message(member("Pikachu", 0, &[])), "level: 0 isn't a level from 1 to 100");
+        // Jolteon learns Pin Missile at 48
+        assert_eq!(message(member("Jolteon", 40, &["Pin Missile"])), "moves: Jolteon can't learn Pin Missile");
+        assert_eq!(message(member("Pikachu", 5, &["Growl", "Growl"])), "moves: Growl is listed twice");
+        // Slam is a Yellow-only level 20 move, which Raichu keeps
+        assert!(member("Raichu", 20, &["Slam"]).pokemon().is_ok());
+        assert_eq!(message(member("Pikachu", 19, &["Slam"])), "moves: Pikachu can't learn Slam");
+
+        let mut bad_dvs = member("Pikachu", 5, &[]);
+        bad_dvs.dvs = Some(IVsGen1 { hp: 15, attack: 14, defense: 15, special: 15, speed: 15 });
+        assert_eq!(message(bad_dvs), "dvs: the HP DV is 15 but the other DVs make it 7");
+
+        let team = TeamFileGen1 { pokemon: vec![member("Pikachu", 5, &[]), member("Raichu", 5, &["Surf"])] };
+        assert_eq!(team.pokemon().unwrap_err().to_string(), "pokemon 2: moves: Raichu can't learn Surf");
+        assert_eq!(TeamFileGen1::default().pokemon().unwrap_err().field, "pokemon");
+    }
+}
@@ -0,0 +1,459 @@
+use crate::battle::{ActionGen1, BattleStateGen1, OpponentPolicyGen1};
+use crate::item::ItemGen1;
+use crate::{MoveEffectGen1, StatusGen1, type_effectiveness_gen_1};
+
+/// Trainer classes in Pokémon Red, Blue and Yellow.
+///
+/// The class decides how a trainer's AI behaves: which move choice
+/// modifiers it applies, and which items or switches it may use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TrainerClassGen1 {
+    Youngster,
+    BugCatcher,
+    Lass,
+    Sailor,
+    JrTrainerM,
+    JrTrainerF,
+    Pokemaniac,
+    SuperNerd,
+    Hiker,
+    Biker,
+    Burglar,
+    Engineer,
+    Fisher,
+    Swimmer,
+    CueBall,
+    Gambler,
+    Beauty,
+    Psychic,
+    Rocker,
+    Juggler,
+    Tamer,
+    BirdKeeper,
+    Blackbelt,
+    Rival1,
+    ProfOak,
+    Chief,
+    Scientist,
+    Giovanni,
+    Rocket,
+    CooltrainerM,
+    CooltrainerF,
+    Bruno,
+    Brock,
+    Misty,
+    LtSurge,
+    Erika,
+    Koga,
+    Blaine,
+    Sabrina,
+    Gentleman,
+    Rival2,
+    Rival3,
+    Lorelei,
+    Channeler,
+    Agatha,
+    Lance,
+}
+
+impl TrainerClassGen1 {
+    /// Every trainer class, in the games' internal order.
+    pub fn iter() -> impl Iterator<Item = TrainerClassGen1> {
+        use TrainerClassGen1::*;
+        [
+            Youngster, BugCatcher, Lass, Sailor, JrTrainerM, JrTrainerF, Pokemaniac,
+            SuperNerd, Hiker, Biker, Burglar, Engineer, Fisher, Swimmer, CueBall,
+            Gambler, Beauty, Psychic, Rocker, Juggler, Tamer, BirdKeeper, Blackbelt,
+            Rival1, ProfOak, Chief, Scientist, Giovanni, Rocket, CooltrainerM,
+            CooltrainerF, Bruno, Brock, Misty, LtSurge, Erika, Koga, Blaine, Sabrina,
+            Gentleman, Rival2, Rival3, Lorelei, Channeler, Agatha, Lance,
+        ]
+        .into_iter()
+    }
+
+    /// The class name as shown in game (e.g., "Cooltrainer♂").
+    pub const fn name(&self) -> &'static str {
+        use TrainerClassGen1::*;
+        match self {
+            Youngster => "Youngster",
+            BugCatcher => "Bug Catcher",
+            Lass => "Lass",
+            Sailor => "Sailor",
+            JrTrainerM => "Jr. Trainer♂",
+            JrTrainerF => "Jr. Trainer♀",
+            Pokemaniac => "PokéManiac",
+            SuperNerd => "Super Nerd",
+            Hiker => "Hiker",
+            Biker => "Biker",
+            Burglar => "Burglar",
+            Engineer => "Engineer",
+            Fisher => "Fisherman",
+            Swimmer => "Swimmer",
+            CueBall => "Cue Ball",
+            Gambler => "Gambler",
+            Beauty => "Beauty",
+            Psychic => "Psychic",
+            Rocker => "Rocker",
+            Juggler => "Juggler",
+            Tamer => "Tamer",
+            BirdKeeper => "Bird Keeper",
+            Blackbelt => "Blackbelt",
+            Rival1 | Rival2 | Rival3 => "Rival",
+            ProfOak => "Prof. Oak",
+            Chief => "Chief",
+            Scientist => "Scientist",
+            Giovanni => "Giovanni",
+            Rocket => "Rocket",
+            CooltrainerM => "Cooltrainer♂",
+            CooltrainerF => "Cooltrainer♀",
+            Bruno => "Bruno",
+            Brock => "Brock",
+            Misty => "Misty",
+            LtSurge => "Lt. Surge",
+            Erika => "Erika",
+            Koga => "Koga",
+            Blaine => "Blaine",
+            Sabrina => "Sabrina",
+            Gentleman => "Gentleman",
+            Lorelei => "Lorelei",
+            Channeler => "Channeler",
+            Agatha => "Agatha",
+            Lance => "Lance",
+        }
+    }
+
+    /// The move choice modifiers (1, 2 and/or 3) the class applies.
+    ///
+    /// 1. Discourage status moves when the player is already statused
+    /// 2. Encourage stat and buff moves on the Pokémon's second turn out
+    /// 3. Encourage super effective moves, discourage not very effective ones
+    pub fn move_choice_modifiers(&self) -> &'static [u8] {
+        use TrainerClassGen1::*;
+        match self {
+            CueBall => &[],
+            Pokemaniac | Lorelei => &[1, 2, 3],
+            SuperNerd | Psychic | Chief | Scientist | Gentleman => &[1, 2],
+            Sailor | Burglar | Fisher | Swimmer | Beauty | ProfOak | Giovanni
+            | CooltrainerM | CooltrainerF | Misty | LtSurge | Erika | Koga | Blaine
+            | Sabrina | Rival2 | Rival3 | Lance => &[1, 3],
+            _ => &[1],
+        }
+    }
+
+    /// Base prize money; the payout is this times the level of the
+    /// trainer's last Pokémon.
+    pub fn base_money(&self) -> u16 {
+        use TrainerClassGen1::*;
+        match self {
+            Swimmer => 5,
+            BugCatcher | Psychic => 10,
+            Youngster | Lass => 15,
+            JrTrainerM | JrTrainerF | Biker => 20,
+            SuperNerd | CueBall | Rocker | BirdKeeper | Blackbelt => 25,
+            Sailor | Chief | Rocket | Channeler => 30,
+            Hiker | Fisher | Juggler | Rival1 | CooltrainerM | CooltrainerF => 35,
+            Tamer => 40,
+            Pokemaniac | Engineer | Scientist => 50,
+            Rival2 => 65,
+            Gambler | Beauty | Gentleman => 70,
+            Burglar => 90,
+            ProfOak | Giovanni | Bruno | Brock | Misty | LtSurge | Erika | Koga | Blaine
+            | Sabrina | Rival3 | Lorelei | Agatha | Lance => 99,
+        }
+    }
+
+    /// How many items or switches the trainer may use per battle.
+    pub fn ai_uses(&self) -> u8 {
+        use TrainerClassGen1::*;
+        match self {
+            Brock => 5,
+            Blackbelt | CooltrainerM | Bruno | Koga | Blaine | Lorelei | Agatha => 2,
+            Chief | Giovanni | CooltrainerF | Misty | LtSurge | Erika | Sabrina
+            | Rival2 | Rival3 | Lance => 1,
+            _ => 3,
+        }
+    }
+}
+
+/// The trainer AI of Pokémon Red, Blue and Yellow.
+///
+/// Each turn, the class's AI routine may use an item or switch (drawing a
+/// random number out of 256), as long as it has uses left. Otherwise every
+/// move starts with a score of 10, the class's move choice modifiers adjust
+/// the scores, and a move is picked uniformly among the lowest scoring ones.
+///
+/// # Example
+/// ```
+/// use engine::{BattleStateGen1, BattlerGen1, OpponentPolicyGen1, PokemonGen1, MoveGen1};
+/// use engine::{TrainerAiGen1, TrainerClassGen1};
+///
+/// let pokemon = PokemonGen1::default();
+/// let battler = BattlerGen1::new(pokemon, vec![MoveGen1::default()], 50);
+/// let state = BattleStateGen1::new(vec![battler.clone()], vec![battler]);
+///
+/// let ai = TrainerAiGen1::new(TrainerClassGen1::Brock);
+/// let actions = ai.action_probabilities(&state);
+/// assert_eq!(actions.len(), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrainerAiGen1 {
+    pub class: TrainerClassGen1,
+}
+
+impl TrainerAiGen1 {
+    pub fn new(class: TrainerClassGen1) -> Self {
+        Self { class }
+    }
+
+    /// Scores each of the opponent's active moves; lower is more likely.
+    pub fn move_scores(&self, state: &BattleStateGen1) -> Vec<u8> {
+        let modifiers = self.class.move_choice_modifiers();
+        let attacker = state.opponent.active();
+        let defender = &state.player.active().pokemon;
+
+        let mut scores = vec![10u8; attacker.moves.len()];
+        for (score, mov) in scores.iter_mut().zip(&attacker.moves) {
+            // Don't use status moves on a Pokémon that already has a status
+            if modifiers.contains(&1)
+                && defender.status != StatusGen1::Healthy
+                && mov.power == 0
+                && matches!(mov.effect, MoveEffectGen1::Sleep | MoveEffectGen1::Poison | MoveEffectGen1::Paralyze)
+            {
+                *score += 5;
+            }
+
+            // Set up on the turn after being sent out
+            if modifiers.contains(&2) && state.opponent.turns_out == 1 && is_setup_effect(mov.effect) {
+                *score -= 1;
+            }
+
+            if modifiers.contains(&3) {
+                let effectiveness = type_effectiveness_gen_1(mov.typ, &defender.types);
+                if effectiveness > 1.0 {
+                    *score -= 1;
+                } else if effectiveness < 1.0 {
+                    // Only discouraged when some other damaging move exists,
+                    // however effective that move turns out to be
+                    let better = attacker.moves.iter().any(|other| {
+                        other.typ != mov.typ && other.power > 0 && !is_special_damage(other.effect)
+                    });
+                    if better {
+                        *score += 1;
+                    }
+                }
+            }
+        }
+        scores
+    }
+
+    /// The chance of the opponent choosing each of its active moves, if it
+    /// doesn't use an item or switch.
+    pub fn move_probabilities(&self, state: &BattleStateGen1) -> Vec<f64> {
+        let scores = self.move_scores(state);
+        let Some(&best) = scores.iter().min() else {
+            return Vec::new();
+        };
+        let count = scores.iter().filter(|&&s| s == best).count();
+        scores.iter()
+            .map(|&s| if s == best { 1.0 / count as f64 } else { 0.0 })
+            .collect()
+    }
+
+    /// The items and switches the class's AI routine might use this turn,
+    /// with their chances. Whatever chance is left over goes to moves.
+    pub fn special_actions(&self, state: &BattleStateGen1) -> Vec<(ActionGen1, f64)> {
+        use TrainerClassGen1::*;
+
+        let side = &state.opponent;
+        if side.ai_actions_used >= self.class.ai_uses() {
+            return Vec::new();
+        }
+
+        let active = side.active();
+        let max_hp = active.pokemon.stats.hp;
+        let hp_below = |fraction: u16| active.hp < max_hp / fraction;
+        let chance = |below: u16| below as f64 / 256.0;
+        let item = |item: ItemGen1, p: f64| vec![(ActionGen1::Item(item), p)];
+        // Only switches out when there is a Pokémon to switch to
+        let switch = |p: f64| match side.switch_targets().next() {
+            Some(i) => vec![(ActionGen1::Switch(i), p)],
+            None => Vec::new(),
+        };
+
+        let actions = match self.class {
+            Juggler => switch(chance(64)),
+            Blackbelt => item(ItemGen1::XAttack, chance(32)),
+            Giovanni => item(ItemGen1::GuardSpec, chance(64)),
+            CooltrainerM | Koga => item(ItemGen1::XAttack, chance(64)),
+            // The intended 25% roll is never checked
+            CooltrainerF if hp_below(10) => item(ItemGen1::HyperPotion, 1.0),
+            CooltrainerF if hp_below(5) => switch(1.0),
+            Bruno | Misty => item(ItemGen1::XDefend, chance(64)),
+            Brock if active.pokemon.status != StatusGen1::Healthy => item(ItemGen1::FullHeal, 1.0),
+            LtSurge => item(ItemGen1::XSpeed, chance(64)),
+            Erika if hp_below(10) => item(ItemGen1::SuperPotion, chance(128)),
+            // Heals even at full HP
+            Blaine => item(ItemGen1::SuperPotion, chance(64)),
+            Sabrina if hp_below(10) => item(ItemGen1::HyperPotion, chance(64)),
+            Rival2 if hp_below(5) => item(ItemGen1::Potion, chance(32)),
+            Rival3 if hp_below(5) => item(ItemGen1::FullRestore, chance(32)),
+            Lorelei if hp_below(5) => item(ItemGen1::SuperPotion, chance(128)),
+            Lance if hp_below(5) => item(ItemGen1::HyperPotion, chance(128)),
+            Agatha => {
+                let mut actions = switch(chance(20));
+                if hp_below(4) {
+                    actions.extend(item(ItemGen1::SuperPotion, chance(128 - 20)));
+                }
+                actions
+            }
+            _ => Vec::new(),
+        };
+        actions.into_iter().filter(|&(_, p)| p > 0.0).collect()
+    }
+}
+
+impl OpponentPolicyGen1 for TrainerAiGen1 {
+    fn action_probabilities(&self, state: &BattleStateGen1) -> Vec<(ActionGen1, f64)> {
+        let mut actions = self.special_actions(state);
+        let remaining = 1.0 - actions.iter().map(|(_, p)| p).sum::<f64>();
+        if remaining > 0.0 {
+            actions.extend(
+                self.move_probabilities(state)
+                    .into_iter()
+                    .enumerate()
+                    .filter(|&(_, p)| p > 0.0)
+                    .map(|(i, p)| (ActionGen1::Move(i), p * remaining)),
+            );
+        }
+        actions
+    }
+}
+
+/// Effects encouraged by the second move choice modifier.
+fn is_setup_effect(effect: MoveEffectGen1) -> bool {
+    matches!(
+        effect,
+        MoveEffectGen1::StatUp(..)
+            | MoveEffectGen1::StatDown(..)
+            | MoveEffectGen1::Conversion
+            | MoveEffectGen1::Haze
+            | MoveEffectGen1::Bide
+            | MoveEffectGen1::Heal
+            | MoveEffectGen1::Transform
+            | MoveEffectGen1::LightScreen
+            | MoveEffectGen1::Reflect
+            | MoveEffectGen1::Poison
+    )
+}
+
+/// Moves the third modifier doesn't count as a better alternative.
+fn is_special_damage(effect: MoveEffectGen1) -> bool {
+    matches!(
+        effect,
+        MoveEffectGen1::SuperFang
+            | MoveEffectGen1::FixedDamage(_)
+            | MoveEffectGen1::LevelDamage
+            | MoveEffectGen1::Psywave
+            | MoveEffectGen1::Fly
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BattlerGen1, MoveCategory, MoveGen1, PokemonGen1, StatsGen1, TypeGen1};
+
+    fn battler(types: [TypeGen1; 2], status: StatusGen1, moves: Vec<MoveGen1>) -> BattlerGen1 {
+        let pokemon = PokemonGen1 {
+            types,
+            stats: StatsGen1 { lvl: 30, hp: 100, attack: 60, defense: 60, special: 60, speed: 60 },
+            status,
+            ..Default::default()
+        };
+        BattlerGen1::new(pokemon, moves, 60)
+    }
+
+    fn mov(name: &str, typ: TypeGen1, power: u8, effect: MoveEffectGen1) -> MoveGen1 {
+        let category = if power == 0 { MoveCategory::Status } else { MoveCategory::Physical };
+        MoveGen1 { name: name.into(), typ, power, category, effect, ..Default::default() }
+    }
+
+    /// Thunder Wave is avoided against a Pokémon that is already paralyzed.
+    #[test]
+    fn test_status_moves_discouraged() {
+        let moves = vec![
+            mov("Thunder Wave", TypeGen1::Electric, 0, MoveEffectGen1::Paralyze),
+            mov("Tackle", TypeGen1::Normal, 35, MoveEffectGen1::None),
+        ];
+        let player = battler([TypeGen1::Water, TypeGen1::None], StatusGen1::Paralyzed, vec![]);
+        let state = BattleStateGen1::new(
+            vec![player],
+            vec![battler([TypeGen1::Electric, TypeGen1::None], StatusGen1::Healthy, moves)],
+        );
+
+        let ai = TrainerAiGen1::new(TrainerClassGen1::Youngster);
+        assert_eq!(ai.move_scores(&state), vec![15, 10]);
+        assert_eq!(ai.move_probabilities(&state), vec![0.0, 1.0]);
+    }
+
+    /// Classes with the third modifier always pick a super effective move.
+    #[test]
+    fn test_super_effective_moves_encouraged() {
+        let moves = vec![
+            mov("Water Gun", TypeGen1::Water, 40, MoveEffectGen1::None),
+            mov("Tackle", TypeGen1::Normal, 35, MoveEffectGen1::None),
+        ];
+        let player = battler([TypeGen1::Rock, TypeGen1::None], StatusGen1::Healthy, vec![]);
+        let state = BattleStateGen1::new(
+            vec![player],
+            vec![battler([TypeGen1::Water, TypeGen1::None], StatusGen1::Healthy, moves)],
+        );
+
+        let misty = TrainerAiGen1::new(TrainerClassGen1::Misty);
+        assert_eq!(misty.move_probabilities(&state), vec![1.0, 0.0]);
+        let cue_ball = TrainerAiGen1::new(TrainerClassGen1::CueBall);
+        assert_eq!(cue_ball.move_probabilities(&state), vec![0.5, 0.5]);
+    }
+
+    /// Brock always cures a status while he has uses left.
+    #[test]
+    fn test_brock_full_heal() {
+        let tackle = mov("Tackle", TypeGen1::Normal, 35, MoveEffectGen1::None);
+        let player = battler([TypeGen1::Normal, TypeGen1::None], StatusGen1::Healthy, vec![]);
+        let mut state = BattleStateGen1::new(
+            vec![player],
+            vec![battler([TypeGen1::Rock, TypeGen1::None], StatusGen1::Poisoned, vec![tackle])],
+        );
+
+        let ai = TrainerAiGen1::new(TrainerClassGen1::Brock);
+        assert_eq!(
+            ai.action_probabilities(&state),
+            vec![(ActionGen1::Item(ItemGen1::FullHeal), 1.0)]
+        );
+
+        state.opponent.ai_actions_used = 5;
+        assert_eq!(ai.action_probabilities(&state), vec![(ActionGen1::Move(0), 1.0)]);
+    }
+
+    /// Item chances and move chances always add up to 1.
+    #[test]
+    fn test_action_probabilities_sum_to_one() {
+        let moves = vec![
+            mov("Swords Dance", TypeGen1::Normal, 0, MoveEffectGen1::StatUp(crate::StatGen1::Attack, 2)),
+            mov("Slash", TypeGen1::Normal, 70, MoveEffectGen1::None),
+        ];
+        let player = battler([TypeGen1::Ghost, TypeGen1::None], StatusGen1::Healthy, vec![]);
+        let mut opponent = battler([TypeGen1::Normal, TypeGen1::None], StatusGen1::Healthy, moves);
+        opponent.hp = 15;
+        let mut state = BattleStateGen1::new(vec![player], vec![opponent.clone(), opponent]);
+        state.opponent.turns_out = 1;
+
+        for class in TrainerClassGen1::iter() {
+            let actions = TrainerAiGen1::new(class).action_probabilities(&state);
+            let total: f64 = actions.iter().map(|(_, p)| p).sum();
+            assert!((total - 1.0).abs() < 1e-9, "{}: {total}", class.name());
+        }
+    }
+}
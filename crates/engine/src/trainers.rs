@@ -0,0 +1,2031 @@
+use std::sync::LazyLock;
+
+use crate::battle::BattlerGen1;
+use crate::pokemon::IVsGen1;
+use crate::species::{find_species_gen_1, name_key};
+use crate::move_data::find_move_gen_1;
+use crate::learnsets::default_moveset_in_version_gen_1;
+use crate::trainer_ai::{TrainerAiGen1, TrainerClassGen1};
+use crate::PokemonGen1;
+
+/// The game versions a trainer appears in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VersionGen1 {
+    RedBlue,
+    Yellow,
+}
+
+/// A Pokémon in a trainer's party.
+///
+/// # Fields
+/// - `species`: Species name, as found by [`find_species_gen_1`]
+/// - `level`: The Pokémon's level
+/// - `moves`: The moves it knows. Empty means it knows the last four moves
+///   its species learns by level up, which is how most trainer Pokémon work
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TrainerPokemonGen1 {
+    pub species: &'static str,
+    pub level: u8,
+    pub moves: &'static [&'static str],
+}
+
+/// A trainer battle from Red, Blue or Yellow.
+///
+/// # Fields
+/// - `class`: Trainer class, which also decides the AI
+/// - `name`: The trainer's name (e.g., "Brock"), or the class name
+/// - `location`: Where the battle takes place
+/// - `version`: The games the battle appears in
+/// - `variant`: What decides between alternative parties, if anything
+///   (e.g., the rival's team depends on the player's starter)
+/// - `party`: The trainer's Pokémon, in the order they're sent out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct TrainerGen1 {
+    pub class: TrainerClassGen1,
+    pub name: &'static str,
+    pub location: &'static str,
+    pub version: VersionGen1,
+    pub variant: Option<&'static str>,
+    pub party: &'static [TrainerPokemonGen1],
+}
+
+/// The DVs every Gen 1 trainer Pokémon has: 9 Attack, 8 for the rest.
+pub const TRAINER_DVS_GEN_1: IVsGen1 = IVsGen1 { hp: 8, attack: 9, defense: 8, special: 8, speed: 8 };
+
+impl TrainerPokemonGen1 {
+    /// The Pokémon with its stats calculated from trainer DVs, knowing its
+    /// moves (or `version`'s level-up default if it has none listed).
+    ///
+    /// # Panics
+    /// If the species name isn't in the species table.
+    pub fn pokemon(&self, version: VersionGen1) -> PokemonGen1 {
+        let species = self.species_data();
+        let mut pokemon = species.pokemon(self.level, TRAINER_DVS_GEN_1);
+        if self.moves.is_empty() {
+            pokemon.moves = default_moveset_in_version_gen_1(species, self.level, version);
+        } else {
+            pokemon.moves = self.moves.iter()
+                .map(|name| find_move_gen_1(name).unwrap_or_else(|| panic!("unknown move {name}")))
+                .collect();
+        }
+        pokemon
+    }
+
+    /// The Pokémon ready for battle, at full HP with its moveset.
+    pub fn battler(&self, version: VersionGen1) -> BattlerGen1 {
+        let pokemon = self.pokemon(version);
+        let moves = pokemon.moves.clone();
+        BattlerGen1::new(pokemon, moves, self.species_data().base.speed)
+    }
+
+    fn species_data(&self) -> &'static crate::SpeciesGen1 {
+        find_species_gen_1(self.species)
+            .unwrap_or_else(|| panic!("unknown species {}", self.species))
+    }
+}
+
+impl TrainerGen1 {
+    /// Prize money for beating the trainer: the class's base money times
+    /// the level of the last Pokémon in the party.
+    pub fn prize_money(&self) -> u32 {
+        let last_level = self.party.last().map_or(0, |p| p.level);
+        self.class.base_money() as u32 * last_level as u32
+    }
+
+    /// The AI this trainer uses in battle.
+    pub fn ai(&self) -> TrainerAiGen1 {
+        TrainerAiGen1::new(self.class)
+    }
+
+    /// The whole party ready for battle.
+    pub fn battlers(&self) -> Vec<BattlerGen1> {
+        self.party.iter().map(|pokemon| pokemon.battler(self.version)).collect()
+    }
+}
+
+/// Every trainer in the database, for both Red/Blue and Yellow: the gym
+/// leaders, Giovanni, the Elite Four, the Champion and every rival battle,
+/// plus the route, gym and Team Rocket trainers.
+pub fn trainers_gen_1() -> &'static [TrainerGen1] {
+    &TRAINERS_GEN_1
+}
+
+/// Finds trainers whose location contains `location`, ignoring case,
+/// spaces and punctuation (so "mt moon" matches "Mt. Moon B2F").
+///
+/// # Example
+/// ```
+/// use engine::find_trainers_by_location_gen_1;
+///
+/// let gym = find_trainers_by_location_gen_1("pewter gym");
+/// assert!(gym.iter().any(|t| t.name == "Brock"));
+/// ```
+pub fn find_trainers_by_location_gen_1(location: &str) -> Vec<&'static TrainerGen1> {
+    let key = name_key(location);
+    TRAINERS_GEN_1.iter().filter(|t| name_key(t.location).contains(&key)).collect()
+}
+
+/// Finds trainers by name, ignoring case, spaces and punctuation.
+///
+/// # Example
+/// ```
+/// use engine::find_trainers_by_name_gen_1;
+///
+/// let surge = find_trainers_by_name_gen_1("lt surge");
+/// assert_eq!(surge[0].party.len(), 3);
+/// ```
+pub fn find_trainers_by_name_gen_1(name: &str) -> Vec<&'static TrainerGen1> {
+    let key = name_key(name);
+    TRAINERS_GEN_1.iter().filter(|t| name_key(t.name) == key).collect()
+}
+
+/// Finds every trainer of a class.
+pub fn find_trainers_by_class_gen_1(class: TrainerClassGen1) -> Vec<&'static TrainerGen1> {
+    TRAINERS_GEN_1.iter().filter(|t| t.class == class).collect()
+}
+
+// ================= Trainer Data =================
+
+const fn mon(species: &'static str, level: u8, moves: &'static [&'static str]) -> TrainerPokemonGen1 {
+    TrainerPokemonGen1 { species, level, moves }
+}
+
+const fn trainer(
+    class: TrainerClassGen1,
+    name: &'static str,
+    location: &'static str,
+    version: VersionGen1,
+    variant: Option<&'static str>,
+    party: &'static [TrainerPokemonGen1],
+) -> TrainerGen1 {
+    TrainerGen1 { class, name, location, version, variant, party }
+}
+
+const fn regular(
+    class: TrainerClassGen1,
+    location: &'static str,
+    party: &'static [TrainerPokemonGen1],
+) -> TrainerGen1 {
+    trainer(class, class.name(), location, RedBlue, None, party)
+}
+
+use TrainerClassGen1 as C;
+use VersionGen1::{RedBlue, Yellow};
+
+const CHOSE_BULBASAUR: Option<&str> = Some("Player chose Bulbasaur");
+const CHOSE_CHARMANDER: Option<&str> = Some("Player chose Charmander");
+const CHOSE_SQUIRTLE: Option<&str> = Some("Player chose Squirtle");
+
+// Yellow's rival evolves his Eevee depending on how the player did in the
+// first two rival battles
+const EEVEE_JOLTEON: Option<&str> = Some("Rival's Eevee became Jolteon");
+const EEVEE_FLAREON: Option<&str> = Some("Rival's Eevee became Flareon");
+const EEVEE_VAPOREON: Option<&str> = Some("Rival's Eevee became Vaporeon");
+
+/// Every trainer: the battles that differ between versions, then the
+/// regular trainers, which are the same in Red, Blue and Yellow.
+static TRAINERS_GEN_1: LazyLock<Vec<TrainerGen1>> = LazyLock::new(|| {
+    let regular = [RedBlue, Yellow].into_iter().flat_map(|version| {
+        REGULAR_TRAINERS_GEN_1.iter().map(move |trainer| TrainerGen1 { version, ..*trainer })
+    });
+    BOSS_TRAINERS_GEN_1.iter().copied().chain(regular).collect()
+});
+
+/// Gym leaders, Giovanni, the Elite Four, the Champion, the rival and
+/// Yellow's Jessie & James, whose parties differ between versions.
+static BOSS_TRAINERS_GEN_1: [TrainerGen1; 72] = [
+    // Gym leaders
+    trainer(C::Brock, "Brock", "Pewter Gym", RedBlue, None, &[
+        mon("Geodude", 12, &["Tackle", "Defense Curl"]),
+        mon("Onix", 14, &["Tackle", "Screech", "Bide"]),
+    ]),
+    trainer(C::Misty, "Misty", "Cerulean Gym", RedBlue, None, &[
+        mon("Staryu", 18, &["Tackle", "Water Gun"]),
+        mon("Starmie", 21, &["Tackle", "Water Gun", "Harden", "Bubble Beam"]),
+    ]),
+    trainer(C::LtSurge, "Lt. Surge", "Vermilion Gym", RedBlue, None, &[
+        mon("Voltorb", 21, &["Tackle", "Screech", "Sonic Boom"]),
+        mon("Pikachu", 18, &["Thunder Shock", "Growl", "Thunder Wave", "Quick Attack"]),
+        mon("Raichu", 24, &["Thunder Shock", "Growl", "Thunder Wave", "Thunderbolt"]),
+    ]),
+    trainer(C::Erika, "Erika", "Celadon Gym", RedBlue, None, &[
+        mon("Victreebel", 29, &["Razor Leaf", "Wrap", "Poison Powder", "Sleep Powder"]),
+        mon("Tangela", 24, &["Constrict", "Bind"]),
+        mon("Vileplume", 29, &["Petal Dance", "Poison Powder", "Mega Drain", "Sleep Powder"]),
+    ]),
+    trainer(C::Koga, "Koga", "Fuchsia Gym", RedBlue, None, &[
+        mon("Koffing", 37, &["Tackle", "Smog", "Sludge", "Smokescreen"]),
+        mon("Muk", 39, &["Disable", "Poison Gas", "Minimize", "Sludge"]),
+        mon("Koffing", 37, &["Tackle", "Smog", "Sludge", "Smokescreen"]),
+        mon("Weezing", 43, &["Tackle", "Smog", "Sludge", "Toxic"]),
+    ]),
+    trainer(C::Sabrina, "Sabrina", "Saffron Gym", RedBlue, None, &[
+        mon("Kadabra", 38, &["Disable", "Psybeam", "Recover", "Psychic"]),
+        mon("Mr. Mime", 37, &["Confusion", "Barrier", "Light Screen", "Double Slap"]),
+        mon("Venomoth", 38, &["Poison Powder", "Leech Life", "Stun Spore", "Psybeam"]),
+        mon("Alakazam", 43, &["Psybeam", "Recover", "Psywave", "Reflect"]),
+    ]),
+    trainer(C::Blaine, "Blaine", "Cinnabar Gym", RedBlue, None, &[
+        mon("Growlithe", 42, &["Ember", "Leer", "Take Down", "Agility"]),
+        mon("Ponyta", 40, &["Tail Whip", "Stomp", "Growl", "Fire Spin"]),
+        mon("Rapidash", 42, &["Tail Whip", "Stomp", "Growl", "Fire Spin"]),
+        mon("Arcanine", 47, &["Roar", "Ember", "Take Down", "Fire Blast"]),
+    ]),
+    trainer(C::Giovanni, "Giovanni", "Viridian Gym", RedBlue, None, &[
+        mon("Rhyhorn", 45, &["Stomp", "Tail Whip", "Fury Attack", "Horn Drill"]),
+        mon("Dugtrio", 42, &["Growl", "Dig", "Sand Attack", "Slash"]),
+        mon("Nidoqueen", 44, &["Scratch", "Tail Whip", "Body Slam", "Poison Sting"]),
+        mon("Nidoking", 45, &["Tackle", "Horn Attack", "Poison Sting", "Thrash"]),
+        mon("Rhydon", 50, &["Stomp", "Tail Whip", "Fissure", "Horn Drill"]),
+    ]),
+
+    // Team Rocket
+    trainer(C::Giovanni, "Giovanni", "Rocket Hideout B4F", RedBlue, None, &[
+        mon("Onix", 25, &["Screech", "Bind", "Rock Throw", "Rage"]),
+        mon("Rhyhorn", 24, &["Horn Attack"]),
+        mon("Kangaskhan", 29, &["Comet Punch", "Rage", "Bite"]),
+    ]),
+    trainer(C::Giovanni, "Giovanni", "Silph Co. 11F", RedBlue, None, &[
+        mon("Nidorino", 37, &["Double Kick", "Poison Sting", "Focus Energy", "Fury Attack"]),
+        mon("Kangaskhan", 35, &["Comet Punch", "Rage", "Bite", "Tail Whip"]),
+        mon("Rhyhorn", 37, &["Horn Attack", "Stomp", "Tail Whip"]),
+        mon("Nidoqueen", 41, &["Tackle", "Scratch", "Tail Whip", "Body Slam"]),
+    ]),
+
+    // Elite Four and Champion
+    trainer(C::Lorelei, "Lorelei", "Indigo Plateau", RedBlue, None, &[
+        mon("Dewgong", 54, &["Growl", "Aurora Beam", "Rest", "Take Down"]),
+        mon("Cloyster", 53, &["Supersonic", "Clamp", "Aurora Beam", "Spike Cannon"]),
+        mon("Slowbro", 54, &["Growl", "Water Gun", "Withdraw", "Amnesia"]),
+        mon("Jynx", 56, &["Double Slap", "Ice Punch", "Body Slam", "Thrash"]),
+        mon("Lapras", 56, &["Body Slam", "Confuse Ray", "Hydro Pump", "Blizzard"]),
+    ]),
+    trainer(C::Bruno, "Bruno", "Indigo Plateau", RedBlue, None, &[
+        mon("Onix", 53, &["Rock Throw", "Rage", "Slam", "Harden"]),
+        mon("Hitmonchan", 55, &["Ice Punch", "Thunder Punch", "Mega Punch", "Counter"]),
+        mon("Hitmonlee", 55, &["Jump Kick", "Focus Energy", "High Jump Kick", "Mega Kick"]),
+        mon("Onix", 56, &["Rock Throw", "Rage", "Slam", "Harden"]),
+        mon("Machamp", 58, &["Leer", "Focus Energy", "Fissure", "Submission"]),
+    ]),
+    trainer(C::Agatha, "Agatha", "Indigo Plateau", RedBlue, None, &[
+        mon("Gengar", 56, &["Confuse Ray", "Night Shade", "Hypnosis", "Dream Eater"]),
+        mon("Golbat", 56, &["Supersonic", "Confuse Ray", "Wing Attack", "Haze"]),
+        mon("Haunter", 55, &["Confuse Ray", "Night Shade", "Hypnosis", "Dream Eater"]),
+        mon("Arbok", 58, &["Bite", "Glare", "Screech", "Acid"]),
+        mon("Gengar", 60, &["Confuse Ray", "Night Shade", "Toxic", "Dream Eater"]),
+    ]),
+    trainer(C::Lance, "Lance", "Indigo Plateau", RedBlue, None, &[
+        mon("Gyarados", 58, &["Hydro Pump", "Dragon Rage", "Leer", "Hyper Beam"]),
+        mon("Dragonair", 56, &["Agility", "Slam", "Dragon Rage", "Hyper Beam"]),
+        mon("Dragonair", 56, &["Agility", "Slam", "Dragon Rage", "Hyper Beam"]),
+        mon("Aerodactyl", 60, &["Supersonic", "Bite", "Take Down", "Hyper Beam"]),
+        mon("Dragonite", 62, &["Agility", "Slam", "Barrier", "Hyper Beam"]),
+    ]),
+    trainer(C::Rival3, "Blue", "Indigo Plateau", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgeot", 61, &["Whirlwind", "Wing Attack", "Sky Attack", "Mirror Move"]),
+        mon("Alakazam", 59, &["Psybeam", "Psychic", "Reflect", "Recover"]),
+        mon("Rhydon", 61, &["Fury Attack", "Horn Drill", "Leer", "Take Down"]),
+        mon("Exeggutor", 61, &["Barrage", "Hypnosis", "Stomp"]),
+        mon("Gyarados", 63, &["Dragon Rage", "Hydro Pump", "Hyper Beam", "Leer"]),
+        mon("Charizard", 65, &["Fire Blast", "Rage", "Slash", "Fire Spin"]),
+    ]),
+    trainer(C::Rival3, "Blue", "Indigo Plateau", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgeot", 61, &["Whirlwind", "Wing Attack", "Sky Attack", "Mirror Move"]),
+        mon("Alakazam", 59, &["Psybeam", "Psychic", "Reflect", "Recover"]),
+        mon("Rhydon", 61, &["Fury Attack", "Horn Drill", "Leer", "Take Down"]),
+        mon("Arcanine", 61, &["Roar", "Ember", "Leer", "Take Down"]),
+        mon("Exeggutor", 63, &["Barrage", "Hypnosis", "Stomp"]),
+        mon("Blastoise", 65, &["Hydro Pump", "Blizzard", "Bite", "Withdraw"]),
+    ]),
+    trainer(C::Rival3, "Blue", "Indigo Plateau", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgeot", 61, &["Whirlwind", "Wing Attack", "Sky Attack", "Mirror Move"]),
+        mon("Alakazam", 59, &["Psybeam", "Psychic", "Reflect", "Recover"]),
+        mon("Rhydon", 61, &["Fury Attack", "Horn Drill", "Leer", "Take Down"]),
+        mon("Gyarados", 61, &["Dragon Rage", "Hydro Pump", "Hyper Beam", "Leer"]),
+        mon("Arcanine", 63, &["Roar", "Ember", "Leer", "Take Down"]),
+        mon("Venusaur", 65, &["Razor Leaf", "Growth", "Mega Drain", "Solar Beam"]),
+    ]),
+
+    // Rival battles
+    trainer(C::Rival1, "Blue", "Oak's Lab", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Charmander", 5, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Oak's Lab", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Squirtle", 5, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Oak's Lab", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Bulbasaur", 5, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Route 22 (first battle)", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgey", 9, &[]),
+        mon("Charmander", 8, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Route 22 (first battle)", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgey", 9, &[]),
+        mon("Squirtle", 8, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Route 22 (first battle)", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgey", 9, &[]),
+        mon("Bulbasaur", 8, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Cerulean City", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgeotto", 18, &[]),
+        mon("Abra", 15, &[]),
+        mon("Rattata", 15, &[]),
+        mon("Charmander", 17, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Cerulean City", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgeotto", 18, &[]),
+        mon("Abra", 15, &[]),
+        mon("Rattata", 15, &[]),
+        mon("Squirtle", 17, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Cerulean City", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgeotto", 18, &[]),
+        mon("Abra", 15, &[]),
+        mon("Rattata", 15, &[]),
+        mon("Bulbasaur", 17, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "S.S. Anne", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgeotto", 19, &[]),
+        mon("Raticate", 16, &[]),
+        mon("Kadabra", 18, &[]),
+        mon("Charmeleon", 20, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "S.S. Anne", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgeotto", 19, &[]),
+        mon("Raticate", 16, &[]),
+        mon("Kadabra", 18, &[]),
+        mon("Wartortle", 20, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "S.S. Anne", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgeotto", 19, &[]),
+        mon("Raticate", 16, &[]),
+        mon("Kadabra", 18, &[]),
+        mon("Ivysaur", 20, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Pokémon Tower 2F", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgeotto", 25, &[]),
+        mon("Exeggcute", 23, &[]),
+        mon("Gyarados", 22, &[]),
+        mon("Kadabra", 20, &[]),
+        mon("Charmeleon", 25, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Pokémon Tower 2F", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgeotto", 25, &[]),
+        mon("Growlithe", 23, &[]),
+        mon("Exeggcute", 22, &[]),
+        mon("Kadabra", 20, &[]),
+        mon("Wartortle", 25, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Pokémon Tower 2F", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgeotto", 25, &[]),
+        mon("Gyarados", 23, &[]),
+        mon("Growlithe", 22, &[]),
+        mon("Kadabra", 20, &[]),
+        mon("Ivysaur", 25, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Silph Co. 7F", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgeot", 37, &[]),
+        mon("Exeggcute", 38, &[]),
+        mon("Gyarados", 35, &[]),
+        mon("Alakazam", 35, &[]),
+        mon("Charizard", 40, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Silph Co. 7F", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgeot", 37, &[]),
+        mon("Growlithe", 38, &[]),
+        mon("Exeggcute", 35, &[]),
+        mon("Alakazam", 35, &[]),
+        mon("Blastoise", 40, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Silph Co. 7F", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgeot", 37, &[]),
+        mon("Gyarados", 38, &[]),
+        mon("Growlithe", 35, &[]),
+        mon("Alakazam", 35, &[]),
+        mon("Venusaur", 40, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Route 22 (second battle)", RedBlue, CHOSE_BULBASAUR, &[
+        mon("Pidgeot", 47, &[]),
+        mon("Rhyhorn", 45, &[]),
+        mon("Exeggcute", 45, &[]),
+        mon("Gyarados", 47, &[]),
+        mon("Alakazam", 50, &[]),
+        mon("Charizard", 53, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Route 22 (second battle)", RedBlue, CHOSE_CHARMANDER, &[
+        mon("Pidgeot", 47, &[]),
+        mon("Rhyhorn", 45, &[]),
+        mon("Growlithe", 45, &[]),
+        mon("Exeggcute", 47, &[]),
+        mon("Alakazam", 50, &[]),
+        mon("Blastoise", 53, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Route 22 (second battle)", RedBlue, CHOSE_SQUIRTLE, &[
+        mon("Pidgeot", 47, &[]),
+        mon("Rhyhorn", 45, &[]),
+        mon("Gyarados", 45, &[]),
+        mon("Growlithe", 47, &[]),
+        mon("Alakazam", 50, &[]),
+        mon("Venusaur", 53, &[]),
+    ]),
+
+    // Yellow gym leaders, Elite Four and Champion. Yellow gives the gym
+    // leaders and Elite Four custom moves on top of their level-up sets.
+    trainer(C::Brock, "Brock", "Pewter Gym", Yellow, None, &[
+        mon("Geodude", 10, &["Tackle"]),
+        mon("Onix", 12, &["Tackle", "Screech", "Bind", "Bide"]),
+    ]),
+    trainer(C::Misty, "Misty", "Cerulean Gym", Yellow, None, &[
+        mon("Staryu", 18, &["Tackle", "Water Gun"]),
+        mon("Starmie", 21, &["Tackle", "Water Gun", "Harden", "Bubble Beam"]),
+    ]),
+    trainer(C::LtSurge, "Lt. Surge", "Vermilion Gym", Yellow, None, &[
+        mon("Raichu", 28, &["Thunderbolt", "Mega Punch", "Mega Kick", "Growl"]),
+    ]),
+    trainer(C::Erika, "Erika", "Celadon Gym", Yellow, None, &[
+        mon("Tangela", 30, &["Constrict", "Bind", "Mega Drain"]),
+        mon("Weepinbell", 32, &["Razor Leaf", "Sleep Powder", "Stun Spore", "Acid"]),
+        mon("Gloom", 32, &["Petal Dance", "Stun Spore", "Sleep Powder", "Acid"]),
+    ]),
+    trainer(C::Koga, "Koga", "Fuchsia Gym", Yellow, None, &[
+        mon("Venonat", 44, &["Toxic", "Psybeam", "Sleep Powder", "Psychic"]),
+        mon("Venonat", 46, &["Toxic", "Psybeam", "Sleep Powder", "Psychic"]),
+        mon("Venonat", 48, &["Toxic", "Psybeam", "Sleep Powder", "Psychic"]),
+        mon("Venomoth", 50, &["Toxic", "Psybeam", "Sleep Powder", "Psychic"]),
+    ]),
+    trainer(C::Sabrina, "Sabrina", "Saffron Gym", Yellow, None, &[
+        mon("Abra", 50, &["Teleport", "Flash"]),
+        mon("Kadabra", 50, &["Psybeam", "Recover", "Kinesis", "Reflect"]),
+        mon("Alakazam", 50, &["Psybeam", "Recover", "Psywave", "Reflect"]),
+    ]),
+    trainer(C::Blaine, "Blaine", "Cinnabar Gym", Yellow, None, &[
+        mon("Ninetales", 48, &["Flamethrower", "Tail Whip", "Quick Attack", "Roar"]),
+        mon("Rapidash", 50, &["Stomp", "Growl", "Fire Blast", "Take Down"]),
+        mon("Arcanine", 54, &["Roar", "Ember", "Reflect", "Take Down"]),
+    ]),
+    trainer(C::Giovanni, "Giovanni", "Viridian Gym", Yellow, None, &[
+        mon("Dugtrio", 50, &["Fissure", "Sand Attack", "Slash", "Earthquake"]),
+        mon("Persian", 53, &["Double Team", "Pay Day", "Fury Swipes", "Slash"]),
+        mon("Nidoqueen", 53, &["Earthquake", "Body Slam", "Double Kick", "Poison Sting"]),
+        mon("Nidoking", 55, &["Thunder", "Poison Sting", "Thrash", "Double Kick"]),
+        mon("Rhydon", 55, &["Rock Slide", "Fury Attack", "Horn Drill", "Leer"]),
+    ]),
+    trainer(C::Lorelei, "Lorelei", "Indigo Plateau", Yellow, None, &[
+        mon("Dewgong", 54, &["Bubble Beam", "Aurora Beam", "Rest", "Take Down"]),
+        mon("Cloyster", 53, &["Supersonic", "Clamp", "Ice Beam", "Spike Cannon"]),
+        mon("Slowbro", 54, &["Psychic", "Surf", "Withdraw", "Amnesia"]),
+        mon("Jynx", 56, &["Lovely Kiss", "Ice Punch", "Body Slam", "Thrash"]),
+        mon("Lapras", 56, &["Body Slam", "Confuse Ray", "Blizzard", "Hydro Pump"]),
+    ]),
+    trainer(C::Bruno, "Bruno", "Indigo Plateau", Yellow, None, &[
+        mon("Onix", 53, &["Rock Throw", "Rage", "Slam", "Harden"]),
+        mon("Hitmonchan", 55, &["Ice Punch", "Thunder Punch", "Mega Punch", "Counter"]),
+        mon("Hitmonlee", 55, &["Jump Kick", "Focus Energy", "High Jump Kick", "Mega Kick"]),
+        mon("Onix", 56, &["Rock Throw", "Rage", "Slam", "Harden"]),
+        mon("Machamp", 58, &["Leer", "Focus Energy", "Fissure", "Submission"]),
+    ]),
+    trainer(C::Agatha, "Agatha", "Indigo Plateau", Yellow, None, &[
+        mon("Gengar", 56, &["Substitute", "Lick", "Mega Drain", "Dream Eater"]),
+        mon("Golbat", 56, &["Supersonic", "Leech Life", "Wing Attack", "Toxic"]),
+        mon("Haunter", 55, &["Confuse Ray", "Night Shade", "Hypnosis", "Dream Eater"]),
+        mon("Arbok", 58, &["Bite", "Glare", "Screech", "Acid"]),
+        mon("Gengar", 60, &["Confuse Ray", "Night Shade", "Hypnosis", "Psychic"]),
+    ]),
+    trainer(C::Lance, "Lance", "Indigo Plateau", Yellow, None, &[
+        mon("Gyarados", 58, &["Hydro Pump", "Dragon Rage", "Leer", "Hyper Beam"]),
+        mon("Dragonair", 56, &["Agility", "Thunder Wave", "Thunderbolt", "Hyper Beam"]),
+        mon("Dragonair", 56, &["Agility", "Bubble Beam", "Ice Beam", "Hyper Beam"]),
+        mon("Aerodactyl", 60, &["Supersonic", "Wing Attack", "Swift", "Hyper Beam"]),
+        mon("Dragonite", 62, &["Blizzard", "Fire Blast", "Thunder", "Hyper Beam"]),
+    ]),
+    trainer(C::Rival3, "Blue", "Indigo Plateau", Yellow, EEVEE_JOLTEON, &[
+        mon("Sandslash", 61, &[]),
+        mon("Alakazam", 59, &[]),
+        mon("Exeggutor", 61, &[]),
+        mon("Cloyster", 61, &[]),
+        mon("Ninetales", 63, &[]),
+        mon("Jolteon", 65, &[]),
+    ]),
+    trainer(C::Rival3, "Blue", "Indigo Plateau", Yellow, EEVEE_FLAREON, &[
+        mon("Sandslash", 61, &[]),
+        mon("Alakazam", 59, &[]),
+        mon("Exeggutor", 61, &[]),
+        mon("Magneton", 61, &[]),
+        mon("Cloyster", 63, &[]),
+        mon("Flareon", 65, &[]),
+    ]),
+    trainer(C::Rival3, "Blue", "Indigo Plateau", Yellow, EEVEE_VAPOREON, &[
+        mon("Sandslash", 61, &[]),
+        mon("Alakazam", 59, &[]),
+        mon("Magneton", 61, &[]),
+        mon("Ninetales", 61, &[]),
+        mon("Exeggutor", 63, &[]),
+        mon("Vaporeon", 65, &[]),
+    ]),
+
+    // Yellow's Team Rocket: Giovanni and Jessie & James, who replace some
+    // of the Rocket grunts
+    trainer(C::Giovanni, "Giovanni", "Rocket Hideout B4F", Yellow, None, &[
+        mon("Onix", 25, &[]),
+        mon("Rhyhorn", 24, &[]),
+        mon("Persian", 29, &[]),
+    ]),
+    trainer(C::Giovanni, "Giovanni", "Silph Co. 11F", Yellow, None, &[
+        mon("Nidorino", 37, &[]),
+        mon("Persian", 35, &[]),
+        mon("Rhyhorn", 37, &[]),
+        mon("Nidoqueen", 41, &[]),
+    ]),
+    trainer(C::Rocket, "Jessie & James", "Mt. Moon B2F", Yellow, None, &[
+        mon("Ekans", 14, &[]),
+        mon("Meowth", 14, &[]),
+        mon("Koffing", 14, &[]),
+    ]),
+    trainer(C::Rocket, "Jessie & James", "Rocket Hideout B4F", Yellow, None, &[
+        mon("Ekans", 25, &[]),
+        mon("Meowth", 25, &[]),
+        mon("Koffing", 25, &[]),
+    ]),
+    trainer(C::Rocket, "Jessie & James", "Pokémon Tower 7F", Yellow, None, &[
+        mon("Ekans", 27, &[]),
+        mon("Meowth", 27, &[]),
+        mon("Koffing", 27, &[]),
+    ]),
+    trainer(C::Rocket, "Jessie & James", "Silph Co. 11F", Yellow, None, &[
+        mon("Arbok", 31, &[]),
+        mon("Meowth", 31, &[]),
+        mon("Weezing", 31, &[]),
+    ]),
+
+    // Yellow rival battles. The rival keeps his Eevee until it evolves
+    // before the Pokémon Tower battle
+    trainer(C::Rival1, "Blue", "Oak's Lab", Yellow, None, &[
+        mon("Eevee", 5, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Route 22 (first battle)", Yellow, None, &[
+        mon("Spearow", 9, &[]),
+        mon("Eevee", 8, &[]),
+    ]),
+    trainer(C::Rival1, "Blue", "Cerulean City", Yellow, None, &[
+        mon("Spearow", 18, &[]),
+        mon("Sandshrew", 15, &[]),
+        mon("Rattata", 15, &[]),
+        mon("Eevee", 17, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "S.S. Anne", Yellow, None, &[
+        mon("Spearow", 19, &[]),
+        mon("Rattata", 16, &[]),
+        mon("Sandshrew", 18, &[]),
+        mon("Eevee", 20, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Pokémon Tower 2F", Yellow, EEVEE_JOLTEON, &[
+        mon("Fearow", 25, &[]),
+        mon("Shellder", 23, &[]),
+        mon("Vulpix", 22, &[]),
+        mon("Sandshrew", 20, &[]),
+        mon("Kadabra", 20, &[]),
+        mon("Jolteon", 25, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Pokémon Tower 2F", Yellow, EEVEE_FLAREON, &[
+        mon("Fearow", 25, &[]),
+        mon("Magnemite", 23, &[]),
+        mon("Shellder", 22, &[]),
+        mon("Sandshrew", 20, &[]),
+        mon("Kadabra", 20, &[]),
+        mon("Flareon", 25, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Pokémon Tower 2F", Yellow, EEVEE_VAPOREON, &[
+        mon("Fearow", 25, &[]),
+        mon("Magnemite", 23, &[]),
+        mon("Vulpix", 22, &[]),
+        mon("Sandshrew", 20, &[]),
+        mon("Kadabra", 20, &[]),
+        mon("Vaporeon", 25, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Silph Co. 7F", Yellow, EEVEE_JOLTEON, &[
+        mon("Fearow", 38, &[]),
+        mon("Cloyster", 35, &[]),
+        mon("Ninetales", 35, &[]),
+        mon("Sandslash", 35, &[]),
+        mon("Alakazam", 35, &[]),
+        mon("Jolteon", 40, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Silph Co. 7F", Yellow, EEVEE_FLAREON, &[
+        mon("Fearow", 38, &[]),
+        mon("Magneton", 35, &[]),
+        mon("Cloyster", 35, &[]),
+        mon("Sandslash", 35, &[]),
+        mon("Alakazam", 35, &[]),
+        mon("Flareon", 40, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Silph Co. 7F", Yellow, EEVEE_VAPOREON, &[
+        mon("Fearow", 38, &[]),
+        mon("Magneton", 35, &[]),
+        mon("Ninetales", 35, &[]),
+        mon("Sandslash", 35, &[]),
+        mon("Alakazam", 35, &[]),
+        mon("Vaporeon", 40, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Route 22 (second battle)", Yellow, EEVEE_JOLTEON, &[
+        mon("Sandslash", 47, &[]),
+        mon("Exeggcute", 45, &[]),
+        mon("Cloyster", 45, &[]),
+        mon("Ninetales", 47, &[]),
+        mon("Alakazam", 50, &[]),
+        mon("Jolteon", 53, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Route 22 (second battle)", Yellow, EEVEE_FLAREON, &[
+        mon("Sandslash", 47, &[]),
+        mon("Exeggcute", 45, &[]),
+        mon("Magneton", 45, &[]),
+        mon("Cloyster", 47, &[]),
+        mon("Alakazam", 50, &[]),
+        mon("Flareon", 53, &[]),
+    ]),
+    trainer(C::Rival2, "Blue", "Route 22 (second battle)", Yellow, EEVEE_VAPOREON, &[
+        mon("Sandslash", 47, &[]),
+        mon("Exeggcute", 45, &[]),
+        mon("Magneton", 45, &[]),
+        mon("Ninetales", 47, &[]),
+        mon("Alakazam", 50, &[]),
+        mon("Vaporeon", 53, &[]),
+    ]),
+];
+
+/// Route, gym and Team Rocket trainers. They're listed under Red/Blue and
+/// copied for Yellow.
+static REGULAR_TRAINERS_GEN_1: [TrainerGen1; 297] = [
+    // Viridian Forest and Pewter City
+    regular(C::BugCatcher, "Viridian Forest", &[
+        mon("Weedle", 6, &[]),
+        mon("Caterpie", 6, &[]),
+    ]),
+    regular(C::BugCatcher, "Viridian Forest", &[
+        mon("Weedle", 7, &[]),
+        mon("Kakuna", 7, &[]),
+        mon("Weedle", 7, &[]),
+    ]),
+    regular(C::BugCatcher, "Viridian Forest", &[
+        mon("Weedle", 9, &[]),
+    ]),
+    regular(C::JrTrainerM, "Pewter Gym", &[
+        mon("Diglett", 11, &[]),
+        mon("Sandshrew", 11, &[]),
+    ]),
+
+    // Route 3 and Mt. Moon
+    regular(C::Youngster, "Route 3", &[
+        mon("Rattata", 11, &[]),
+        mon("Ekans", 11, &[]),
+    ]),
+    regular(C::Youngster, "Route 3", &[
+        mon("Spearow", 14, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 3", &[
+        mon("Caterpie", 10, &[]),
+        mon("Weedle", 10, &[]),
+        mon("Caterpie", 10, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 3", &[
+        mon("Weedle", 9, &[]),
+        mon("Kakuna", 9, &[]),
+        mon("Caterpie", 9, &[]),
+        mon("Metapod", 9, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 3", &[
+        mon("Caterpie", 11, &[]),
+        mon("Metapod", 11, &[]),
+    ]),
+    regular(C::Lass, "Route 3", &[
+        mon("Pidgey", 9, &[]),
+        mon("Pidgey", 9, &[]),
+    ]),
+    regular(C::Lass, "Route 3", &[
+        mon("Rattata", 10, &[]),
+        mon("Nidoran♂", 10, &[]),
+    ]),
+    regular(C::Lass, "Route 3", &[
+        mon("Jigglypuff", 14, &[]),
+    ]),
+    regular(C::Youngster, "Mt. Moon 1F", &[
+        mon("Rattata", 10, &[]),
+        mon("Rattata", 10, &[]),
+        mon("Zubat", 10, &[]),
+    ]),
+    regular(C::BugCatcher, "Mt. Moon 1F", &[
+        mon("Weedle", 11, &[]),
+        mon("Kakuna", 11, &[]),
+    ]),
+    regular(C::BugCatcher, "Mt. Moon 1F", &[
+        mon("Caterpie", 10, &[]),
+        mon("Metapod", 10, &[]),
+        mon("Caterpie", 10, &[]),
+    ]),
+    regular(C::Lass, "Mt. Moon 1F", &[
+        mon("Oddish", 11, &[]),
+        mon("Bellsprout", 11, &[]),
+    ]),
+    regular(C::Lass, "Mt. Moon 1F", &[
+        mon("Clefairy", 14, &[]),
+    ]),
+    regular(C::SuperNerd, "Mt. Moon 1F", &[
+        mon("Magnemite", 11, &[]),
+        mon("Voltorb", 11, &[]),
+    ]),
+    regular(C::Hiker, "Mt. Moon 1F", &[
+        mon("Geodude", 10, &[]),
+        mon("Geodude", 10, &[]),
+        mon("Onix", 10, &[]),
+    ]),
+    regular(C::Rocket, "Mt. Moon B2F", &[
+        mon("Rattata", 13, &[]),
+        mon("Zubat", 13, &[]),
+    ]),
+    regular(C::Rocket, "Mt. Moon B2F", &[
+        mon("Sandshrew", 11, &[]),
+        mon("Rattata", 11, &[]),
+        mon("Zubat", 11, &[]),
+    ]),
+    regular(C::Rocket, "Mt. Moon B2F", &[
+        mon("Zubat", 12, &[]),
+        mon("Ekans", 12, &[]),
+    ]),
+    regular(C::Rocket, "Mt. Moon B2F", &[
+        mon("Raticate", 16, &[]),
+    ]),
+    regular(C::SuperNerd, "Mt. Moon B2F", &[
+        mon("Grimer", 12, &[]),
+        mon("Voltorb", 12, &[]),
+        mon("Koffing", 12, &[]),
+    ]),
+
+    // Cerulean City, Routes 24 and 25
+    regular(C::Swimmer, "Cerulean Gym", &[
+        mon("Horsea", 16, &[]),
+        mon("Shellder", 16, &[]),
+    ]),
+    regular(C::JrTrainerF, "Cerulean Gym", &[
+        mon("Goldeen", 19, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 24", &[
+        mon("Caterpie", 14, &[]),
+        mon("Weedle", 14, &[]),
+    ]),
+    regular(C::Lass, "Route 24", &[
+        mon("Pidgey", 14, &[]),
+        mon("Nidoran♀", 14, &[]),
+    ]),
+    regular(C::Youngster, "Route 24", &[
+        mon("Rattata", 14, &[]),
+        mon("Ekans", 14, &[]),
+        mon("Zubat", 14, &[]),
+    ]),
+    regular(C::Lass, "Route 24", &[
+        mon("Pidgey", 16, &[]),
+        mon("Nidoran♀", 16, &[]),
+    ]),
+    regular(C::JrTrainerM, "Route 24", &[
+        mon("Mankey", 18, &[]),
+    ]),
+    regular(C::Rocket, "Route 24", &[
+        mon("Ekans", 15, &[]),
+        mon("Zubat", 15, &[]),
+    ]),
+    regular(C::Youngster, "Route 25", &[
+        mon("Rattata", 15, &[]),
+        mon("Spearow", 15, &[]),
+    ]),
+    regular(C::Youngster, "Route 25", &[
+        mon("Slowpoke", 17, &[]),
+    ]),
+    regular(C::Youngster, "Route 25", &[
+        mon("Ekans", 14, &[]),
+        mon("Sandshrew", 14, &[]),
+    ]),
+    regular(C::Hiker, "Route 25", &[
+        mon("Machop", 15, &[]),
+        mon("Geodude", 15, &[]),
+    ]),
+    regular(C::Hiker, "Route 25", &[
+        mon("Geodude", 13, &[]),
+        mon("Geodude", 13, &[]),
+        mon("Machop", 13, &[]),
+        mon("Geodude", 13, &[]),
+    ]),
+    regular(C::Hiker, "Route 25", &[
+        mon("Onix", 17, &[]),
+    ]),
+    regular(C::Lass, "Route 25", &[
+        mon("Nidoran♂", 15, &[]),
+        mon("Nidoran♀", 15, &[]),
+    ]),
+    regular(C::Lass, "Route 25", &[
+        mon("Oddish", 13, &[]),
+        mon("Pidgey", 13, &[]),
+        mon("Oddish", 13, &[]),
+    ]),
+    regular(C::Rocket, "Cerulean City", &[
+        mon("Machop", 17, &[]),
+        mon("Drowzee", 17, &[]),
+    ]),
+
+    // Routes 5 to 11, the S.S. Anne and Vermilion City
+    regular(C::BugCatcher, "Route 6", &[
+        mon("Weedle", 16, &[]),
+        mon("Caterpie", 16, &[]),
+        mon("Weedle", 16, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 6", &[
+        mon("Butterfree", 20, &[]),
+    ]),
+    regular(C::JrTrainerM, "Route 6", &[
+        mon("Squirtle", 20, &[]),
+    ]),
+    regular(C::JrTrainerM, "Route 6", &[
+        mon("Spearow", 16, &[]),
+        mon("Raticate", 16, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 6", &[
+        mon("Rattata", 16, &[]),
+        mon("Pikachu", 16, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 6", &[
+        mon("Pidgey", 16, &[]),
+        mon("Pidgey", 16, &[]),
+        mon("Pidgey", 16, &[]),
+    ]),
+    regular(C::Youngster, "S.S. Anne", &[
+        mon("Nidoran♂", 21, &[]),
+    ]),
+    regular(C::Gentleman, "S.S. Anne", &[
+        mon("Growlithe", 18, &[]),
+        mon("Growlithe", 18, &[]),
+    ]),
+    regular(C::Gentleman, "S.S. Anne", &[
+        mon("Nidoran♂", 19, &[]),
+        mon("Nidoran♀", 19, &[]),
+    ]),
+    regular(C::Gentleman, "S.S. Anne", &[
+        mon("Pikachu", 23, &[]),
+    ]),
+    regular(C::Lass, "S.S. Anne", &[
+        mon("Pidgey", 18, &[]),
+        mon("Nidoran♀", 18, &[]),
+    ]),
+    regular(C::Lass, "S.S. Anne", &[
+        mon("Jigglypuff", 20, &[]),
+    ]),
+    regular(C::Sailor, "S.S. Anne", &[
+        mon("Machop", 18, &[]),
+        mon("Shellder", 18, &[]),
+    ]),
+    regular(C::Sailor, "S.S. Anne", &[
+        mon("Machop", 17, &[]),
+        mon("Tentacool", 17, &[]),
+    ]),
+    regular(C::Sailor, "S.S. Anne", &[
+        mon("Shellder", 21, &[]),
+    ]),
+    regular(C::Sailor, "S.S. Anne", &[
+        mon("Horsea", 17, &[]),
+        mon("Shellder", 17, &[]),
+        mon("Tentacool", 17, &[]),
+    ]),
+    regular(C::Sailor, "S.S. Anne", &[
+        mon("Tentacool", 18, &[]),
+        mon("Staryu", 18, &[]),
+    ]),
+    regular(C::Sailor, "S.S. Anne", &[
+        mon("Machop", 20, &[]),
+    ]),
+    regular(C::Fisher, "S.S. Anne", &[
+        mon("Goldeen", 17, &[]),
+        mon("Tentacool", 17, &[]),
+        mon("Goldeen", 17, &[]),
+    ]),
+    regular(C::Fisher, "S.S. Anne", &[
+        mon("Tentacool", 17, &[]),
+        mon("Staryu", 17, &[]),
+        mon("Shellder", 17, &[]),
+    ]),
+    regular(C::Gentleman, "Vermilion Gym", &[
+        mon("Pikachu", 23, &[]),
+    ]),
+    regular(C::Rocker, "Vermilion Gym", &[
+        mon("Voltorb", 20, &[]),
+        mon("Magnemite", 20, &[]),
+        mon("Voltorb", 20, &[]),
+    ]),
+    regular(C::Sailor, "Vermilion Gym", &[
+        mon("Pikachu", 21, &[]),
+        mon("Pikachu", 21, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 9", &[
+        mon("Beedrill", 19, &[]),
+        mon("Beedrill", 19, &[]),
+    ]),
+    regular(C::BugCatcher, "Route 9", &[
+        mon("Caterpie", 20, &[]),
+        mon("Weedle", 20, &[]),
+        mon("Venonat", 20, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 9", &[
+        mon("Oddish", 18, &[]),
+        mon("Bellsprout", 18, &[]),
+        mon("Oddish", 18, &[]),
+        mon("Bellsprout", 18, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 9", &[
+        mon("Meowth", 23, &[]),
+    ]),
+    regular(C::JrTrainerM, "Route 9", &[
+        mon("Growlithe", 21, &[]),
+        mon("Charmander", 21, &[]),
+    ]),
+    regular(C::JrTrainerM, "Route 9", &[
+        mon("Rattata", 19, &[]),
+        mon("Diglett", 19, &[]),
+        mon("Ekans", 19, &[]),
+        mon("Sandshrew", 19, &[]),
+    ]),
+    regular(C::Hiker, "Route 9", &[
+        mon("Machop", 20, &[]),
+        mon("Onix", 20, &[]),
+    ]),
+    regular(C::Hiker, "Route 9", &[
+        mon("Geodude", 21, &[]),
+        mon("Onix", 21, &[]),
+    ]),
+    regular(C::Hiker, "Route 9", &[
+        mon("Geodude", 15, &[]),
+        mon("Geodude", 15, &[]),
+        mon("Machop", 15, &[]),
+        mon("Geodude", 15, &[]),
+    ]),
+    regular(C::Pokemaniac, "Route 10", &[
+        mon("Rhyhorn", 30, &[]),
+        mon("Lickitung", 30, &[]),
+    ]),
+    regular(C::Pokemaniac, "Route 10", &[
+        mon("Cubone", 20, &[]),
+        mon("Slowpoke", 20, &[]),
+    ]),
+    regular(C::Hiker, "Route 10", &[
+        mon("Onix", 19, &[]),
+    ]),
+    regular(C::Hiker, "Route 10", &[
+        mon("Machop", 20, &[]),
+        mon("Onix", 20, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 10", &[
+        mon("Pikachu", 20, &[]),
+        mon("Clefairy", 20, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 10", &[
+        mon("Pidgey", 21, &[]),
+        mon("Pidgeotto", 21, &[]),
+    ]),
+    regular(C::Youngster, "Route 11", &[
+        mon("Ekans", 21, &[]),
+    ]),
+    regular(C::Youngster, "Route 11", &[
+        mon("Sandshrew", 19, &[]),
+        mon("Zubat", 19, &[]),
+    ]),
+    regular(C::Youngster, "Route 11", &[
+        mon("Rattata", 17, &[]),
+        mon("Rattata", 17, &[]),
+        mon("Raticate", 17, &[]),
+    ]),
+    regular(C::Youngster, "Route 11", &[
+        mon("Nidoran♂", 18, &[]),
+        mon("Nidorino", 18, &[]),
+    ]),
+    regular(C::Gambler, "Route 11", &[
+        mon("Poliwag", 18, &[]),
+        mon("Horsea", 18, &[]),
+    ]),
+    regular(C::Gambler, "Route 11", &[
+        mon("Bellsprout", 18, &[]),
+        mon("Oddish", 18, &[]),
+    ]),
+    regular(C::Gambler, "Route 11", &[
+        mon("Voltorb", 18, &[]),
+        mon("Magnemite", 18, &[]),
+    ]),
+    regular(C::Gambler, "Route 11", &[
+        mon("Growlithe", 18, &[]),
+        mon("Vulpix", 18, &[]),
+    ]),
+    regular(C::Engineer, "Route 11", &[
+        mon("Magnemite", 21, &[]),
+    ]),
+    regular(C::Engineer, "Route 11", &[
+        mon("Magnemite", 18, &[]),
+        mon("Magnemite", 18, &[]),
+        mon("Magneton", 18, &[]),
+    ]),
+
+    // Rock Tunnel, Lavender Town and Celadon City
+    regular(C::Pokemaniac, "Rock Tunnel", &[
+        mon("Charmander", 20, &[]),
+        mon("Cubone", 20, &[]),
+    ]),
+    regular(C::Pokemaniac, "Rock Tunnel", &[
+        mon("Slowpoke", 25, &[]),
+    ]),
+    regular(C::Pokemaniac, "Rock Tunnel", &[
+        mon("Cubone", 23, &[]),
+        mon("Slowpoke", 23, &[]),
+    ]),
+    regular(C::Hiker, "Rock Tunnel", &[
+        mon("Geodude", 19, &[]),
+        mon("Machop", 19, &[]),
+        mon("Geodude", 19, &[]),
+        mon("Geodude", 19, &[]),
+    ]),
+    regular(C::Hiker, "Rock Tunnel", &[
+        mon("Onix", 20, &[]),
+        mon("Onix", 20, &[]),
+        mon("Geodude", 20, &[]),
+    ]),
+    regular(C::Hiker, "Rock Tunnel", &[
+        mon("Geodude", 21, &[]),
+        mon("Graveler", 21, &[]),
+    ]),
+    regular(C::Hiker, "Rock Tunnel", &[
+        mon("Geodude", 25, &[]),
+    ]),
+    regular(C::JrTrainerF, "Rock Tunnel", &[
+        mon("Bellsprout", 22, &[]),
+        mon("Clefairy", 22, &[]),
+    ]),
+    regular(C::JrTrainerF, "Rock Tunnel", &[
+        mon("Meowth", 20, &[]),
+        mon("Oddish", 20, &[]),
+        mon("Pidgey", 20, &[]),
+    ]),
+    regular(C::JrTrainerF, "Rock Tunnel", &[
+        mon("Pidgey", 19, &[]),
+        mon("Rattata", 19, &[]),
+        mon("Rattata", 19, &[]),
+        mon("Bellsprout", 19, &[]),
+    ]),
+    regular(C::JrTrainerF, "Rock Tunnel", &[
+        mon("Jigglypuff", 22, &[]),
+        mon("Pidgey", 22, &[]),
+        mon("Meowth", 22, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 3F", &[
+        mon("Gastly", 23, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 3F", &[
+        mon("Gastly", 24, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 3F", &[
+        mon("Gastly", 22, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 4F", &[
+        mon("Gastly", 23, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 4F", &[
+        mon("Gastly", 22, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 4F", &[
+        mon("Gastly", 24, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 5F", &[
+        mon("Gastly", 23, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 5F", &[
+        mon("Gastly", 24, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 5F", &[
+        mon("Gastly", 22, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 5F", &[
+        mon("Haunter", 23, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 6F", &[
+        mon("Gastly", 22, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 6F", &[
+        mon("Gastly", 24, &[]),
+    ]),
+    regular(C::Channeler, "Pokémon Tower 6F", &[
+        mon("Gastly", 24, &[]),
+    ]),
+    regular(C::Rocket, "Pokémon Tower 7F", &[
+        mon("Zubat", 25, &[]),
+        mon("Zubat", 25, &[]),
+        mon("Golbat", 25, &[]),
+    ]),
+    regular(C::Rocket, "Pokémon Tower 7F", &[
+        mon("Koffing", 26, &[]),
+        mon("Drowzee", 26, &[]),
+    ]),
+    regular(C::Rocket, "Pokémon Tower 7F", &[
+        mon("Zubat", 23, &[]),
+        mon("Rattata", 23, &[]),
+        mon("Raticate", 23, &[]),
+        mon("Zubat", 23, &[]),
+    ]),
+    regular(C::Lass, "Celadon Gym", &[
+        mon("Bellsprout", 23, &[]),
+        mon("Weepinbell", 23, &[]),
+    ]),
+    regular(C::Lass, "Celadon Gym", &[
+        mon("Oddish", 23, &[]),
+        mon("Ivysaur", 23, &[]),
+    ]),
+    regular(C::Beauty, "Celadon Gym", &[
+        mon("Oddish", 21, &[]),
+        mon("Bellsprout", 21, &[]),
+        mon("Oddish", 21, &[]),
+        mon("Bellsprout", 21, &[]),
+    ]),
+    regular(C::Beauty, "Celadon Gym", &[
+        mon("Bellsprout", 24, &[]),
+        mon("Bellsprout", 24, &[]),
+    ]),
+    regular(C::Beauty, "Celadon Gym", &[
+        mon("Exeggcute", 26, &[]),
+    ]),
+    regular(C::JrTrainerF, "Celadon Gym", &[
+        mon("Bulbasaur", 24, &[]),
+        mon("Ivysaur", 24, &[]),
+    ]),
+    regular(C::CooltrainerF, "Celadon Gym", &[
+        mon("Weepinbell", 24, &[]),
+        mon("Gloom", 24, &[]),
+        mon("Ivysaur", 24, &[]),
+    ]),
+    regular(C::Rocket, "Celadon Game Corner", &[
+        mon("Raticate", 20, &[]),
+        mon("Zubat", 20, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B1F", &[
+        mon("Drowzee", 21, &[]),
+        mon("Machop", 21, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B1F", &[
+        mon("Rattata", 21, &[]),
+        mon("Raticate", 21, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B1F", &[
+        mon("Grimer", 20, &[]),
+        mon("Koffing", 20, &[]),
+        mon("Koffing", 20, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B1F", &[
+        mon("Rattata", 19, &[]),
+        mon("Raticate", 19, &[]),
+        mon("Raticate", 19, &[]),
+        mon("Rattata", 19, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B1F", &[
+        mon("Grimer", 22, &[]),
+        mon("Koffing", 22, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B2F", &[
+        mon("Zubat", 17, &[]),
+        mon("Koffing", 17, &[]),
+        mon("Grimer", 17, &[]),
+        mon("Zubat", 17, &[]),
+        mon("Raticate", 17, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B3F", &[
+        mon("Rattata", 20, &[]),
+        mon("Raticate", 20, &[]),
+        mon("Drowzee", 20, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B3F", &[
+        mon("Machop", 21, &[]),
+        mon("Machop", 21, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B4F", &[
+        mon("Sandshrew", 23, &[]),
+        mon("Ekans", 23, &[]),
+        mon("Sandslash", 23, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B4F", &[
+        mon("Ekans", 23, &[]),
+        mon("Sandshrew", 23, &[]),
+        mon("Arbok", 23, &[]),
+    ]),
+    regular(C::Rocket, "Rocket Hideout B4F", &[
+        mon("Koffing", 21, &[]),
+        mon("Zubat", 21, &[]),
+    ]),
+
+    // Routes 12 to 18 and Fuchsia City
+    regular(C::Fisher, "Route 12", &[
+        mon("Goldeen", 22, &[]),
+        mon("Poliwag", 22, &[]),
+        mon("Goldeen", 22, &[]),
+    ]),
+    regular(C::Fisher, "Route 12", &[
+        mon("Tentacool", 24, &[]),
+        mon("Goldeen", 24, &[]),
+    ]),
+    regular(C::Fisher, "Route 12", &[
+        mon("Goldeen", 27, &[]),
+    ]),
+    regular(C::Fisher, "Route 12", &[
+        mon("Poliwag", 21, &[]),
+        mon("Shellder", 21, &[]),
+        mon("Goldeen", 21, &[]),
+        mon("Horsea", 21, &[]),
+    ]),
+    regular(C::Fisher, "Route 12", &[
+        mon("Magikarp", 28, &[]),
+        mon("Magikarp", 28, &[]),
+        mon("Magikarp", 28, &[]),
+        mon("Magikarp", 28, &[]),
+    ]),
+    regular(C::Rocker, "Route 12", &[
+        mon("Voltorb", 29, &[]),
+        mon("Electrode", 29, &[]),
+    ]),
+    regular(C::JrTrainerM, "Route 12", &[
+        mon("Nidoran♂", 29, &[]),
+        mon("Nidorino", 29, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 13", &[
+        mon("Pidgey", 29, &[]),
+        mon("Pidgeotto", 29, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 13", &[
+        mon("Spearow", 25, &[]),
+        mon("Pidgey", 25, &[]),
+        mon("Pidgey", 25, &[]),
+        mon("Spearow", 25, &[]),
+        mon("Spearow", 25, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 13", &[
+        mon("Pidgeotto", 26, &[]),
+        mon("Farfetch'd", 26, &[]),
+    ]),
+    regular(C::Beauty, "Route 13", &[
+        mon("Rattata", 27, &[]),
+        mon("Pikachu", 27, &[]),
+        mon("Rattata", 27, &[]),
+    ]),
+    regular(C::Beauty, "Route 13", &[
+        mon("Clefairy", 29, &[]),
+        mon("Meowth", 29, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 13", &[
+        mon("Pidgey", 24, &[]),
+        mon("Meowth", 24, &[]),
+        mon("Rattata", 24, &[]),
+        mon("Pikachu", 24, &[]),
+        mon("Meowth", 24, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 13", &[
+        mon("Poliwag", 30, &[]),
+        mon("Poliwag", 30, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 13", &[
+        mon("Pidgey", 27, &[]),
+        mon("Meowth", 27, &[]),
+        mon("Pidgey", 27, &[]),
+        mon("Pidgeotto", 27, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 13", &[
+        mon("Goldeen", 28, &[]),
+        mon("Poliwag", 28, &[]),
+        mon("Horsea", 28, &[]),
+    ]),
+    regular(C::Biker, "Route 13", &[
+        mon("Koffing", 28, &[]),
+        mon("Koffing", 28, &[]),
+        mon("Koffing", 28, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 14", &[
+        mon("Pidgey", 28, &[]),
+        mon("Doduo", 28, &[]),
+        mon("Pidgeotto", 28, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 14", &[
+        mon("Spearow", 26, &[]),
+        mon("Spearow", 26, &[]),
+        mon("Fearow", 26, &[]),
+        mon("Spearow", 26, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 14", &[
+        mon("Pidgey", 29, &[]),
+        mon("Pidgeotto", 29, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 14", &[
+        mon("Farfetch'd", 33, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 14", &[
+        mon("Spearow", 29, &[]),
+        mon("Fearow", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 14", &[
+        mon("Koffing", 29, &[]),
+        mon("Muk", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 14", &[
+        mon("Koffing", 29, &[]),
+        mon("Grimer", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 14", &[
+        mon("Grimer", 28, &[]),
+        mon("Grimer", 28, &[]),
+        mon("Koffing", 28, &[]),
+    ]),
+    regular(C::Biker, "Route 14", &[
+        mon("Koffing", 26, &[]),
+        mon("Koffing", 26, &[]),
+        mon("Grimer", 26, &[]),
+        mon("Koffing", 26, &[]),
+    ]),
+    regular(C::Beauty, "Route 15", &[
+        mon("Pidgeotto", 29, &[]),
+        mon("Wigglytuff", 29, &[]),
+    ]),
+    regular(C::Beauty, "Route 15", &[
+        mon("Bulbasaur", 29, &[]),
+        mon("Ivysaur", 29, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 15", &[
+        mon("Gloom", 28, &[]),
+        mon("Oddish", 28, &[]),
+        mon("Oddish", 28, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 15", &[
+        mon("Pikachu", 29, &[]),
+        mon("Raichu", 29, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 15", &[
+        mon("Clefairy", 33, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 15", &[
+        mon("Bellsprout", 29, &[]),
+        mon("Oddish", 29, &[]),
+        mon("Tangela", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 15", &[
+        mon("Koffing", 28, &[]),
+        mon("Koffing", 28, &[]),
+        mon("Weezing", 28, &[]),
+    ]),
+    regular(C::Biker, "Route 15", &[
+        mon("Koffing", 25, &[]),
+        mon("Koffing", 25, &[]),
+        mon("Weezing", 25, &[]),
+        mon("Koffing", 25, &[]),
+        mon("Grimer", 25, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 15", &[
+        mon("Dodrio", 28, &[]),
+        mon("Doduo", 28, &[]),
+        mon("Doduo", 28, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 15", &[
+        mon("Pidgeotto", 26, &[]),
+        mon("Farfetch'd", 26, &[]),
+        mon("Doduo", 26, &[]),
+        mon("Pidgey", 26, &[]),
+    ]),
+    regular(C::Biker, "Route 16", &[
+        mon("Grimer", 28, &[]),
+        mon("Grimer", 28, &[]),
+        mon("Koffing", 28, &[]),
+    ]),
+    regular(C::Biker, "Route 16", &[
+        mon("Koffing", 29, &[]),
+        mon("Grimer", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 16", &[
+        mon("Weezing", 33, &[]),
+    ]),
+    regular(C::CueBall, "Route 16", &[
+        mon("Machop", 28, &[]),
+        mon("Mankey", 28, &[]),
+        mon("Machop", 28, &[]),
+    ]),
+    regular(C::CueBall, "Route 16", &[
+        mon("Mankey", 29, &[]),
+        mon("Machop", 29, &[]),
+    ]),
+    regular(C::CueBall, "Route 16", &[
+        mon("Machop", 33, &[]),
+    ]),
+    regular(C::Biker, "Route 17", &[
+        mon("Weezing", 28, &[]),
+        mon("Koffing", 28, &[]),
+        mon("Weezing", 28, &[]),
+    ]),
+    regular(C::Biker, "Route 17", &[
+        mon("Muk", 33, &[]),
+    ]),
+    regular(C::Biker, "Route 17", &[
+        mon("Voltorb", 29, &[]),
+        mon("Voltorb", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 17", &[
+        mon("Grimer", 29, &[]),
+        mon("Koffing", 29, &[]),
+    ]),
+    regular(C::Biker, "Route 17", &[
+        mon("Koffing", 25, &[]),
+        mon("Weezing", 25, &[]),
+        mon("Koffing", 25, &[]),
+        mon("Koffing", 25, &[]),
+        mon("Weezing", 25, &[]),
+    ]),
+    regular(C::CueBall, "Route 17", &[
+        mon("Mankey", 29, &[]),
+        mon("Primeape", 29, &[]),
+    ]),
+    regular(C::CueBall, "Route 17", &[
+        mon("Machop", 29, &[]),
+        mon("Machoke", 29, &[]),
+    ]),
+    regular(C::CueBall, "Route 17", &[
+        mon("Machoke", 33, &[]),
+    ]),
+    regular(C::CueBall, "Route 17", &[
+        mon("Mankey", 26, &[]),
+        mon("Mankey", 26, &[]),
+        mon("Machoke", 26, &[]),
+        mon("Machop", 26, &[]),
+    ]),
+    regular(C::CueBall, "Route 17", &[
+        mon("Primeape", 29, &[]),
+        mon("Machoke", 29, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 18", &[
+        mon("Spearow", 29, &[]),
+        mon("Fearow", 29, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 18", &[
+        mon("Spearow", 34, &[]),
+        mon("Dodrio", 34, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 18", &[
+        mon("Dodrio", 26, &[]),
+        mon("Doduo", 26, &[]),
+        mon("Doduo", 26, &[]),
+    ]),
+    regular(C::Juggler, "Fuchsia Gym", &[
+        mon("Kadabra", 34, &[]),
+        mon("Mr. Mime", 34, &[]),
+    ]),
+    regular(C::Juggler, "Fuchsia Gym", &[
+        mon("Hypno", 38, &[]),
+    ]),
+    regular(C::Juggler, "Fuchsia Gym", &[
+        mon("Drowzee", 31, &[]),
+        mon("Drowzee", 31, &[]),
+        mon("Kadabra", 31, &[]),
+        mon("Drowzee", 31, &[]),
+    ]),
+    regular(C::Juggler, "Fuchsia Gym", &[
+        mon("Drowzee", 34, &[]),
+        mon("Hypno", 34, &[]),
+    ]),
+    regular(C::Tamer, "Fuchsia Gym", &[
+        mon("Sandslash", 34, &[]),
+        mon("Arbok", 34, &[]),
+    ]),
+    regular(C::Tamer, "Fuchsia Gym", &[
+        mon("Arbok", 33, &[]),
+        mon("Sandslash", 33, &[]),
+        mon("Arbok", 33, &[]),
+    ]),
+
+    // Saffron City and Silph Co.
+    regular(C::Blackbelt, "Fighting Dojo", &[
+        mon("Mankey", 31, &[]),
+        mon("Mankey", 31, &[]),
+        mon("Primeape", 31, &[]),
+    ]),
+    regular(C::Blackbelt, "Fighting Dojo", &[
+        mon("Machop", 32, &[]),
+        mon("Machoke", 32, &[]),
+    ]),
+    regular(C::Blackbelt, "Fighting Dojo", &[
+        mon("Primeape", 36, &[]),
+    ]),
+    regular(C::Blackbelt, "Fighting Dojo", &[
+        mon("Machop", 31, &[]),
+        mon("Mankey", 31, &[]),
+        mon("Primeape", 31, &[]),
+    ]),
+    regular(C::Blackbelt, "Fighting Dojo", &[
+        mon("Hitmonlee", 37, &[]),
+        mon("Hitmonchan", 37, &[]),
+    ]),
+    regular(C::Psychic, "Saffron Gym", &[
+        mon("Kadabra", 31, &[]),
+        mon("Slowpoke", 31, &[]),
+        mon("Mr. Mime", 31, &[]),
+        mon("Kadabra", 31, &[]),
+    ]),
+    regular(C::Psychic, "Saffron Gym", &[
+        mon("Mr. Mime", 34, &[]),
+        mon("Kadabra", 34, &[]),
+    ]),
+    regular(C::Psychic, "Saffron Gym", &[
+        mon("Slowpoke", 33, &[]),
+        mon("Slowpoke", 33, &[]),
+        mon("Slowbro", 33, &[]),
+    ]),
+    regular(C::Psychic, "Saffron Gym", &[
+        mon("Slowbro", 38, &[]),
+    ]),
+    regular(C::Channeler, "Saffron Gym", &[
+        mon("Gastly", 33, &[]),
+    ]),
+    regular(C::Channeler, "Saffron Gym", &[
+        mon("Haunter", 38, &[]),
+    ]),
+    regular(C::Channeler, "Saffron Gym", &[
+        mon("Gastly", 34, &[]),
+        mon("Haunter", 34, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 2F", &[
+        mon("Golbat", 26, &[]),
+        mon("Zubat", 26, &[]),
+        mon("Zubat", 26, &[]),
+        mon("Raticate", 26, &[]),
+        mon("Zubat", 26, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 2F", &[
+        mon("Cubone", 29, &[]),
+        mon("Zubat", 29, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 2F", &[
+        mon("Grimer", 29, &[]),
+        mon("Electrode", 29, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 2F", &[
+        mon("Voltorb", 28, &[]),
+        mon("Koffing", 28, &[]),
+        mon("Magneton", 28, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 3F", &[
+        mon("Raticate", 28, &[]),
+        mon("Hypno", 28, &[]),
+        mon("Raticate", 28, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 3F", &[
+        mon("Electrode", 29, &[]),
+        mon("Weezing", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 4F", &[
+        mon("Machop", 29, &[]),
+        mon("Drowzee", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 4F", &[
+        mon("Ekans", 28, &[]),
+        mon("Zubat", 28, &[]),
+        mon("Cubone", 28, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 4F", &[
+        mon("Grimer", 33, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 5F", &[
+        mon("Arbok", 33, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 5F", &[
+        mon("Hypno", 33, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 5F", &[
+        mon("Magnemite", 26, &[]),
+        mon("Koffing", 26, &[]),
+        mon("Weezing", 26, &[]),
+        mon("Magnemite", 26, &[]),
+    ]),
+    regular(C::Juggler, "Silph Co. 5F", &[
+        mon("Kadabra", 29, &[]),
+        mon("Mr. Mime", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 6F", &[
+        mon("Machop", 29, &[]),
+        mon("Machoke", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 6F", &[
+        mon("Zubat", 28, &[]),
+        mon("Zubat", 28, &[]),
+        mon("Golbat", 28, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 6F", &[
+        mon("Magneton", 29, &[]),
+        mon("Koffing", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 7F", &[
+        mon("Raticate", 26, &[]),
+        mon("Arbok", 26, &[]),
+        mon("Koffing", 26, &[]),
+        mon("Golbat", 26, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 7F", &[
+        mon("Cubone", 29, &[]),
+        mon("Cubone", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 7F", &[
+        mon("Sandshrew", 29, &[]),
+        mon("Sandslash", 29, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 7F", &[
+        mon("Magnemite", 25, &[]),
+        mon("Voltorb", 25, &[]),
+        mon("Koffing", 25, &[]),
+        mon("Magneton", 25, &[]),
+        mon("Magnemite", 25, &[]),
+        mon("Koffing", 25, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 8F", &[
+        mon("Raticate", 28, &[]),
+        mon("Golbat", 28, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 8F", &[
+        mon("Machop", 28, &[]),
+        mon("Drowzee", 28, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 8F", &[
+        mon("Voltorb", 29, &[]),
+        mon("Magnemite", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 9F", &[
+        mon("Drowzee", 28, &[]),
+        mon("Grimer", 28, &[]),
+        mon("Machop", 28, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 9F", &[
+        mon("Golbat", 28, &[]),
+        mon("Drowzee", 28, &[]),
+        mon("Hypno", 28, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 9F", &[
+        mon("Magneton", 28, &[]),
+        mon("Electrode", 28, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 10F", &[
+        mon("Machoke", 33, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 10F", &[
+        mon("Voltorb", 29, &[]),
+        mon("Koffing", 29, &[]),
+        mon("Magnemite", 29, &[]),
+    ]),
+    regular(C::Rocket, "Silph Co. 11F", &[
+        mon("Cubone", 32, &[]),
+        mon("Drowzee", 32, &[]),
+        mon("Marowak", 32, &[]),
+    ]),
+    regular(C::Scientist, "Silph Co. 11F", &[
+        mon("Grimer", 32, &[]),
+        mon("Weezing", 32, &[]),
+    ]),
+
+    // Routes 19 to 21, Cinnabar Island and Viridian City
+    regular(C::Swimmer, "Route 19", &[
+        mon("Goldeen", 30, &[]),
+        mon("Horsea", 30, &[]),
+        mon("Staryu", 30, &[]),
+    ]),
+    regular(C::Swimmer, "Route 19", &[
+        mon("Horsea", 29, &[]),
+        mon("Goldeen", 29, &[]),
+        mon("Shellder", 29, &[]),
+        mon("Goldeen", 29, &[]),
+    ]),
+    regular(C::Swimmer, "Route 19", &[
+        mon("Poliwag", 30, &[]),
+        mon("Poliwhirl", 30, &[]),
+    ]),
+    regular(C::Swimmer, "Route 19", &[
+        mon("Horsea", 27, &[]),
+        mon("Tentacool", 27, &[]),
+        mon("Tentacool", 27, &[]),
+        mon("Goldeen", 27, &[]),
+    ]),
+    regular(C::Swimmer, "Route 19", &[
+        mon("Goldeen", 29, &[]),
+        mon("Shellder", 29, &[]),
+        mon("Seaking", 29, &[]),
+    ]),
+    regular(C::Swimmer, "Route 19", &[
+        mon("Horsea", 30, &[]),
+        mon("Horsea", 30, &[]),
+    ]),
+    regular(C::Swimmer, "Route 19", &[
+        mon("Tentacool", 27, &[]),
+        mon("Tentacool", 27, &[]),
+        mon("Staryu", 27, &[]),
+        mon("Horsea", 27, &[]),
+        mon("Tentacruel", 27, &[]),
+    ]),
+    regular(C::Beauty, "Route 19", &[
+        mon("Goldeen", 30, &[]),
+        mon("Seaking", 30, &[]),
+    ]),
+    regular(C::Beauty, "Route 19", &[
+        mon("Poliwag", 29, &[]),
+        mon("Goldeen", 29, &[]),
+        mon("Seaking", 29, &[]),
+        mon("Goldeen", 29, &[]),
+        mon("Poliwag", 29, &[]),
+    ]),
+    regular(C::Beauty, "Route 19", &[
+        mon("Staryu", 27, &[]),
+        mon("Staryu", 27, &[]),
+        mon("Staryu", 27, &[]),
+    ]),
+    regular(C::Swimmer, "Route 20", &[
+        mon("Shellder", 31, &[]),
+        mon("Cloyster", 31, &[]),
+    ]),
+    regular(C::Swimmer, "Route 20", &[
+        mon("Staryu", 35, &[]),
+    ]),
+    regular(C::Swimmer, "Route 20", &[
+        mon("Seadra", 28, &[]),
+        mon("Horsea", 28, &[]),
+        mon("Seadra", 28, &[]),
+    ]),
+    regular(C::Beauty, "Route 20", &[
+        mon("Goldeen", 30, &[]),
+        mon("Seaking", 30, &[]),
+    ]),
+    regular(C::Beauty, "Route 20", &[
+        mon("Seadra", 31, &[]),
+        mon("Horsea", 31, &[]),
+    ]),
+    regular(C::Beauty, "Route 20", &[
+        mon("Shellder", 35, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 20", &[
+        mon("Tentacool", 31, &[]),
+        mon("Horsea", 31, &[]),
+    ]),
+    regular(C::JrTrainerF, "Route 20", &[
+        mon("Seel", 30, &[]),
+    ]),
+    regular(C::BirdKeeper, "Route 20", &[
+        mon("Fearow", 30, &[]),
+        mon("Fearow", 30, &[]),
+        mon("Pidgeotto", 30, &[]),
+    ]),
+    regular(C::Fisher, "Route 21", &[
+        mon("Seaking", 28, &[]),
+        mon("Goldeen", 28, &[]),
+        mon("Seaking", 28, &[]),
+        mon("Seaking", 28, &[]),
+    ]),
+    regular(C::Fisher, "Route 21", &[
+        mon("Shellder", 31, &[]),
+        mon("Cloyster", 31, &[]),
+    ]),
+    regular(C::Fisher, "Route 21", &[
+        mon("Magikarp", 27, &[]),
+        mon("Magikarp", 27, &[]),
+        mon("Magikarp", 27, &[]),
+        mon("Magikarp", 27, &[]),
+        mon("Magikarp", 27, &[]),
+        mon("Magikarp", 27, &[]),
+    ]),
+    regular(C::Fisher, "Route 21", &[
+        mon("Seadra", 33, &[]),
+        mon("Tentacruel", 33, &[]),
+    ]),
+    regular(C::Swimmer, "Route 21", &[
+        mon("Starmie", 37, &[]),
+    ]),
+    regular(C::Swimmer, "Route 21", &[
+        mon("Tentacool", 31, &[]),
+        mon("Tentacool", 31, &[]),
+        mon("Tentacruel", 31, &[]),
+    ]),
+    regular(C::Swimmer, "Route 21", &[
+        mon("Staryu", 33, &[]),
+        mon("Wartortle", 33, &[]),
+    ]),
+    regular(C::Swimmer, "Route 21", &[
+        mon("Poliwhirl", 32, &[]),
+        mon("Tentacool", 32, &[]),
+        mon("Seadra", 32, &[]),
+    ]),
+    regular(C::Swimmer, "Route 21", &[
+        mon("Seel", 33, &[]),
+    ]),
+    regular(C::Burglar, "Pokémon Mansion", &[
+        mon("Charmander", 34, &[]),
+        mon("Charmeleon", 34, &[]),
+    ]),
+    regular(C::Burglar, "Pokémon Mansion", &[
+        mon("Ninetales", 38, &[]),
+    ]),
+    regular(C::Burglar, "Pokémon Mansion", &[
+        mon("Growlithe", 34, &[]),
+        mon("Ponyta", 34, &[]),
+    ]),
+    regular(C::Scientist, "Pokémon Mansion", &[
+        mon("Magnemite", 33, &[]),
+        mon("Magneton", 33, &[]),
+        mon("Voltorb", 33, &[]),
+    ]),
+    regular(C::Scientist, "Pokémon Mansion", &[
+        mon("Electrode", 34, &[]),
+        mon("Weezing", 34, &[]),
+    ]),
+    regular(C::SuperNerd, "Cinnabar Gym", &[
+        mon("Vulpix", 36, &[]),
+        mon("Vulpix", 36, &[]),
+        mon("Ninetales", 36, &[]),
+    ]),
+    regular(C::SuperNerd, "Cinnabar Gym", &[
+        mon("Ponyta", 34, &[]),
+        mon("Charmander", 34, &[]),
+        mon("Vulpix", 34, &[]),
+        mon("Growlithe", 34, &[]),
+    ]),
+    regular(C::SuperNerd, "Cinnabar Gym", &[
+        mon("Rapidash", 41, &[]),
+    ]),
+    regular(C::SuperNerd, "Cinnabar Gym", &[
+        mon("Growlithe", 37, &[]),
+        mon("Vulpix", 37, &[]),
+    ]),
+    regular(C::Burglar, "Cinnabar Gym", &[
+        mon("Growlithe", 36, &[]),
+        mon("Vulpix", 36, &[]),
+        mon("Ninetales", 36, &[]),
+    ]),
+    regular(C::Burglar, "Cinnabar Gym", &[
+        mon("Ponyta", 41, &[]),
+    ]),
+    regular(C::Burglar, "Cinnabar Gym", &[
+        mon("Vulpix", 37, &[]),
+        mon("Growlithe", 37, &[]),
+    ]),
+    regular(C::CooltrainerM, "Viridian Gym", &[
+        mon("Nidorino", 39, &[]),
+        mon("Nidoking", 39, &[]),
+    ]),
+    regular(C::CooltrainerM, "Viridian Gym", &[
+        mon("Sandslash", 43, &[]),
+        mon("Dugtrio", 43, &[]),
+    ]),
+    regular(C::CooltrainerM, "Viridian Gym", &[
+        mon("Rhyhorn", 45, &[]),
+    ]),
+    regular(C::Tamer, "Viridian Gym", &[
+        mon("Rhyhorn", 43, &[]),
+    ]),
+    regular(C::Tamer, "Viridian Gym", &[
+        mon("Arbok", 39, &[]),
+        mon("Tauros", 39, &[]),
+    ]),
+    regular(C::Blackbelt, "Viridian Gym", &[
+        mon("Machoke", 43, &[]),
+    ]),
+    regular(C::Blackbelt, "Viridian Gym", &[
+        mon("Machop", 38, &[]),
+        mon("Machoke", 38, &[]),
+        mon("Machop", 38, &[]),
+    ]),
+    regular(C::Blackbelt, "Viridian Gym", &[
+        mon("Machoke", 40, &[]),
+        mon("Machop", 40, &[]),
+    ]),
+
+    // Victory Road
+    regular(C::CooltrainerM, "Victory Road 1F", &[
+        mon("Electrode", 42, &[]),
+        mon("Golbat", 42, &[]),
+    ]),
+    regular(C::CooltrainerF, "Victory Road 1F", &[
+        mon("Persian", 42, &[]),
+        mon("Ponyta", 42, &[]),
+        mon("Ninetales", 42, &[]),
+    ]),
+    regular(C::Blackbelt, "Victory Road 2F", &[
+        mon("Machoke", 43, &[]),
+        mon("Machop", 43, &[]),
+        mon("Machoke", 43, &[]),
+    ]),
+    regular(C::Juggler, "Victory Road 2F", &[
+        mon("Mr. Mime", 41, &[]),
+        mon("Kadabra", 41, &[]),
+        mon("Hypno", 41, &[]),
+    ]),
+    regular(C::Tamer, "Victory Road 2F", &[
+        mon("Persian", 44, &[]),
+        mon("Golduck", 44, &[]),
+    ]),
+    regular(C::Pokemaniac, "Victory Road 2F", &[
+        mon("Charmeleon", 40, &[]),
+        mon("Lapras", 40, &[]),
+        mon("Lickitung", 40, &[]),
+    ]),
+    regular(C::Juggler, "Victory Road 2F", &[
+        mon("Drowzee", 48, &[]),
+    ]),
+    regular(C::CooltrainerM, "Victory Road 3F", &[
+        mon("Exeggutor", 43, &[]),
+        mon("Cloyster", 43, &[]),
+        mon("Arcanine", 43, &[]),
+    ]),
+    regular(C::CooltrainerM, "Victory Road 3F", &[
+        mon("Kingler", 43, &[]),
+        mon("Tentacruel", 43, &[]),
+        mon("Blastoise", 43, &[]),
+    ]),
+    regular(C::CooltrainerF, "Victory Road 3F", &[
+        mon("Ivysaur", 43, &[]),
+        mon("Wartortle", 43, &[]),
+        mon("Charmeleon", 43, &[]),
+        mon("Charizard", 43, &[]),
+    ]),
+    regular(C::CooltrainerF, "Victory Road 3F", &[
+        mon("Bellsprout", 43, &[]),
+        mon("Weepinbell", 43, &[]),
+        mon("Victreebel", 43, &[]),
+    ]),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every species and move in the database resolves.
+    #[test]
+    fn test_data_resolves() {
+        for trainer in trainers_gen_1() {
+            assert!(!trainer.party.is_empty());
+            for pokemon in trainer.party {
+                let battler = pokemon.battler(trainer.version);
+                assert!(pokemon.moves.is_empty() || battler.moves.len() == pokemon.moves.len());
+                assert!((1..=4).contains(&battler.moves.len()), "{} {}", trainer.name, pokemon.species);
+            }
+        }
+    }
+
+    /// Prize money and AI come from the trainer class.
+    #[test]
+    fn test_trainer_queries() {
+        let brock = find_trainers_by_name_gen_1("BROCK");
+        assert_eq!(brock.len(), 2);
+        assert_eq!(brock[0].prize_money(), 99 * 14);
+
+        let champions = find_trainers_by_class_gen_1(TrainerClassGen1::Rival3);
+        assert_eq!(champions.len(), 6);
+        assert!(champions.iter().all(|t| t.location == "Indigo Plateau"));
+        assert_eq!(champions.iter().filter(|t| t.version == Yellow).count(), 3);
+
+        // Yellow's Brock has custom moves, not his Onix's level-up set
+        let onix = brock[1].battlers().pop().unwrap();
+        assert_eq!((brock[1].version, onix.moves[3].name.as_str()), (Yellow, "Bide"));
+
+        let silph_bosses: Vec<_> = find_trainers_by_location_gen_1("silph co").into_iter()
+            .filter(|t| matches!(t.class, C::Giovanni | C::Rival2))
+            .collect();
+        assert_eq!(silph_bosses.len(), 8);
+
+        let misty = &find_trainers_by_name_gen_1("Misty")[0];
+        assert_eq!(misty.ai().class, TrainerClassGen1::Misty);
+        assert_eq!(misty.battlers()[1].pokemon.stats.hp, 59);
+    }
+
+    /// Yellow has its own rival and Giovanni battles, and the regular
+    /// trainers appear in both versions.
+    #[test]
+    fn test_yellow_and_regular_trainers() {
+        let yellow = |name: &str| -> Vec<_> {
+            find_trainers_by_name_gen_1(name).into_iter().filter(|t| t.version == Yellow).collect()
+        };
+        let rival = yellow("Blue");
+        assert_eq!(rival.len(), 16);
+        assert_eq!(rival.iter().find(|t| t.location == "Oak's Lab").unwrap().party[0].species, "Eevee");
+        assert_eq!(rival.iter().filter(|t| t.variant == EEVEE_FLAREON).count(), 4);
+
+        let giovanni = yellow("Giovanni");
+        let locations: Vec<_> = giovanni.iter().map(|t| t.location).collect();
+        assert_eq!(locations, ["Viridian Gym", "Rocket Hideout B4F", "Silph Co. 11F"]);
+        assert_eq!(yellow("Jessie & James").len(), 4);
+
+        // Regular trainers are named after their class and use its AI and
+        // prize money
+        let forest = find_trainers_by_location_gen_1("viridian forest");
+        assert_eq!(forest.len(), 6);
+        assert_eq!(forest.iter().filter(|t| t.version == Yellow).count(), 3);
+        assert_eq!((forest[0].name, forest[0].prize_money()), ("Bug Catcher", 10 * 6));
+        assert_eq!(find_trainers_by_name_gen_1("bug catcher").len(), 2 * 13);
+
+        let gym = find_trainers_by_location_gen_1("pewter gym");
+        assert_eq!(gym.iter().filter(|t| t.class == C::JrTrainerM).count(), 2);
+    }
+}
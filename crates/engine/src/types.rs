@@ -8,6 +8,7 @@
 /// - Psychic is immune to Ghost (bug in original games)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypeGen1 {
     Normal, Fire, Water, Electric, 
     Grass, Ice, Fighting, Poison, 
@@ -21,6 +22,8 @@ impl TypeGen1 {
     ///
     /// # Example
     /// ```
+    /// use engine::TypeGen1;
+    ///
     /// let type_count = TypeGen1::iter().count();
     /// assert_eq!(type_count, 15);
     /// ```
@@ -144,6 +147,8 @@ const IMMUNE: [(TypeGen1, TypeGen1); 6] = [
 ///
 /// # Example
 /// ```
+/// use engine::{TypeGen1, type_effectiveness_gen_1};
+///
 /// let effectiveness = type_effectiveness_gen_1(
 ///     TypeGen1::Water,
 ///     &[TypeGen1::Fire, TypeGen1::Ground]  // Charizard
@@ -0,0 +1,318 @@
+use std::fmt;
+
+use crate::{BattlerGen1, MOVES_GEN_1, SpeciesGen1, StatStagesGen1, StatsGen1, TrainerClassGen1, VersionGen1};
+use crate::dvs::hp_dv_gen_1;
+use crate::pokemon::IVsGen1;
+use crate::save::{species_from_index_gen_1, status_from_byte};
+
+/// Size of the Game Boy's work RAM (`$C000`-`$DFFF`), which is what
+/// emulators dump.
+pub const WRAM_SIZE_GEN_1: usize = 0x2000;
+
+// Addresses in Red and Blue (international), from pokered's WRAM layout.
+// Yellow moves most of these by a byte or two, so its dumps are refused
+// rather than misread.
+const WRAM_START: usize = 0xC000;
+const PLAYER_UNMODIFIED_STATS: usize = 0xCD0F;
+const PLAYER_STAT_MODS: usize = 0xCD1A;
+const ENEMY_UNMODIFIED_STATS: usize = 0xCD23;
+const ENEMY_STAT_MODS: usize = 0xCD2E;
+const ENEMY_MON: usize = 0xCFE5;
+const PLAYER_MON: usize = 0xD014;
+const IS_IN_BATTLE: usize = 0xD057;
+const CUR_OPPONENT: usize = 0xD059;
+const TRAINER_NO: usize = 0xD05D;
+const PLAYER_BATTLE_STATUS: usize = 0xD062;
+const ENEMY_BATTLE_STATUS: usize = 0xD067;
+const PLAYER_ID: usize = 0xD359;
+
+/// Trainer battles set the opponent to the trainer class plus this.
+const TRAINER_OPPONENT: u8 = 200;
+
+/// Battle flags that only last while a Pokémon stays in, from the games'
+/// three battle status bytes.
+///
+/// # Fields
+/// - `bide`: Storing energy with Bide
+/// - `thrashing`: Locked into Thrash or Petal Dance
+/// - `multi_hit`: In the middle of a multi-hit move
+/// - `flinched`: Flinched this turn
+/// - `charging`: Charging a two-turn move (Solar Beam, Sky Attack, ...)
+/// - `trapping`: Using a trapping move (Wrap, Fire Spin, ...)
+/// - `invulnerable`: In the air or underground (Fly, Dig)
+/// - `confused`: Confused
+/// - `x_accuracy`: Under X Accuracy
+/// - `mist`: Protected by Mist
+/// - `focus_energy`: Pumped up by Focus Energy (which quarters the
+///   critical hit rate in Gen 1)
+/// - `substitute`: Behind a Substitute
+/// - `recharging`: Must recharge after Hyper Beam
+/// - `rage`: Locked into Rage
+/// - `seeded`: Seeded by Leech Seed
+/// - `badly_poisoned`: Poisoned by Toxic
+/// - `light_screen`/`reflect`: Has Light Screen or Reflect up
+/// - `transformed`: Transformed into its opponent
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolatileFlagsGen1 {
+    pub bide: bool,
+    pub thrashing: bool,
+    pub multi_hit: bool,
+    pub flinched: bool,
+    pub charging: bool,
+    pub trapping: bool,
+    pub invulnerable: bool,
+    pub confused: bool,
+    pub x_accuracy: bool,
+    pub mist: bool,
+    pub focus_energy: bool,
+    pub substitute: bool,
+    pub recharging: bool,
+    pub rage: bool,
+    pub seeded: bool,
+    pub badly_poisoned: bool,
+    pub light_screen: bool,
+    pub reflect: bool,
+    pub transformed: bool,
+}
+
+/// One side's active Pokémon in a RAM snapshot.
+///
+/// # Fields
+/// - `species`: Its species
+/// - `battler`: The Pokémon as it is in battle, ready for the damage calc:
+///   its stats before stat stages, the stages themselves, status, moves
+///   and current HP
+/// - `pp`: Current PP of each move
+/// - `volatile`: Its volatile battle flags
+#[derive(Debug, Clone)]
+pub struct SnapshotBattlerGen1 {
+    pub species: &'static SpeciesGen1,
+    pub battler: BattlerGen1,
+    pub pp: Vec<u8>,
+    pub volatile: VolatileFlagsGen1,
+}
+
+/// The battle going on when a RAM snapshot was taken.
+///
+/// # Fields
+/// - `player`: The player's active Pokémon
+/// - `enemy`: The opponent's active Pokémon
+/// - `trainer`: The opposing trainer's class and number within the class,
+///   or `None` in a wild battle
+/// - `player_id`: The player's trainer ID
+#[derive(Debug, Clone)]
+pub struct BattleSnapshotGen1 {
+    pub player: SnapshotBattlerGen1,
+    pub enemy: SnapshotBattlerGen1,
+    pub trainer: Option<(TrainerClassGen1, u8)>,
+    pub player_id: u16,
+}
+
+/// Why a RAM snapshot couldn't be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WramErrorGen1 {
+    /// The dump isn't [`WRAM_SIZE_GEN_1`] bytes long
+    WrongSize(usize),
+    /// No battle was going on
+    NotInBattle,
+    /// A species index that isn't a Pokémon
+    UnknownSpecies(u8),
+    /// A move index that isn't a move
+    UnknownMove(u8),
+    /// The dump is from a version whose RAM layout isn't known
+    UnsupportedVersion(VersionGen1),
+}
+
+impl fmt::Display for WramErrorGen1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WrongSize(size) => write!(f, "a WRAM dump is {WRAM_SIZE_GEN_1} bytes, not {size}"),
+            Self::NotInBattle => write!(f, "no battle was going on"),
+            Self::UnknownSpecies(index) => write!(f, "unknown species index {index:#04x}"),
+            Self::UnknownMove(index) => write!(f, "unknown move index {index:#04x}"),
+            Self::UnsupportedVersion(version) => write!(f, "{version:?} RAM dumps aren't supported, only Red and Blue"),
+        }
+    }
+}
+
+impl std::error::Error for WramErrorGen1 {}
+
+impl VolatileFlagsGen1 {
+    /// Decodes the three battle status bytes.
+    pub fn from_bytes([first, second, third]: [u8; 3]) -> Self {
+        let bit = |byte: u8, n: u8| byte & (1 << n) != 0;
+        Self {
+            bide: bit(first, 0),
+            thrashing: bit(first, 1),
+            multi_hit: bit(first, 2),
+            flinched: bit(first, 3),
+            charging: bit(first, 4),
+            trapping: bit(first, 5),
+            invulnerable: bit(first, 6),
+            confused: bit(first, 7),
+            x_accuracy: bit(second, 0),
+            mist: bit(second, 1),
+            focus_energy: bit(second, 2),
+            substitute: bit(second, 4),
+            recharging: bit(second, 5),
+            rage: bit(second, 6),
+            seeded: bit(second, 7),
+            badly_poisoned: bit(third, 0),
+            light_screen: bit(third, 1),
+            reflect: bit(third, 2),
+            transformed: bit(third, 3),
+        }
+    }
+}
+
+/// Reads the battle in progress from a dump of `version`'s work RAM.
+///
+/// # Errors
+/// If the dump is from Yellow, whose addresses differ, is the wrong size,
+/// no battle was going on, or the battlers hold something that isn't a
+/// Pokémon or a move.
+pub fn parse_wram_gen_1(data: &[u8], version: VersionGen1) -> Result<BattleSnapshotGen1, WramErrorGen1> {
+    if version != VersionGen1::RedBlue {
+        return Err(WramErrorGen1::UnsupportedVersion(version));
+    }
+    if data.len() != WRAM_SIZE_GEN_1 {
+        return Err(WramErrorGen1::WrongSize(data.len()));
+    }
+    let at = |address: usize| data[address - WRAM_START];
+    // 0 is no battle and 0xFF a lost one
+    if !matches!(at(IS_IN_BATTLE), 1 | 2) {
+        return Err(WramErrorGen1::NotInBattle);
+    }
+
+    // Class 13 is an unused copy of Juggler, which the engine leaves out
+    let trainer = at(CUR_OPPONENT).checked_sub(TRAINER_OPPONENT)
+        .and_then(|class| match class {
+            0 | 13 => None,
+            1..13 => TrainerClassGen1::iter().nth(class as usize - 1),
+            _ => TrainerClassGen1::iter().nth(class as usize - 2),
+        })
+        .map(|class| (class, at(TRAINER_NO)));
+    Ok(BattleSnapshotGen1 {
+        player: read_battler(data, PLAYER_MON, PLAYER_UNMODIFIED_STATS, PLAYER_STAT_MODS, PLAYER_BATTLE_STATUS)?,
+        enemy: read_battler(data, ENEMY_MON, ENEMY_UNMODIFIED_STATS, ENEMY_STAT_MODS, ENEMY_BATTLE_STATUS)?,
+        trainer,
+        player_id: u16::from_be_bytes([at(PLAYER_ID), at(PLAYER_ID + 1)]),
+    })
+}
+
+/// Reads one side's active Pokémon: its battle struct, its stats before
+/// stat stages, its stat stages and its battle status bytes.
+fn read_battler(
+    data: &[u8],
+    mon: usize,
+    unmodified: usize,
+    mods: usize,
+    battle_status: usize,
+) -> Result<SnapshotBattlerGen1, WramErrorGen1> {
+    let at = |address: usize| data[address - WRAM_START];
+    let word = |address: usize| u16::from_be_bytes([at(address), at(address + 1)]);
+    let species = species_from_index_gen_1(at(mon)).ok_or(WramErrorGen1::UnknownSpecies(at(mon)))?;
+
+    let (dvs_high, dvs_low) = (at(mon + 0x0C), at(mon + 0x0D));
+    let (attack, defense, speed, special) = (dvs_high >> 4, dvs_high & 0x0F, dvs_low >> 4, dvs_low & 0x0F);
+    let ivs = IVsGen1 { hp: hp_dv_gen_1(attack, defense, special, speed), attack, defense, special, speed };
+    let mut pokemon = species.pokemon(at(mon + 0x0E), ivs);
+    pokemon.stats = StatsGen1 {
+        lvl: at(unmodified),
+        hp: word(unmodified + 1),
+        attack: word(unmodified + 3),
+        defense: word(unmodified + 5),
+        speed: word(unmodified + 7),
+        special: word(unmodified + 9),
+    };
+    // Stages are stored as 1-13 with 7 for no change; accuracy and evasion
+    // come after these and have no equivalent in the engine yet
+    let stage = |i: usize| at(mods + i) as i8 - 7;
+    pokemon.stat_stages = StatStagesGen1 { attack: stage(0), defense: stage(1), speed: stage(2), special: stage(3) };
+    pokemon.status = status_from_byte(at(mon + 0x04));
+
+    let mut pp = Vec::new();
+    pokemon.moves.clear();
+    for slot in 0..4 {
+        let index = at(mon + 0x08 + slot);
+        if index == 0 {
+            continue;
+        }
+        let data = MOVES_GEN_1.get(index as usize - 1).ok_or(WramErrorGen1::UnknownMove(index))?;
+        pokemon.moves.push(data.to_move());
+        pp.push(at(mon + 0x19 + slot) & 0x3F);
+    }
+
+    let moves = pokemon.moves.clone();
+    let mut battler = BattlerGen1::new(pokemon, moves, species.base.speed);
+    battler.hp = word(mon + 0x01);
+    let volatile = VolatileFlagsGen1::from_bytes([at(battle_status), at(battle_status + 1), at(battle_status + 2)]);
+    Ok(SnapshotBattlerGen1 { species, battler, pp, volatile })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StatusGen1;
+
+    /// Writes a battler into a WRAM dump: `species` index, HP, status,
+    /// one move, DVs, level and stats all 50, and the given stat mods.
+    fn put_battler(data: &mut [u8], mon: usize, unmodified: usize, mods: usize, species: u8, status: u8, stages: [u8; 4]) {
+        let mut put = |address: usize, bytes: &[u8]| {
+            data[address - WRAM_START..address - WRAM_START + bytes.len()].copy_from_slice(bytes);
+        };
+        put(mon, &[species, 0, 42, 0, status]);
+        put(mon + 0x08, &[85, 0, 0, 0]);
+        put(mon + 0x0C, &[0xFA, 0x98, 50]);
+        put(mon + 0x19, &[0xC0 | 12]);
+        put(unmodified, &[50, 0, 100, 0, 50, 0, 50, 0, 50, 0, 50]);
+        put(mods, &stages);
+    }
+
+    /// Both battlers, their stages and flags, and the trainer come through.
+    #[test]
+    fn test_parse_wram() {
+        let mut data = vec![0; WRAM_SIZE_GEN_1];
+        put_battler(&mut data, PLAYER_MON, PLAYER_UNMODIFIED_STATS, PLAYER_STAT_MODS, 0x54, 0x40, [9, 7, 7, 7]);
+        put_battler(&mut data, ENEMY_MON, ENEMY_UNMODIFIED_STATS, ENEMY_STAT_MODS, 0x22, 0x03, [7, 5, 7, 13]);
+        assert_eq!(parse_wram_gen_1(&data, VersionGen1::RedBlue).unwrap_err(), WramErrorGen1::NotInBattle);
+
+        data[IS_IN_BATTLE - WRAM_START] = 2;
+        data[CUR_OPPONENT - WRAM_START] = TRAINER_OPPONENT + 35;
+        data[TRAINER_NO - WRAM_START] = 1;
+        data[ENEMY_BATTLE_STATUS - WRAM_START..ENEMY_BATTLE_STATUS - WRAM_START + 3].copy_from_slice(&[0x80, 0x10, 0x04]);
+        let snapshot = parse_wram_gen_1(&data, VersionGen1::RedBlue).unwrap();
+
+        let player = &snapshot.player;
+        assert_eq!(player.species.name, "Pikachu");
+        assert_eq!((player.battler.hp, player.battler.pokemon.stats.hp, player.pp.as_slice()), (42, 100, [12].as_slice()));
+        assert_eq!(player.battler.pokemon.stat_stages.attack, 2);
+        assert_eq!(player.battler.pokemon.status, StatusGen1::Paralyzed);
+        assert_eq!(player.battler.pokemon.ivs.hp, 0b1010);
+        assert_eq!(player.battler.moves[0].name, "Thunderbolt");
+        assert_eq!(player.volatile, VolatileFlagsGen1::default());
+
+        let enemy = &snapshot.enemy;
+        assert_eq!(enemy.species.name, "Onix");
+        assert_eq!((enemy.battler.pokemon.stat_stages.defense, enemy.battler.pokemon.stat_stages.special), (-2, 6));
+        assert_eq!(enemy.battler.pokemon.status, StatusGen1::Asleep(3));
+        assert!(enemy.volatile.confused && enemy.volatile.substitute && enemy.volatile.reflect);
+        assert_eq!(snapshot.trainer, Some((TrainerClassGen1::Misty, 1)));
+    }
+
+    /// Stats over 255 come through whole, and Yellow dumps are refused.
+    #[test]
+    fn test_big_stats_and_yellow() {
+        let mut data = vec![0; WRAM_SIZE_GEN_1];
+        put_battler(&mut data, PLAYER_MON, PLAYER_UNMODIFIED_STATS, PLAYER_STAT_MODS, 0x28, 0, [7; 4]);
+        put_battler(&mut data, ENEMY_MON, ENEMY_UNMODIFIED_STATS, ENEMY_STAT_MODS, 0x22, 0, [7; 4]);
+        data[IS_IN_BATTLE - WRAM_START] = 1;
+        let hp = PLAYER_UNMODIFIED_STATS + 1 - WRAM_START;
+        data[hp..hp + 2].copy_from_slice(&600u16.to_be_bytes());
+
+        let snapshot = parse_wram_gen_1(&data, VersionGen1::RedBlue).unwrap();
+        assert_eq!(snapshot.player.battler.pokemon.stats.hp, 600);
+        assert_eq!(parse_wram_gen_1(&data, VersionGen1::Yellow).unwrap_err(), WramErrorGen1::UnsupportedVersion(VersionGen1::Yellow));
+    }
+}
@@ -0,0 +1,23 @@
+//! Nuzlocke run tracking.
+//!
+//! Provides:
+//! - Encounters per area, with the first-encounter rule and dupe skips
+//! - Caught Pokémon in the party, the box or the graveyard
+//! - Causes of death
+//! - Battles won, with the experience and stat experience they give
+//! - Configurable rule sets with presets and a validator
+//! - Soul Link runs, with encounters paired across two players' runs
+//!
+//! Tracked Pokémon are built from the engine's species data, so they can be
+//! fed straight into the damage calculator.
+
+pub mod run;
+pub mod rules;
+pub mod soul_link;
+
+pub use run::{
+    BattleGen1, DeathGen1, EncounterGen1, EncounterOutcomeGen1, RunGen1, StorageGen1, TrackedPokemonGen1,
+    TrackerErrorGen1, PARTY_SIZE,
+};
+pub use rules::{ProposedActionGen1, RuleGen1, RuleSetGen1, ViolationGen1};
+pub use soul_link::{PlayerGen1, SoulLinkGen1, SoulLinkPairGen1};
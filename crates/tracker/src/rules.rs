@@ -0,0 +1,476 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use engine::species::name_key;
+use engine::{DupesClauseGen1, ItemGen1, SpeciesGen1};
+
+use crate::run::{EncounterGen1, EncounterOutcomeGen1, RunGen1, StorageGen1, TrackedPokemonGen1};
+use crate::soul_link::{PlayerGen1, SoulLinkGen1};
+
+/// A Nuzlocke rule that can be broken.
+///
+/// # Variants
+/// - `FirstEncounter`: Only the first encounter in each area may be caught
+/// - `DupesClause`: Encounters of species already caught may be skipped
+/// - `ShinyClause`: Shinies may be caught regardless of the other rules
+/// - `SpeciesClause`: No two Pokémon of the same species may be caught
+/// - `LevelCap`: Nothing may be leveled past the level cap
+/// - `NoBattleItems`: No items may be used in battle
+/// - `SetMode`: No switching when the opponent's Pokémon faints
+/// - `GiftExemption`: Gift Pokémon don't count as an area's encounter
+/// - `Graveyard`: Fainted Pokémon are dead and may not be used again
+/// - `Wedlocke`: Pokémon whose partner has died may not be used
+/// - `SoulLink`: Linked Pokémon live, die and join the party together, and
+///   no two pairs in the party share a primary type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RuleGen1 {
+    FirstEncounter,
+    DupesClause,
+    ShinyClause,
+    SpeciesClause,
+    LevelCap,
+    NoBattleItems,
+    SetMode,
+    GiftExemption,
+    Graveyard,
+    Wedlocke,
+    SoulLink,
+}
+
+/// A broken rule and why it's broken.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViolationGen1 {
+    pub rule: RuleGen1,
+    pub explanation: String,
+}
+
+/// Which optional rules a run is played with. The first encounter rule and
+/// permanent deaths always apply.
+///
+/// # Fields
+/// - `dupes_clause`: How encounters of species already caught may be
+///   skipped, if at all
+/// - `shiny_clause`: Shinies may always be caught
+/// - `species_clause`: No two Pokémon of the same species may be caught
+/// - `level_caps`: Nothing may be leveled past the next boss's ace
+/// - `no_battle_items`: No items in battle
+/// - `set_mode`: No switching when the opponent's Pokémon faints
+/// - `gift_exemption`: Gift Pokémon don't use up their area's encounter
+/// - `wedlocke`: Pokémon are paired, and a Pokémon whose partner dies may
+///   no longer be used
+/// - `soul_link`: Encounters are linked with another player's run
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, GameGen1};
+/// use tracker::{ProposedActionGen1, RuleGen1, RuleSetGen1, RunGen1};
+///
+/// let mut run = RunGen1::new("Red", GameGen1::Red);
+/// let rattata = find_species_gen_1("Rattata").unwrap();
+/// run.catch("Route 1", rattata, 3, "Rat").unwrap();
+///
+/// let pidgey = find_species_gen_1("Pidgey").unwrap();
+/// let catch = ProposedActionGen1::Catch { area: "Route 1", species: pidgey, shiny: false, gift: false };
+/// let violations = RuleSetGen1::classic().validate_action(&run, &catch);
+/// assert_eq!(violations[0].rule, RuleGen1::FirstEncounter);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RuleSetGen1 {
+    pub dupes_clause: Option<DupesClauseGen1>,
+    pub shiny_clause: bool,
+    pub species_clause: bool,
+    pub level_caps: bool,
+    pub no_battle_items: bool,
+    pub set_mode: bool,
+    pub gift_exemption: bool,
+    pub wedlocke: bool,
+    pub soul_link: bool,
+}
+
+/// Something the player is about to do.
+///
+/// # Variants
+/// - `Catch`: Catch a Pokémon in an area
+/// - `SkipDupe`: Skip an encounter under the dupes clause
+/// - `Withdraw`: Put a Pokémon in the party
+/// - `LevelUp`: Level a Pokémon up to `level`
+/// - `UseItemInBattle`: Use an item in battle
+/// - `ShiftAfterKo`: Switch after knocking out the opponent's Pokémon
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProposedActionGen1<'a> {
+    Catch { area: &'a str, species: &'static SpeciesGen1, shiny: bool, gift: bool },
+    SkipDupe { area: &'a str, species: &'static SpeciesGen1 },
+    Withdraw(usize),
+    LevelUp { id: usize, level: u8 },
+    UseItemInBattle(ItemGen1),
+    ShiftAfterKo,
+}
+
+impl fmt::Display for ViolationGen1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.rule, self.explanation)
+    }
+}
+
+impl RuleSetGen1 {
+    /// The original rules plus the common clauses: first encounters only,
+    /// dupes and shiny clauses, and gifts don't count.
+    pub fn classic() -> Self {
+        Self {
+            dupes_clause: Some(DupesClauseGen1::default()),
+            shiny_clause: true,
+            species_clause: false,
+            level_caps: false,
+            no_battle_items: false,
+            set_mode: false,
+            gift_exemption: true,
+            wedlocke: false,
+            soul_link: false,
+        }
+    }
+
+    /// Classic rules plus level caps, set mode and no items in battle.
+    /// Gifts count as their area's encounter.
+    pub fn hardcore() -> Self {
+        Self {
+            level_caps: true,
+            no_battle_items: true,
+            set_mode: true,
+            gift_exemption: false,
+            ..Self::classic()
+        }
+    }
+
+    /// Classic rules with Pokémon paired into couples.
+    ///
+    /// Gen 1 has no genders, so any two Pokémon can be paired.
+    pub fn wedlocke() -> Self {
+        Self { wedlocke: true, ..Self::classic() }
+    }
+
+    /// Classic rules with encounters linked to another player's run.
+    pub fn soul_link() -> Self {
+        Self { soul_link: true, ..Self::classic() }
+    }
+
+    /// Every rule the run has broken so far.
+    pub fn validate_run(&self, run: &RunGen1) -> Vec<ViolationGen1> {
+        let mut violations: Vec<_> = self.encounter_violations(&run.encounters)
+            .into_iter()
+            .map(|(_, violation)| violation)
+            .collect();
+        for pokemon in run.party() {
+            violations.extend(self.party_violations(run, pokemon, pokemon.level));
+        }
+        violations
+    }
+
+    /// Every rule either player has broken so far, including the rules
+    /// that link their runs.
+    ///
+    /// Each player's party must be made of whole pairs: a caught Pokémon
+    /// whose partner died, fled or was never caught can't be used, and a
+    /// pair must be in both parties or neither. No two pairs in the party
+    /// may share a primary type, on either side.
+    pub fn validate_soul_link(&self, link: &SoulLinkGen1) -> Vec<ViolationGen1> {
+        let mut violations: Vec<_> = link.runs.iter().flat_map(|run| self.validate_run(run)).collect();
+        if !self.soul_link {
+            return violations;
+        }
+
+        for player in [PlayerGen1::One, PlayerGen1::Two] {
+            let (run, other) = (link.run(player), link.run(player.other()));
+            for pokemon in run.party() {
+                let explanation = match link.partner(player, pokemon.id).map(|id| &other.pokemon[id]) {
+                    None => format!("{} has no partner in {}'s run", pokemon.nickname, other.name),
+                    Some(partner) if partner.is_dead() => format!("{}'s partner {} has died", pokemon.nickname, partner.nickname),
+                    Some(partner) if partner.storage != StorageGen1::Party => {
+                        format!("{}'s partner {} isn't in {}'s party", pokemon.nickname, partner.nickname, other.name)
+                    }
+                    Some(_) => continue,
+                };
+                violations.push(ViolationGen1 { rule: RuleGen1::SoulLink, explanation });
+            }
+        }
+
+        let pairs: Vec<_> = link.party_pairs().collect();
+        for (i, pair) in pairs.iter().enumerate() {
+            for earlier in &pairs[..i] {
+                let shared = pair.iter()
+                    .map(|p| p.species.types[0])
+                    .find(|&typ| earlier.iter().any(|p| p.species.types[0] == typ));
+                if let Some(typ) = shared {
+                    violations.push(ViolationGen1 {
+                        rule: RuleGen1::SoulLink,
+                        explanation: format!(
+                            "{} & {} and {} & {} share the primary type {typ:?}",
+                            earlier[0].nickname, earlier[1].nickname, pair[0].nickname, pair[1].nickname,
+                        ),
+                    });
+                }
+            }
+        }
+        violations
+    }
+
+    /// Every rule the action would break.
+    pub fn validate_action(&self, run: &RunGen1, action: &ProposedActionGen1) -> Vec<ViolationGen1> {
+        let (area, species, outcome, shiny, gift) = match *action {
+            ProposedActionGen1::Catch { area, species, shiny, gift } => {
+                (area, species, EncounterOutcomeGen1::Caught, shiny, gift)
+            }
+            ProposedActionGen1::SkipDupe { area, species } => {
+                (area, species, EncounterOutcomeGen1::DupeSkipped, false, false)
+            }
+            ProposedActionGen1::Withdraw(id) => {
+                return match run.pokemon.get(id) {
+                    Some(pokemon) => self.party_violations(run, pokemon, pokemon.level),
+                    None => Vec::new(),
+                };
+            }
+            ProposedActionGen1::LevelUp { id, level } => {
+                return match run.pokemon.get(id) {
+                    Some(pokemon) => self.level_cap_violations(run, pokemon, level),
+                    None => Vec::new(),
+                };
+            }
+            ProposedActionGen1::UseItemInBattle(item) => {
+                return self.violation(
+                    self.no_battle_items,
+                    RuleGen1::NoBattleItems,
+                    format!("{} can't be used in battle", item.name()),
+                );
+            }
+            ProposedActionGen1::ShiftAfterKo => {
+                return self.violation(
+                    self.set_mode,
+                    RuleGen1::SetMode,
+                    "switching after a knockout isn't allowed in set mode".into(),
+                );
+            }
+        };
+
+        let mut encounters = run.encounters.clone();
+        encounters.push(EncounterGen1 { shiny, gift, ..EncounterGen1::new(area, species, 0, outcome) });
+        let last = encounters.len() - 1;
+        self.encounter_violations(&encounters)
+            .into_iter()
+            .filter(|&(index, _)| index == last)
+            .map(|(_, violation)| violation)
+            .collect()
+    }
+
+    /// Replays the encounters in order, returning each violation with the
+    /// index of the encounter that caused it.
+    fn encounter_violations(&self, encounters: &[EncounterGen1]) -> Vec<(usize, ViolationGen1)> {
+        let mut violations = Vec::new();
+        let mut used_areas = HashSet::new();
+        let mut caught = HashSet::new();
+        let mut caught_species = Vec::new();
+        let mut skipped = HashMap::new();
+        for (index, encounter) in encounters.iter().enumerate() {
+            let mut violate = |rule, explanation| violations.push((index, ViolationGen1 { rule, explanation }));
+            let species = encounter.species.name;
+            let area = &encounter.area;
+
+            if encounter.outcome == EncounterOutcomeGen1::DupeSkipped {
+                let skips = skipped.entry(name_key(area)).or_insert(0u16);
+                *skips += 1;
+                match self.dupes_clause {
+                    None => {
+                        violate(RuleGen1::DupesClause, format!("{species} on {area} can't be skipped without the dupes clause"));
+                    }
+                    Some(clause) if !clause.is_dupe(encounter.species, &caught_species) => {
+                        violate(RuleGen1::DupesClause, format!("{species} on {area} isn't a dupe, so it can't be skipped"));
+                    }
+                    Some(DupesClauseGen1 { rerolls: Some(rerolls), .. }) if *skips > rerolls as u16 => {
+                        violate(RuleGen1::DupesClause, format!("{area} has no dupe rerolls left"));
+                    }
+                    Some(_) => {}
+                }
+                continue;
+            }
+
+            // Exempt gifts and shinies don't use up the area, so whatever
+            // comes next there is still its first encounter
+            let exempt = (encounter.gift && self.gift_exemption) || (encounter.shiny && self.shiny_clause);
+            if !exempt && !used_areas.insert(name_key(area)) {
+                if encounter.gift {
+                    violate(RuleGen1::GiftExemption, format!("gift {species} counts as the encounter on {area}, which is already used"));
+                } else if encounter.shiny {
+                    violate(RuleGen1::ShinyClause, format!("shiny {species} can't be caught on {area} without the shiny clause"));
+                } else {
+                    violate(RuleGen1::FirstEncounter, format!("{species} isn't the first encounter on {area}"));
+                }
+            }
+
+            if encounter.outcome == EncounterOutcomeGen1::Caught {
+                caught_species.push(encounter.species);
+            }
+            if encounter.outcome == EncounterOutcomeGen1::Caught
+                && !caught.insert(species)
+                && self.species_clause
+                && !(encounter.shiny && self.shiny_clause)
+            {
+                violate(RuleGen1::SpeciesClause, format!("{species} has already been caught"));
+            }
+        }
+        violations
+    }
+
+    /// Why a Pokémon at `level` can't be in the party.
+    fn party_violations(&self, run: &RunGen1, pokemon: &TrackedPokemonGen1, level: u8) -> Vec<ViolationGen1> {
+        let name = &pokemon.nickname;
+        if pokemon.is_dead() {
+            return vec![ViolationGen1 { rule: RuleGen1::Graveyard, explanation: format!("{name} is dead") }];
+        }
+
+        let mut violations = self.level_cap_violations(run, pokemon, level);
+        let widowed = pokemon.partner.and_then(|p| run.pokemon.get(p)).filter(|p| p.is_dead());
+        if let Some(partner) = widowed {
+            violations.extend(self.violation(
+                self.wedlocke,
+                RuleGen1::Wedlocke,
+                format!("{name}'s partner {} has died", partner.nickname),
+            ));
+        }
+        violations
+    }
+
+    fn level_cap_violations(&self, run: &RunGen1, pokemon: &TrackedPokemonGen1, level: u8) -> Vec<ViolationGen1> {
+        match run.level_cap() {
+            Some(cap) if level > cap => self.violation(
+                self.level_caps,
+                RuleGen1::LevelCap,
+                format!("{} would be level {level}, over the level cap of {cap}", pokemon.nickname),
+            ),
+            _ => Vec::new(),
+        }
+    }
+
+    fn violation(&self, enabled: bool, rule: RuleGen1, explanation: String) -> Vec<ViolationGen1> {
+        if enabled {
+            vec![ViolationGen1 { rule, explanation }]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+impl Default for RuleSetGen1 {
+    /// Defaults to the classic rules.
+    fn default() -> Self {
+        Self::classic()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use engine::{GameGen1, find_species_gen_1};
+
+    fn species(name: &str) -> &'static SpeciesGen1 {
+        find_species_gen_1(name).unwrap()
+    }
+
+    fn rules(violations: Vec<ViolationGen1>) -> Vec<RuleGen1> {
+        violations.into_iter().map(|v| v.rule).collect()
+    }
+
+    /// Shinies, gifts and dupes are judged by the rule set, not the run.
+    #[test]
+    fn test_encounter_rules() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        run.catch("Route 1", species("Pidgey"), 3, "Birdie").unwrap();
+        run.record(EncounterGen1 { gift: true, ..EncounterGen1::new("Route 1", species("Magikarp"), 5, EncounterOutcomeGen1::Caught) })
+            .unwrap();
+        assert!(RuleSetGen1::classic().validate_run(&run).is_empty());
+        assert_eq!(rules(RuleSetGen1::hardcore().validate_run(&run)), [RuleGen1::GiftExemption]);
+
+        let classic = RuleSetGen1::classic();
+        let pidgey = species("Pidgey");
+        let shiny = ProposedActionGen1::Catch { area: "Route 1", species: pidgey, shiny: true, gift: false };
+        assert!(classic.validate_action(&run, &shiny).is_empty());
+        let no_shiny_clause = RuleSetGen1 { shiny_clause: false, ..classic };
+        assert_eq!(rules(no_shiny_clause.validate_action(&run, &shiny)), [RuleGen1::ShinyClause]);
+
+        let dupe = ProposedActionGen1::SkipDupe { area: "Route 2", species: pidgey };
+        assert!(classic.validate_action(&run, &dupe).is_empty());
+        let not_dupe = ProposedActionGen1::SkipDupe { area: "Route 2", species: species("Rattata") };
+        assert_eq!(rules(classic.validate_action(&run, &not_dupe)), [RuleGen1::DupesClause]);
+
+        // Pidgeotto is in Pidgey's line, and one reroll only allows one skip
+        let one_reroll = RuleSetGen1 { dupes_clause: Some(DupesClauseGen1 { rerolls: Some(1), evolution_lines: true }), ..classic };
+        let pidgeotto = ProposedActionGen1::SkipDupe { area: "Route 2", species: species("Pidgeotto") };
+        assert!(one_reroll.validate_action(&run, &pidgeotto).is_empty());
+        run.record_encounter("Route 2", species("Pidgeotto"), 9, EncounterOutcomeGen1::DupeSkipped).unwrap();
+        assert_eq!(rules(one_reroll.validate_action(&run, &dupe)), [RuleGen1::DupesClause]);
+        assert!(classic.validate_action(&run, &dupe).is_empty());
+
+        let second_pidgey = ProposedActionGen1::Catch { area: "Route 2", species: pidgey, shiny: false, gift: false };
+        assert!(classic.validate_action(&run, &second_pidgey).is_empty());
+        let species_clause = RuleSetGen1 { species_clause: true, ..classic };
+        assert_eq!(rules(species_clause.validate_action(&run, &second_pidgey)), [RuleGen1::SpeciesClause]);
+    }
+
+    /// A shiny caught first under the shiny clause leaves the area's
+    /// encounter for the next Pokémon, as the tracker does.
+    #[test]
+    fn test_shiny_first() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        run.record(EncounterGen1 { shiny: true, ..EncounterGen1::new("Route 3", species("Pidgey"), 8, EncounterOutcomeGen1::Caught) })
+            .unwrap();
+        run.catch("Route 3", species("Spearow"), 8, "Beaky").unwrap();
+        assert!(RuleSetGen1::classic().validate_run(&run).is_empty());
+
+        let no_shiny_clause = RuleSetGen1 { shiny_clause: false, ..RuleSetGen1::classic() };
+        assert_eq!(rules(no_shiny_clause.validate_run(&run)), [RuleGen1::FirstEncounter]);
+    }
+
+    /// Party rules: level caps, items, set mode, deaths and widows.
+    #[test]
+    fn test_party_rules() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        let a = run.catch("Route 1", species("Pidgey"), 12, "A").unwrap();
+        let b = run.catch("Route 2", species("Rattata"), 12, "B").unwrap();
+        // Brock's Onix is level 14
+        run.pair(a, b).unwrap();
+        run.deposit(a).unwrap();
+        run.record_death(b, Default::default()).unwrap();
+
+        let hardcore = RuleSetGen1::hardcore();
+        assert_eq!(rules(hardcore.validate_action(&run, &ProposedActionGen1::LevelUp { id: a, level: 15 })), [RuleGen1::LevelCap]);
+        assert!(hardcore.validate_action(&run, &ProposedActionGen1::LevelUp { id: a, level: 14 }).is_empty());
+        assert!(RuleSetGen1::classic().validate_action(&run, &ProposedActionGen1::LevelUp { id: a, level: 15 }).is_empty());
+
+        let potion = ProposedActionGen1::UseItemInBattle(ItemGen1::Potion);
+        assert_eq!(rules(hardcore.validate_action(&run, &potion)), [RuleGen1::NoBattleItems]);
+        assert_eq!(rules(hardcore.validate_action(&run, &ProposedActionGen1::ShiftAfterKo)), [RuleGen1::SetMode]);
+
+        assert_eq!(rules(hardcore.validate_action(&run, &ProposedActionGen1::Withdraw(b))), [RuleGen1::Graveyard]);
+        assert!(hardcore.validate_action(&run, &ProposedActionGen1::Withdraw(a)).is_empty());
+        assert_eq!(rules(RuleSetGen1::wedlocke().validate_action(&run, &ProposedActionGen1::Withdraw(a))), [RuleGen1::Wedlocke]);
+    }
+
+    /// Soul Link parties are made of whole pairs with distinct primary types.
+    #[test]
+    fn test_soul_link_rules() {
+        let mut link = SoulLinkGen1::new(RunGen1::new("one", GameGen1::Red), RunGen1::new("two", GameGen1::Blue));
+        link.catch("Route 1", [(species("Pidgey"), 3, "A"), (species("Rattata"), 3, "B")]).unwrap();
+        link.catch("Route 22", [(species("Spearow"), 3, "C"), (species("Mankey"), 3, "D")]).unwrap();
+        let soul_link = RuleSetGen1::soul_link();
+        assert!(RuleSetGen1::classic().validate_soul_link(&link).is_empty());
+
+        // Pidgey and Spearow are both Normal/Flying
+        assert_eq!(rules(soul_link.validate_soul_link(&link)), [RuleGen1::SoulLink]);
+        link.deposit(PlayerGen1::One, 1).unwrap();
+        assert!(soul_link.validate_soul_link(&link).is_empty());
+
+        // Player one's partner fled, so player two's catch can't be used
+        link.run_mut(PlayerGen1::One).record_encounter("Route 2", species("Caterpie"), 4, EncounterOutcomeGen1::Fled).unwrap();
+        link.run_mut(PlayerGen1::Two).catch("Route 2", species("Weedle"), 4, "E").unwrap();
+        assert_eq!(rules(soul_link.validate_soul_link(&link)), [RuleGen1::SoulLink]);
+    }
+}
@@ -0,0 +1,697 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use engine::pokemon::{EVsGen1, IVsGen1};
+use engine::species::name_key;
+use engine::{
+    BattleExpGen1, DupesClauseGen1, EncounterMethodGen1, EncounterTableGen1, FirstEncounterChanceGen1, GameGen1,
+    LevelingPokemonGen1, PokemonGen1, ProgressGen1, SpeciesGen1, TrainerGen1, exp_gain_gen_1,
+    find_encounters_by_area_gen_1, find_species_gen_1, first_encounter_odds_gen_1, growth_rate_gen_1, level_cap_gen_1,
+};
+
+/// The most Pokémon a party can hold.
+pub const PARTY_SIZE: usize = 6;
+
+/// What happened to an encounter.
+///
+/// # Variants
+/// - `Caught`: Caught and added to the run
+/// - `Fled`: The wild Pokémon fled or the player ran
+/// - `Fainted`: The wild Pokémon fainted before it could be caught
+/// - `DupeSkipped`: Skipped under the dupes clause; the area's encounter is
+///   still available
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EncounterOutcomeGen1 {
+    Caught,
+    Fled,
+    Fainted,
+    DupeSkipped,
+}
+
+/// One encounter.
+///
+/// # Fields
+/// - `area`: Route or area name (e.g., "Route 2")
+/// - `species`/`level`: The Pokémon encountered
+/// - `outcome`: What happened
+/// - `shiny`: Whether the Pokémon was shiny
+/// - `gift`: Whether the Pokémon was a gift rather than a wild encounter
+/// - `pokemon`: Id of the caught Pokémon, if it was caught
+/// - `timestamp`: Seconds since the Unix epoch
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EncounterGen1 {
+    pub area: String,
+    pub species: &'static SpeciesGen1,
+    pub level: u8,
+    pub outcome: EncounterOutcomeGen1,
+    pub shiny: bool,
+    pub gift: bool,
+    pub pokemon: Option<usize>,
+    pub timestamp: u64,
+}
+
+/// Where a caught Pokémon currently is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StorageGen1 {
+    Party,
+    Box,
+    Graveyard,
+}
+
+/// How a Pokémon died. Every field but the timestamp is optional, since
+/// not every death happens in a trainer battle.
+///
+/// # Fields
+/// - `trainer`: The trainer it died to (e.g., "Misty")
+/// - `opponent`: The Pokémon that killed it
+/// - `move_name`: The move that killed it
+/// - `turn`: The turn of the battle it died on
+/// - `soul_link`: Whether it died because its Soul Link partner did; the
+///   other fields then describe the partner's death
+/// - `timestamp`: Seconds since the Unix epoch
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DeathGen1 {
+    pub trainer: Option<String>,
+    pub opponent: Option<String>,
+    pub move_name: Option<String>,
+    pub turn: Option<u16>,
+    pub soul_link: bool,
+    pub timestamp: u64,
+}
+
+/// A Pokémon caught during a run.
+///
+/// # Fields
+/// - `id`: Index in the run's Pokémon list; never reused
+/// - `nickname`: The Pokémon's nickname
+/// - `species`/`level`: Current species and level
+/// - `exp`: Total experience; logged battles raise `level` to match it
+/// - `ivs`: DVs, all 0 (the worst case) until they're known
+/// - `evs`: Stat experience
+/// - `traded`: Whether it came from a trade, which gives 1.5× experience
+/// - `area`: Where it was caught
+/// - `caught_at`: Seconds since the Unix epoch
+/// - `storage`: Party, box or graveyard
+/// - `death`: How it died, once it has
+/// - `partner`: The Pokémon it's paired with under the Wedlocke rules (Soul
+///   Link partners are in another run; see [`SoulLinkGen1`](crate::SoulLinkGen1))
+/// - `hp`: Current HP if it's been recorded; `None` means full HP
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackedPokemonGen1 {
+    pub id: usize,
+    pub nickname: String,
+    pub species: &'static SpeciesGen1,
+    pub level: u8,
+    pub exp: u32,
+    pub ivs: IVsGen1,
+    pub evs: EVsGen1,
+    pub traded: bool,
+    pub area: String,
+    pub caught_at: u64,
+    pub storage: StorageGen1,
+    pub death: Option<DeathGen1>,
+    pub partner: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hp: Option<u16>,
+}
+
+/// A battle the party won.
+///
+/// # Fields
+/// - `trainer`: The trainer beaten, or `None` for a wild battle
+/// - `opponents`: The Pokémon defeated, as `(species, level)`
+/// - `participants`: Ids of the party members that battled each opponent
+/// - `exp_all`: Whether the Exp. All was in the bag
+/// - `timestamp`: Seconds since the Unix epoch
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BattleGen1 {
+    pub trainer: Option<String>,
+    pub opponents: Vec<(&'static SpeciesGen1, u8)>,
+    pub participants: Vec<usize>,
+    pub exp_all: bool,
+    pub timestamp: u64,
+}
+
+/// A Nuzlocke run: every encounter and every Pokémon caught.
+///
+/// # Fields
+/// - `name`: Name of the run
+/// - `game`: The game being played
+/// - `started_at`: Seconds since the Unix epoch
+/// - `encounters`: Every encounter, in order
+/// - `pokemon`: Every Pokémon caught, indexed by id
+/// - `battles`: Every battle won, in order
+/// - `progress`: Badges earned and Elite Four progress
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, GameGen1};
+/// use tracker::{EncounterOutcomeGen1, RunGen1, StorageGen1};
+///
+/// let mut run = RunGen1::new("Blue attempt 3", GameGen1::Blue);
+/// let pidgey = find_species_gen_1("Pidgey").unwrap();
+/// let id = run.catch("Route 1", pidgey, 3, "Birdie").unwrap();
+/// assert_eq!(run.pokemon[id].storage, StorageGen1::Party);
+///
+/// // Only the first encounter in an area counts
+/// let rattata = find_species_gen_1("Rattata").unwrap();
+/// assert!(run.record_encounter("Route 1", rattata, 2, EncounterOutcomeGen1::Fled).is_err());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RunGen1 {
+    pub name: String,
+    pub game: GameGen1,
+    pub started_at: u64,
+    pub encounters: Vec<EncounterGen1>,
+    pub pokemon: Vec<TrackedPokemonGen1>,
+    pub battles: Vec<BattleGen1>,
+    pub progress: ProgressGen1,
+}
+
+/// Why a change to a run was refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TrackerErrorGen1 {
+    /// The area's first encounter has already been used
+    AreaUsed(String),
+    /// No Pokémon has this id
+    UnknownPokemon(usize),
+    /// The party already has [`PARTY_SIZE`] Pokémon
+    PartyFull,
+    /// The Pokémon is dead
+    Dead(usize),
+    /// The Pokémon isn't in the party
+    NotInParty(usize),
+    /// No species has this name
+    UnknownSpecies(String),
+}
+
+impl fmt::Display for TrackerErrorGen1 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AreaUsed(area) => write!(f, "the encounter on {area} has already been used"),
+            Self::UnknownPokemon(id) => write!(f, "no Pokémon with id {id}"),
+            Self::PartyFull => write!(f, "the party is full"),
+            Self::Dead(id) => write!(f, "Pokémon {id} is dead"),
+            Self::NotInParty(id) => write!(f, "Pokémon {id} isn't in the party"),
+            Self::UnknownSpecies(name) => write!(f, "unknown species '{name}'"),
+        }
+    }
+}
+
+impl std::error::Error for TrackerErrorGen1 {}
+
+impl EncounterGen1 {
+    /// A wild encounter that isn't shiny, ready to be [recorded](RunGen1::record).
+    pub fn new(area: &str, species: &'static SpeciesGen1, level: u8, outcome: EncounterOutcomeGen1) -> Self {
+        Self {
+            area: area.into(),
+            species,
+            level,
+            outcome,
+            shiny: false,
+            gift: false,
+            pokemon: None,
+            timestamp: 0,
+        }
+    }
+
+    /// Whether this is an ordinary encounter that uses up its area. Dupe
+    /// skips, shinies and gifts don't; whether they're allowed depends on
+    /// the rule set.
+    pub fn uses_area(&self) -> bool {
+        self.outcome != EncounterOutcomeGen1::DupeSkipped && !self.shiny && !self.gift
+    }
+}
+
+impl BattleGen1 {
+    /// A wild battle against one Pokémon, ready to be [recorded](RunGen1::record_battle).
+    pub fn wild(species: &'static SpeciesGen1, level: u8, participants: Vec<usize>) -> Self {
+        Self { trainer: None, opponents: vec![(species, level)], participants, exp_all: false, timestamp: 0 }
+    }
+
+    /// A battle against a trainer's whole party.
+    ///
+    /// # Errors
+    /// [`TrackerErrorGen1::UnknownSpecies`] if the trainer's party has a
+    /// species that isn't in the species table.
+    pub fn against_trainer(trainer: &TrainerGen1, participants: Vec<usize>) -> Result<Self, TrackerErrorGen1> {
+        let opponents = trainer.party.iter()
+            .map(|p| match find_species_gen_1(p.species) {
+                Some(species) => Ok((species, p.level)),
+                None => Err(TrackerErrorGen1::UnknownSpecies(p.species.into())),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { trainer: Some(trainer.name.into()), opponents, participants, exp_all: false, timestamp: 0 })
+    }
+}
+
+impl TrackedPokemonGen1 {
+    /// Whether the Pokémon has died.
+    pub fn is_dead(&self) -> bool {
+        self.storage == StorageGen1::Graveyard
+    }
+
+    /// The Pokémon with stats calculated from its level, DVs and stat
+    /// experience, ready for `calc_damage_gen_1`.
+    pub fn pokemon(&self) -> PokemonGen1 {
+        let mut pokemon = self.species.pokemon(self.level, self.ivs.clone());
+        pokemon.evs = self.evs.clone();
+        self.species.calculate_stats(&mut pokemon);
+        pokemon
+    }
+
+    /// Current HP: the recorded HP, at most its max HP, or full HP if none
+    /// has been recorded. Always 0 once it's dead.
+    pub fn current_hp(&self) -> u16 {
+        if self.is_dead() {
+            return 0;
+        }
+        let max = self.pokemon().stats.hp;
+        self.hp.map_or(max, |hp| hp.min(max))
+    }
+}
+
+impl RunGen1 {
+    /// Starts an empty run.
+    pub fn new(name: &str, game: GameGen1) -> Self {
+        Self {
+            name: name.into(),
+            game,
+            started_at: unix_time(),
+            encounters: Vec::new(),
+            pokemon: Vec::new(),
+            battles: Vec::new(),
+            progress: ProgressGen1::default(),
+        }
+    }
+
+    /// The encounter that counts for an area: the first one that
+    /// [uses the area](EncounterGen1::uses_area). Area names are compared
+    /// ignoring case, spaces and punctuation.
+    pub fn encounter(&self, area: &str) -> Option<&EncounterGen1> {
+        let key = name_key(area);
+        self.encounters.iter().find(|e| e.uses_area() && name_key(&e.area) == key)
+    }
+
+    /// The current level cap: the level of the next boss's ace. `None` once
+    /// the Champion has been beaten.
+    pub fn level_cap(&self) -> Option<u8> {
+        level_cap_gen_1(self.game.version(), &self.progress).map(|cap| cap.level)
+    }
+
+    /// Living party members above the level cap.
+    pub fn over_level_cap(&self) -> impl Iterator<Item = &TrackedPokemonGen1> {
+        let cap = self.level_cap().unwrap_or(u8::MAX);
+        self.party().filter(move |p| p.level > cap)
+    }
+
+    /// Every species caught so far, dead or alive, for the dupes clause.
+    pub fn caught_species(&self) -> Vec<&'static SpeciesGen1> {
+        self.pokemon.iter().map(|p| p.species).collect()
+    }
+
+    /// The odds of each species being the first valid encounter in an area
+    /// under a dupes clause, for each wild encounter method there.
+    pub fn first_encounter_odds(
+        &self,
+        area: &str,
+        clause: &DupesClauseGen1,
+    ) -> Vec<(&'static EncounterTableGen1, Vec<FirstEncounterChanceGen1>)> {
+        let caught = self.caught_species();
+        find_encounters_by_area_gen_1(area, self.game).into_iter()
+            .filter(|t| !matches!(t.method, EncounterMethodGen1::Gift | EncounterMethodGen1::Trade | EncounterMethodGen1::Static))
+            .map(|t| (t, first_encounter_odds_gen_1(t, &caught, clause)))
+            .collect()
+    }
+
+    /// Records an ordinary wild encounter. See [`RunGen1::record`].
+    ///
+    /// # Errors
+    /// [`TrackerErrorGen1::AreaUsed`] if the area's encounter has been used.
+    pub fn record_encounter(
+        &mut self,
+        area: &str,
+        species: &'static SpeciesGen1,
+        level: u8,
+        outcome: EncounterOutcomeGen1,
+    ) -> Result<Option<usize>, TrackerErrorGen1> {
+        self.record(EncounterGen1::new(area, species, level, outcome))
+    }
+
+    /// Records an encounter, stamping it with the current time. A caught
+    /// Pokémon joins the party, or the box if the party is full, nicknamed
+    /// after its species; its id is returned.
+    ///
+    /// # Errors
+    /// [`TrackerErrorGen1::AreaUsed`] if the encounter would use an area
+    /// whose encounter has already been used.
+    pub fn record(&mut self, mut encounter: EncounterGen1) -> Result<Option<usize>, TrackerErrorGen1> {
+        if encounter.uses_area() && self.encounter(&encounter.area).is_some() {
+            return Err(TrackerErrorGen1::AreaUsed(encounter.area));
+        }
+
+        encounter.timestamp = unix_time();
+        encounter.pokemon = (encounter.outcome == EncounterOutcomeGen1::Caught).then(|| {
+            let id = self.pokemon.len();
+            let storage = if self.party().count() < PARTY_SIZE { StorageGen1::Party } else { StorageGen1::Box };
+            self.pokemon.push(TrackedPokemonGen1 {
+                id,
+                nickname: encounter.species.name.into(),
+                species: encounter.species,
+                level: encounter.level,
+                exp: growth_rate_gen_1(encounter.species).exp_for_level(encounter.level),
+                ivs: IVsGen1::default(),
+                evs: EVsGen1::default(),
+                traded: false,
+                area: encounter.area.clone(),
+                caught_at: encounter.timestamp,
+                storage,
+                death: None,
+                partner: None,
+                hp: None,
+            });
+            id
+        });
+        let pokemon = encounter.pokemon;
+        self.encounters.push(encounter);
+        Ok(pokemon)
+    }
+
+    /// Records catching a Pokémon and gives it a nickname. Returns its id.
+    ///
+    /// # Errors
+    /// [`TrackerErrorGen1::AreaUsed`] if the area's encounter has been used.
+    pub fn catch(
+        &mut self,
+        area: &str,
+        species: &'static SpeciesGen1,
+        level: u8,
+        nickname: &str,
+    ) -> Result<usize, TrackerErrorGen1> {
+        let id = self.record_encounter(area, species, level, EncounterOutcomeGen1::Caught)?
+            .expect("caught encounters always add a Pokémon");
+        self.pokemon[id].nickname = nickname.into();
+        Ok(id)
+    }
+
+    /// Moves a Pokémon to the graveyard.
+    ///
+    /// # Errors
+    /// If there's no such Pokémon, or it's already dead.
+    pub fn record_death(&mut self, id: usize, death: DeathGen1) -> Result<(), TrackerErrorGen1> {
+        let pokemon = self.alive_mut(id)?;
+        pokemon.storage = StorageGen1::Graveyard;
+        pokemon.death = Some(DeathGen1 { timestamp: unix_time(), ..death });
+        Ok(())
+    }
+
+    /// Pairs two Pokémon with each other, replacing any previous partners.
+    ///
+    /// # Errors
+    /// If either Pokémon doesn't exist.
+    pub fn pair(&mut self, a: usize, b: usize) -> Result<(), TrackerErrorGen1> {
+        for id in [a, b] {
+            let partner = self.pokemon.get(id).ok_or(TrackerErrorGen1::UnknownPokemon(id))?.partner;
+            if let Some(old) = partner {
+                self.pokemon[old].partner = None;
+            }
+        }
+        self.pokemon[a].partner = Some(b);
+        self.pokemon[b].partner = Some(a);
+        Ok(())
+    }
+
+    /// Moves a living Pokémon from the party to the box.
+    ///
+    /// # Errors
+    /// If there's no such Pokémon, or it's dead.
+    pub fn deposit(&mut self, id: usize) -> Result<(), TrackerErrorGen1> {
+        self.alive_mut(id)?.storage = StorageGen1::Box;
+        Ok(())
+    }
+
+    /// Moves a living Pokémon from the box to the party.
+    ///
+    /// # Errors
+    /// If there's no such Pokémon, it's dead, or the party is full.
+    pub fn withdraw(&mut self, id: usize) -> Result<(), TrackerErrorGen1> {
+        let in_party = self.party().count();
+        let pokemon = self.alive_mut(id)?;
+        if pokemon.storage != StorageGen1::Party {
+            if in_party >= PARTY_SIZE {
+                return Err(TrackerErrorGen1::PartyFull);
+            }
+            pokemon.storage = StorageGen1::Party;
+        }
+        Ok(())
+    }
+
+    /// Records a won battle, stamping it with the current time. Every living
+    /// party member gains its experience and stat experience, so their
+    /// [stats](TrackedPokemonGen1::pokemon) follow. Returns the ids of those
+    /// that levelled up.
+    ///
+    /// # Errors
+    /// If a participant doesn't exist, is dead or isn't in the party, in
+    /// which case nothing is recorded.
+    pub fn record_battle(&mut self, mut battle: BattleGen1) -> Result<Vec<usize>, TrackerErrorGen1> {
+        for &id in &battle.participants {
+            if self.alive_mut(id)?.storage != StorageGen1::Party {
+                return Err(TrackerErrorGen1::NotInParty(id));
+            }
+        }
+
+        let exp = BattleExpGen1 {
+            trainer: battle.trainer.is_some(),
+            participants: battle.participants.len() as u8,
+            exp_all: battle.exp_all,
+            party_size: self.party().count() as u8,
+        };
+        let mut levelled_up = Vec::new();
+        for pokemon in self.pokemon.iter_mut().filter(|p| p.storage == StorageGen1::Party) {
+            let participant = battle.participants.contains(&pokemon.id);
+            let mut leveling = LevelingPokemonGen1 {
+                species: pokemon.species,
+                exp: pokemon.exp,
+                evs: pokemon.evs.clone(),
+                traded: pokemon.traded,
+            };
+            for &(species, level) in &battle.opponents {
+                leveling.gain(&exp_gain_gen_1(species, level, &exp, participant, pokemon.traded));
+            }
+            let level = leveling.level();
+            pokemon.exp = leveling.exp;
+            pokemon.evs = leveling.evs;
+            if level > pokemon.level {
+                pokemon.level = level;
+                levelled_up.push(pokemon.id);
+            }
+        }
+
+        battle.timestamp = unix_time();
+        self.battles.push(battle);
+        Ok(levelled_up)
+    }
+
+    /// Pokémon in the party, in the order they were caught.
+    pub fn party(&self) -> impl Iterator<Item = &TrackedPokemonGen1> {
+        self.stored(StorageGen1::Party)
+    }
+
+    /// Living Pokémon in the box.
+    pub fn boxed(&self) -> impl Iterator<Item = &TrackedPokemonGen1> {
+        self.stored(StorageGen1::Box)
+    }
+
+    /// Pokémon that have died.
+    pub fn graveyard(&self) -> impl Iterator<Item = &TrackedPokemonGen1> {
+        self.stored(StorageGen1::Graveyard)
+    }
+
+    fn stored(&self, storage: StorageGen1) -> impl Iterator<Item = &TrackedPokemonGen1> {
+        self.pokemon.iter().filter(move |p| p.storage == storage)
+    }
+
+    fn alive_mut(&mut self, id: usize) -> Result<&mut TrackedPokemonGen1, TrackerErrorGen1> {
+        let pokemon = self.pokemon.get_mut(id).ok_or(TrackerErrorGen1::UnknownPokemon(id))?;
+        if pokemon.is_dead() {
+            return Err(TrackerErrorGen1::Dead(id));
+        }
+        Ok(pokemon)
+    }
+}
+
+/// Seconds since the Unix epoch.
+pub(crate) fn unix_time() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use engine::{DamageRoll, calc_damage_gen_1, find_move_gen_1, find_species_gen_1};
+
+    fn species(name: &str) -> &'static SpeciesGen1 {
+        find_species_gen_1(name).unwrap()
+    }
+
+    /// Dupe skips and shinies don't use up the area, and a seventh catch goes
+    /// to the box.
+    #[test]
+    fn test_encounters_and_storage() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        let skipped = run.record_encounter("Route 2", species("Pidgey"), 3, EncounterOutcomeGen1::DupeSkipped).unwrap();
+        assert_eq!(skipped, None);
+        assert!(run.encounter("route 2").is_none());
+        run.catch("Route 2", species("Caterpie"), 4, "Cat").unwrap();
+        assert_eq!(run.encounter("ROUTE 2").unwrap().species.name, "Caterpie");
+        assert_eq!(
+            run.record_encounter("Route 2", species("Weedle"), 4, EncounterOutcomeGen1::Fled),
+            Err(TrackerErrorGen1::AreaUsed("Route 2".into()))
+        );
+        let shiny = EncounterGen1 { shiny: true, ..EncounterGen1::new("Route 2", species("Weedle"), 4, EncounterOutcomeGen1::Caught) };
+        assert_eq!(run.record(shiny), Ok(Some(1)));
+
+        for area in 1..=5 {
+            run.catch(&format!("Area {area}"), species("Rattata"), 3, "Rat").unwrap();
+        }
+        assert_eq!(run.party().count(), PARTY_SIZE);
+        assert_eq!(run.pokemon[6].storage, StorageGen1::Box);
+        assert_eq!(run.withdraw(6), Err(TrackerErrorGen1::PartyFull));
+        run.deposit(0).unwrap();
+        run.withdraw(6).unwrap();
+        assert_eq!(run.boxed().map(|p| p.id).collect::<Vec<_>>(), [0]);
+    }
+
+    /// Dead Pokémon keep their cause of death, have no HP left and can't be
+    /// moved.
+    #[test]
+    fn test_deaths() {
+        let mut run = RunGen1::new("test", GameGen1::Yellow);
+        let id = run.catch("Viridian Forest", species("Pikachu"), 5, "Sparky").unwrap();
+        let max_hp = run.pokemon[id].pokemon().stats.hp;
+        assert_eq!(run.pokemon[id].current_hp(), max_hp);
+        run.pokemon[id].hp = Some(3);
+        assert_eq!(run.pokemon[id].current_hp(), 3);
+        let death = DeathGen1 {
+            trainer: Some("Brock".into()),
+            opponent: Some("Onix".into()),
+            move_name: Some("Bide".into()),
+            turn: Some(4),
+            ..Default::default()
+        };
+        run.record_death(id, death).unwrap();
+
+        let dead = &run.pokemon[id];
+        assert!(dead.is_dead());
+        assert_eq!(dead.current_hp(), 0);
+        assert_eq!(dead.death.as_ref().unwrap().trainer.as_deref(), Some("Brock"));
+        assert!(dead.death.as_ref().unwrap().timestamp >= run.started_at);
+        assert_eq!(run.graveyard().count(), 1);
+        assert_eq!(run.withdraw(id), Err(TrackerErrorGen1::Dead(id)));
+        assert_eq!(run.record_death(id, DeathGen1::default()), Err(TrackerErrorGen1::Dead(id)));
+        assert_eq!(run.deposit(99), Err(TrackerErrorGen1::UnknownPokemon(99)));
+    }
+
+    /// Dupes odds use everything caught so far, including the dead.
+    #[test]
+    fn test_first_encounter_odds() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        let id = run.catch("Route 22", species("Spearow"), 3, "Beaky").unwrap();
+        run.record_death(id, DeathGen1::default()).unwrap();
+
+        let odds = run.first_encounter_odds("Route 22", &DupesClauseGen1::default());
+        let (grass, grass_odds) = &odds[0];
+        assert_eq!(grass.method, EncounterMethodGen1::Grass);
+        assert!(grass_odds.iter().all(|c| c.species.name != "Spearow"));
+        assert_eq!(odds.len(), 4);
+    }
+
+    /// The level cap follows the run's progress.
+    #[test]
+    fn test_level_cap() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        let id = run.catch("Route 22", species("Mankey"), 15, "Punchy").unwrap();
+        assert_eq!(run.level_cap(), Some(14));
+        assert_eq!(run.over_level_cap().map(|p| p.id).collect::<Vec<_>>(), [id]);
+
+        run.progress.earn(engine::BadgeGen1::Boulder);
+        assert_eq!(run.level_cap(), Some(21));
+        assert_eq!(run.over_level_cap().count(), 0);
+    }
+
+    /// Logged battles add experience and stat experience to the living
+    /// party, split between the participants.
+    #[test]
+    fn test_record_battle() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        let lead = run.catch("Route 22", species("Mankey"), 9, "Punchy").unwrap();
+        let bench = run.catch("Route 2", species("Caterpie"), 9, "Cat").unwrap();
+        let brock = engine::find_trainers_by_name_gen_1("Brock")[0];
+
+        let levelled_up = run.record_battle(BattleGen1::against_trainer(brock, vec![lead]).unwrap()).unwrap();
+        assert_eq!(levelled_up, [lead]);
+        // Geodude and Onix: 220 + 324 experience, and both their base stats
+        let exp_at_9 = growth_rate_gen_1(species("Mankey")).exp_for_level(9);
+        assert_eq!(run.pokemon[lead].exp, exp_at_9 + 544);
+        assert_eq!(run.pokemon[lead].evs.defense, 100 + 160);
+        assert_eq!(run.pokemon[bench].exp, growth_rate_gen_1(species("Caterpie")).exp_for_level(9));
+        assert_eq!(run.battles[0].trainer.as_deref(), Some("Brock"));
+
+        run.deposit(bench).unwrap();
+        let wild = BattleGen1::wild(species("Pidgey"), 3, vec![bench]);
+        assert_eq!(run.record_battle(wild), Err(TrackerErrorGen1::NotInParty(bench)));
+        assert_eq!(run.battles.len(), 1);
+    }
+
+    /// A hand-written trainer with a species that doesn't exist is refused
+    /// rather than panicking.
+    #[test]
+    fn test_unknown_trainer_species() {
+        let trainer = TrainerGen1 {
+            party: &[engine::TrainerPokemonGen1 { species: "MissingNo.", level: 5, moves: &[] }],
+            ..*engine::find_trainers_by_name_gen_1("Brock")[0]
+        };
+        let error = BattleGen1::against_trainer(&trainer, vec![0]).unwrap_err();
+        assert_eq!(error, TrackerErrorGen1::UnknownSpecies("MissingNo.".into()));
+        assert_eq!(error.to_string(), "unknown species 'MissingNo.'");
+    }
+
+    /// Tracked Pokémon feed straight into the damage calculator.
+    #[test]
+    fn test_pokemon_for_damage_calc() {
+        let mut run = RunGen1::new("test", GameGen1::Red);
+        let id = run.catch("Route 3", species("Jigglypuff"), 10, "Puff").unwrap();
+        run.pokemon[id].evs.hp = 65_535;
+        let puff = run.pokemon[id].pokemon();
+        assert_eq!(puff.stats.lvl, 10);
+        assert_eq!(puff.stats.hp, species("Jigglypuff").pokemon(10, IVsGen1::default()).stats.hp + 6);
+
+        let onix = species("Onix").pokemon(12, IVsGen1::default());
+        let tackle = find_move_gen_1("Tackle").unwrap();
+        assert!(calc_damage_gen_1(&onix, &puff, &tackle, false, DamageRoll::Max) > 0);
+    }
+
+    /// A run survives being saved as JSON, with species stored by name.
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let mut run = RunGen1::new("test", GameGen1::Blue);
+        let id = run.catch("Route 1", species("Pidgey"), 3, "Birdie").unwrap();
+        run.record_battle(BattleGen1::wild(species("Rattata"), 2, vec![id])).unwrap();
+        let json = serde_json::to_string(&run).unwrap();
+        assert!(json.contains(r#""species":"Pidgey""#));
+        assert!(json.contains(r#""opponents":[["Rattata",2]]"#));
+
+        let read: RunGen1 = serde_json::from_str(&json).unwrap();
+        assert_eq!(format!("{read:?}"), format!("{run:?}"));
+        assert!(serde_json::from_str::<RunGen1>(&json.replace("Pidgey", "Pidgeyy")).is_err());
+    }
+}
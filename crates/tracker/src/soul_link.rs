@@ -0,0 +1,246 @@
+use engine::SpeciesGen1;
+use engine::species::name_key;
+
+use crate::run::{DeathGen1, RunGen1, StorageGen1, TrackedPokemonGen1, TrackerErrorGen1};
+
+/// One of the two players in a Soul Link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PlayerGen1 {
+    One,
+    Two,
+}
+
+/// Two Pokémon linked across the players' runs.
+///
+/// # Fields
+/// - `area`: The area both were caught in
+/// - `pokemon`: Each player's Pokémon id, player one first
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoulLinkPairGen1 {
+    pub area: String,
+    pub pokemon: [usize; 2],
+}
+
+/// Two players' runs with their encounters linked. Each player keeps their
+/// own run, with its own game, save and progress; what links them is the
+/// list of pairs.
+///
+/// # Fields
+/// - `runs`: Each player's run, player one first
+/// - `pairs`: Every linked pair, in the order they were linked
+///
+/// # Example
+/// ```
+/// use engine::{find_species_gen_1, GameGen1};
+/// use tracker::{DeathGen1, PlayerGen1, RunGen1, SoulLinkGen1};
+///
+/// let mut link = SoulLinkGen1::new(RunGen1::new("Ash", GameGen1::Red), RunGen1::new("Gary", GameGen1::Blue));
+/// let pidgey = find_species_gen_1("Pidgey").unwrap();
+/// let rattata = find_species_gen_1("Rattata").unwrap();
+/// let [birdie, rat] = link.catch("Route 1", [(pidgey, 3, "Birdie"), (rattata, 2, "Rat")]).unwrap();
+///
+/// // When Birdie dies, so does Rat
+/// link.record_death(PlayerGen1::One, birdie, DeathGen1::default()).unwrap();
+/// assert!(link.run(PlayerGen1::Two).pokemon[rat].is_dead());
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SoulLinkGen1 {
+    pub runs: [RunGen1; 2],
+    pub pairs: Vec<SoulLinkPairGen1>,
+}
+
+impl PlayerGen1 {
+    /// The other player.
+    pub fn other(&self) -> PlayerGen1 {
+        match self {
+            PlayerGen1::One => PlayerGen1::Two,
+            PlayerGen1::Two => PlayerGen1::One,
+        }
+    }
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl SoulLinkPairGen1 {
+    /// The player's half of the pair.
+    pub fn id(&self, player: PlayerGen1) -> usize {
+        self.pokemon[player.index()]
+    }
+}
+
+impl SoulLinkGen1 {
+    /// Links two players' runs, with no pairs yet.
+    pub fn new(one: RunGen1, two: RunGen1) -> Self {
+        Self { runs: [one, two], pairs: Vec::new() }
+    }
+
+    /// A player's run.
+    pub fn run(&self, player: PlayerGen1) -> &RunGen1 {
+        &self.runs[player.index()]
+    }
+
+    /// A player's run, for changes that don't involve the link.
+    pub fn run_mut(&mut self, player: PlayerGen1) -> &mut RunGen1 {
+        &mut self.runs[player.index()]
+    }
+
+    /// Records both players catching their encounter in an area and links
+    /// the two. Each catch is `(species, level, nickname)`, player one
+    /// first. Returns both ids.
+    ///
+    /// # Errors
+    /// [`TrackerErrorGen1::AreaUsed`] if either player has used the area's
+    /// encounter, in which case neither catch is recorded.
+    pub fn catch(
+        &mut self,
+        area: &str,
+        catches: [(&'static SpeciesGen1, u8, &str); 2],
+    ) -> Result<[usize; 2], TrackerErrorGen1> {
+        if self.runs.iter().any(|run| run.encounter(area).is_some()) {
+            return Err(TrackerErrorGen1::AreaUsed(area.into()));
+        }
+        let [one, two] = catches;
+        let one = self.runs[0].catch(area, one.0, one.1, one.2)?;
+        let two = self.runs[1].catch(area, two.0, two.1, two.2)?;
+        self.link(area, one, two)?;
+        Ok([one, two])
+    }
+
+    /// Links player one's Pokémon `one` with player two's `two`, replacing
+    /// any pairs either was in.
+    ///
+    /// # Errors
+    /// If either Pokémon doesn't exist.
+    pub fn link(&mut self, area: &str, one: usize, two: usize) -> Result<(), TrackerErrorGen1> {
+        for (run, id) in self.runs.iter().zip([one, two]) {
+            run.pokemon.get(id).ok_or(TrackerErrorGen1::UnknownPokemon(id))?;
+        }
+        self.pairs.retain(|pair| pair.pokemon[0] != one && pair.pokemon[1] != two);
+        self.pairs.push(SoulLinkPairGen1 { area: area.into(), pokemon: [one, two] });
+        Ok(())
+    }
+
+    /// The pair a player's Pokémon is in, if any.
+    pub fn pair(&self, player: PlayerGen1, id: usize) -> Option<&SoulLinkPairGen1> {
+        self.pairs.iter().find(|pair| pair.id(player) == id)
+    }
+
+    /// The id of a player's Pokémon's partner in the other player's run.
+    pub fn partner(&self, player: PlayerGen1, id: usize) -> Option<usize> {
+        self.pair(player, id).map(|pair| pair.id(player.other()))
+    }
+
+    /// The pair an area's encounters were linked in. Area names are
+    /// compared ignoring case, spaces and punctuation.
+    pub fn pair_in_area(&self, area: &str) -> Option<&SoulLinkPairGen1> {
+        let key = name_key(area);
+        self.pairs.iter().find(|pair| name_key(&pair.area) == key)
+    }
+
+    /// Moves a player's Pokémon to the graveyard, and its partner with it.
+    /// Returns the partner's id if the partner was still alive.
+    ///
+    /// # Errors
+    /// If there's no such Pokémon, or it's already dead.
+    pub fn record_death(
+        &mut self,
+        player: PlayerGen1,
+        id: usize,
+        death: DeathGen1,
+    ) -> Result<Option<usize>, TrackerErrorGen1> {
+        self.runs[player.index()].record_death(id, death.clone())?;
+        let partner = self.partner(player, id)
+            .filter(|&partner| !self.run(player.other()).pokemon[partner].is_dead());
+        if let Some(partner) = partner {
+            self.runs[player.other().index()].record_death(partner, DeathGen1 { soul_link: true, ..death })?;
+        }
+        Ok(partner)
+    }
+
+    /// Moves a player's Pokémon and its partner from the party to the box.
+    /// Neither moves unless both can.
+    ///
+    /// # Errors
+    /// If either Pokémon doesn't exist or is dead.
+    pub fn deposit(&mut self, player: PlayerGen1, id: usize) -> Result<(), TrackerErrorGen1> {
+        self.with_partner(player, id, RunGen1::deposit)
+    }
+
+    /// Moves a player's Pokémon and its partner from the box to the party.
+    /// Neither moves unless both can.
+    ///
+    /// # Errors
+    /// If either Pokémon doesn't exist, is dead, or its party is full.
+    pub fn withdraw(&mut self, player: PlayerGen1, id: usize) -> Result<(), TrackerErrorGen1> {
+        self.with_partner(player, id, RunGen1::withdraw)
+    }
+
+    /// Pairs whose halves are both in their players' parties.
+    pub fn party_pairs(&self) -> impl Iterator<Item = [&TrackedPokemonGen1; 2]> {
+        self.pairs.iter()
+            .map(|pair| [&self.runs[0].pokemon[pair.pokemon[0]], &self.runs[1].pokemon[pair.pokemon[1]]])
+            .filter(|pokemon| pokemon.iter().all(|p| p.storage == StorageGen1::Party))
+    }
+
+    /// Applies a change to a Pokémon and its partner, or to neither if
+    /// either fails.
+    fn with_partner(
+        &mut self,
+        player: PlayerGen1,
+        id: usize,
+        change: fn(&mut RunGen1, usize) -> Result<(), TrackerErrorGen1>,
+    ) -> Result<(), TrackerErrorGen1> {
+        let mut runs = self.runs.clone();
+        change(&mut runs[player.index()], id)?;
+        if let Some(partner) = self.partner(player, id) {
+            change(&mut runs[player.other().index()], partner)?;
+        }
+        self.runs = runs;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use engine::{GameGen1, find_species_gen_1};
+
+    fn species(name: &str) -> &'static SpeciesGen1 {
+        find_species_gen_1(name).unwrap()
+    }
+
+    fn link() -> SoulLinkGen1 {
+        SoulLinkGen1::new(RunGen1::new("one", GameGen1::Red), RunGen1::new("two", GameGen1::Yellow))
+    }
+
+    /// Both players catch or neither does, and pairs move and die together.
+    #[test]
+    fn test_linked_pairs() {
+        let mut link = link();
+        let [a, b] = link.catch("Route 1", [(species("Pidgey"), 3, "A"), (species("Rattata"), 3, "B")]).unwrap();
+        assert_eq!(link.partner(PlayerGen1::Two, b), Some(a));
+        assert_eq!(link.pair_in_area("route 1").unwrap().pokemon, [a, b]);
+
+        // Player two has already used Route 2, so player one can't catch there
+        link.run_mut(PlayerGen1::Two).catch("Route 2", species("Pidgey"), 3, "C").unwrap();
+        let route_2 = [(species("Caterpie"), 4, "D"), (species("Weedle"), 4, "E")];
+        assert_eq!(link.catch("Route 2", route_2), Err(TrackerErrorGen1::AreaUsed("Route 2".into())));
+        assert_eq!(link.run(PlayerGen1::One).pokemon.len(), 1);
+
+        link.deposit(PlayerGen1::One, a).unwrap();
+        assert_eq!(link.run(PlayerGen1::Two).pokemon[b].storage, StorageGen1::Box);
+        link.withdraw(PlayerGen1::Two, b).unwrap();
+        assert_eq!(link.party_pairs().count(), 1);
+
+        let death = DeathGen1 { trainer: Some("Brock".into()), ..Default::default() };
+        assert_eq!(link.record_death(PlayerGen1::Two, b, death), Ok(Some(a)));
+        let partner_death = link.run(PlayerGen1::One).pokemon[a].death.clone().unwrap();
+        assert!(partner_death.soul_link);
+        assert_eq!(partner_death.trainer.as_deref(), Some("Brock"));
+    }
+}